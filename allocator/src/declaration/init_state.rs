@@ -1,7 +0,0 @@
-use serde::{Deserialize, Serialize};
-use super::Position;
-
-#[derive(Deserialize, Serialize, Default, Debug, Clone)]
-pub struct InitState {
-    pub ball: Option<Position>,
-}
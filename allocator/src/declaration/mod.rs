@@ -5,12 +5,8 @@ pub mod coach;
 pub mod referee;
 pub mod host_port;
 pub mod stop_event;
-pub mod init_state;
 
-pub mod position;
-pub mod unum;
-
-pub use unum::{Unum, unum};
+pub use common::types::{Unum, unum};
 pub use team::Team as TeamDeclaration;
 pub use image::Image as ImageDeclaration;
 pub use player::{
@@ -24,8 +20,16 @@ pub use coach::{
     CoachBase as CoachBaseDeclaration,
 };
 pub use referee::Referee as RefereeDeclaration;
-pub use init_state::InitState as InitStateDeclaration;
+// the service applies these as declared, so they are defined once in `common`
+pub use common::types::{
+    InitState as InitStateDeclaration,
+    PlayerInitState as PlayerInitStateDeclaration,
+};
 pub use stop_event::StoppingEvent as StopEventDeclaration;
+pub use common::types::{
+    Blocklist as BlocklistDeclaration,
+    PlayerBlocklist as PlayerBlocklistDeclaration,
+};
 
-pub use position::Position;
+pub use common::types::Position;
 pub use host_port::HostPort;
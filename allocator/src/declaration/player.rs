@@ -1,4 +1,4 @@
-use super::unum::Unum;
+use super::Unum;
 use super::image::Image;
 use super::host_port::HostPort;
 use serde::{Deserialize, Serialize};
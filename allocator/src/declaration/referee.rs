@@ -1,6 +1,12 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Deserialize, Serialize, Default, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct Referee {
     pub enabled: bool
 }
+
+impl Default for Referee {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
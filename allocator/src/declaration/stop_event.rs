@@ -3,5 +3,8 @@ use serde::{Deserialize, Serialize};
 #[derive(Deserialize, Serialize, Default, Debug, Clone)]
 pub struct StoppingEvent {
     pub timeup: Option<u16>,
+    #[serde(default)]
+    pub goal_l: Option<u8>,
+    #[serde(default)]
+    pub goal_r: Option<u8>,
 }
-
@@ -1,14 +1,18 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use serde::{Deserialize, Serialize};
 use common::errors::BuilderError;
 use crate::declaration::{
+    BlocklistDeclaration,
     CoachDeclaration,
     InitStateDeclaration,
     RefereeDeclaration,
     StopEventDeclaration
 };
 
+/// Team names `from_map` makes up when the annotations declare none.
+const PLACEHOLDER_TEAM_L: &str = "TeamLeft";
+const PLACEHOLDER_TEAM_R: &str = "TeamRight";
 
 #[derive(Deserialize, Serialize, Default, Debug, Clone)]
 pub struct Annotations {
@@ -19,6 +23,10 @@ pub struct Annotations {
     pub init: InitStateDeclaration,
     pub referee: RefereeDeclaration,
     pub stopping: StopEventDeclaration,
+    #[serde(default)]
+    pub blocklist: BlocklistDeclaration,
+    #[serde(default)]
+    pub server_params: BTreeMap<String, String>,
 }
 
 impl Annotations {
@@ -32,14 +40,29 @@ impl Annotations {
         let init = map.get("init")
             .and_then(|i| serde_json::from_str(i).ok())
             .unwrap_or_default();
-        let team_l = map.remove("team.l").unwrap_or("TeamLeft".to_string());
-        let team_r = map.remove("team.r").unwrap_or("TeamRight".to_string());
+        let blocklist = map.get("blocklist")
+            .and_then(|b| serde_json::from_str(b).ok())
+            .unwrap_or_default();
+        let server_params = map.get("server_params")
+            .and_then(|p| serde_json::from_str(p).ok())
+            .unwrap_or_default();
+        let team_l = map.remove("team.l").unwrap_or(PLACEHOLDER_TEAM_L.to_string());
+        let team_r = map.remove("team.r").unwrap_or(PLACEHOLDER_TEAM_R.to_string());
         let coach_l = map.get("team.coach.l")
             .and_then(|c| serde_json::from_str(c).ok());
         let coach_r = map.get("team.coach.r")
             .and_then(|c| serde_json::from_str(c).ok());
-        Annotations { referee, stopping, init, team_l, team_r, coach_l, coach_r }
+        Annotations { referee, stopping, init, blocklist, server_params, team_l, team_r, coach_l, coach_r }
     }
+
+    /// The team names actually declared, placeholders and empty names left out.
+    pub fn declared_teams(&self) -> (Option<&str>, Option<&str>) {
+        fn declared<'a>(name: &'a str, placeholder: &str) -> Option<&'a str> {
+            (!name.is_empty() && name != placeholder).then_some(name)
+        }
+        (declared(&self.team_l, PLACEHOLDER_TEAM_L), declared(&self.team_r, PLACEHOLDER_TEAM_R))
+    }
+
     pub fn into_map(self) -> HashMap<String, String> {
         let mut map = HashMap::new();
         map.insert("team.l".to_string(), self.team_l);
@@ -59,6 +82,13 @@ impl Annotations {
         if let Ok(init_str) = serde_json::to_string(&self.init) {
             map.insert("init".to_string(), init_str);
         }
+        if let Ok(blocklist_str) = serde_json::to_string(&self.blocklist) {
+            map.insert("blocklist".to_string(), blocklist_str);
+        }
+        if !self.server_params.is_empty()
+            && let Ok(params_str) = serde_json::to_string(&self.server_params) {
+            map.insert("server_params".to_string(), params_str);
+        }
         map
     }
 }
@@ -70,3 +100,18 @@ impl TryInto<Annotations> for HashMap<String, String> {
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_declared_teams() {
+        let annotations = Annotations::from_map(HashMap::from([("team.l".to_string(), "Helios".to_string())]));
+        assert_eq!(annotations.team_r, PLACEHOLDER_TEAM_R);
+        assert_eq!(annotations.declared_teams(), (Some("Helios"), None));
+
+        let annotations = Annotations::from_map(HashMap::from([("team.r".to_string(), String::new())]));
+        assert_eq!(annotations.declared_teams(), (None, None));
+    }
+}
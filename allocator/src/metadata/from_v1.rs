@@ -1,8 +1,10 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use common::errors::BuilderError;
+use process::config::ServerConfig;
 
 use crate::declaration::{
+    BlocklistDeclaration,
     CoachBaseDeclaration,
     CoachDeclaration,
     HostPort,
@@ -10,6 +12,8 @@ use crate::declaration::{
     InitStateDeclaration,
     PlayerBaseDeclaration,
     PlayerDeclaration,
+    PlayerBlocklistDeclaration,
+    PlayerInitStateDeclaration,
     Position as PositionDeclaration,
     RefereeDeclaration,
     StopEventDeclaration,
@@ -17,7 +21,7 @@ use crate::declaration::{
 };
 use crate::schema::{
     Schema,
-    v1::{ConfigV1, PlayerV1, PolicyV1, TeamSideV1, TeamV1},
+    v1::{ConfigV1, PlayerActionList, PlayerV1, PolicyV1, ServerParamV1, TeamSideV1, TeamV1},
 };
 
 use super::{Annotations, Labels, MetaData, labels::PlayerLabel};
@@ -36,14 +40,17 @@ impl TryFrom<ConfigV1> for MetaData {
             referee,
             stopping,
             init_state,
+            server_params,
             log,
             ..
         } = value;
 
-        let (team_l, players_l, coach_l) = parse_team(TeamSideV1::Left, teams.left, log)?;
-        let (team_r, players_r, coach_r) = parse_team(TeamSideV1::Right, teams.right, log)?;
+        let (team_l, players_l, coach_l, init_l, blocklist_l) = parse_team(TeamSideV1::Left, teams.left, log)?;
+        let (team_r, players_r, coach_r, init_r, blocklist_r) = parse_team(TeamSideV1::Right, teams.right, log)?;
 
-        let labels = Labels::new(players_l, players_r);
+        let server_params = convert_server_params(server_params)?;
+
+        let labels = Labels::new(players_l, players_r).with_server_params(&server_params);
         labels.validate()?;
 
         Ok(MetaData {
@@ -58,21 +65,36 @@ impl TryFrom<ConfigV1> for MetaData {
                 },
                 stopping: StopEventDeclaration {
                     timeup: stopping.time_up,
+                    goal_l: stopping.goal_l,
+                    goal_r: stopping.goal_r,
                 },
                 init: InitStateDeclaration {
                     ball: init_state.ball.map(|position| PositionDeclaration {
                         x: position.x.into(),
                         y: position.y.into(),
                     }),
+                    left: init_l,
+                    right: init_r,
+                },
+                blocklist: BlocklistDeclaration {
+                    left: blocklist_l,
+                    right: blocklist_r,
                 },
+                server_params,
             },
         })
     }
 }
 
-fn parse_team(
-    side: TeamSideV1, team: TeamV1, log: bool
-) -> Result<(String, HashMap<Unum, PlayerLabel>, Option<CoachDeclaration>), BuilderError> {
+type ParsedTeam = (
+    String,
+    HashMap<Unum, PlayerLabel>,
+    Option<CoachDeclaration>,
+    BTreeMap<Unum, PlayerInitStateDeclaration>,
+    BTreeMap<Unum, PlayerBlocklistDeclaration>,
+);
+
+fn parse_team(side: TeamSideV1, team: TeamV1, log: bool) -> Result<ParsedTeam, BuilderError> {
     let TeamV1 {
         name,
         side: team_side,
@@ -89,9 +111,17 @@ fn parse_team(
     }
 
     let mut labels = HashMap::new();
+    let mut init_states = BTreeMap::new();
+    let mut blocklists = BTreeMap::new();
 
     for player in players {
-        let (unum, label) = convert_player(player, log)?;
+        let (unum, label, init_state, blocklist) = convert_player(player, log)?;
+        if !init_state.is_empty() {
+            init_states.insert(unum, init_state);
+        }
+        if !blocklist.is_empty() {
+            blocklists.insert(unum, blocklist);
+        }
         if labels.insert(unum, label).is_some() {
             return Err(BuilderError::InvalidField {
                 field: "teams.players",
@@ -102,7 +132,35 @@ fn parse_team(
 
     let coach = coach.map(|coach| convert_coach(coach, log)).transpose()?;
 
-    Ok((name, labels, coach))
+    Ok((name, labels, coach, init_states, blocklists))
+}
+
+/// Parameters the service sets itself, overriding them would break the trainer or the proxies.
+const RESERVED_SERVER_PARAMS: &[&str] = &["port", "coach_port", "olcoach_port", "coach", "synch_mode"];
+
+fn convert_server_params(
+    params: BTreeMap<String, ServerParamV1>
+) -> Result<BTreeMap<String, String>, BuilderError> {
+    let mut probe = ServerConfig::default();
+    params.into_iter()
+        .map(|(name, value)| {
+            let value = value.to_string();
+            if RESERVED_SERVER_PARAMS.contains(&name.as_str()) {
+                return Err(BuilderError::InvalidField {
+                    field: "server_params",
+                    message: format!("server::{name} is managed by the service"),
+                });
+            }
+            if !probe.set_param(&name, &value) {
+                return Err(BuilderError::InvalidValue {
+                    field: "server_params",
+                    value: format!("{name}={value}"),
+                    expected: "a known server:: parameter with a value of its type".to_string(),
+                });
+            }
+            Ok((name, value))
+        })
+        .collect()
 }
 
 fn convert_coach(coach: crate::schema::v1::CoachV1, log: bool) -> Result<CoachDeclaration, BuilderError> {
@@ -121,12 +179,15 @@ fn convert_coach(coach: crate::schema::v1::CoachV1, log: bool) -> Result<CoachDe
     }
 }
 
-fn convert_player(player: PlayerV1, log: bool) -> Result<(Unum, PlayerLabel), BuilderError> {
+type ConvertedPlayer = (Unum, PlayerLabel, PlayerInitStateDeclaration, PlayerBlocklistDeclaration);
+
+fn convert_player(player: PlayerV1, log: bool) -> Result<ConvertedPlayer, BuilderError> {
     let PlayerV1 {
         unum,
         goalie,
         policy,
-        ..
+        init_state,
+        blocklist,
     } = player;
 
     let unum = Unum::try_from(unum)?;
@@ -149,7 +210,18 @@ fn convert_player(player: PlayerV1, log: bool) -> Result<(Unum, PlayerLabel), Bu
         },
     };
 
-    Ok((unum, PlayerLabel { player }))
+    let init_state = PlayerInitStateDeclaration {
+        pos: init_state.pos.map(|position| PositionDeclaration {
+            x: position.x.into(),
+            y: position.y.into(),
+        }),
+        stamina: init_state.stamina,
+    };
+
+    let PlayerActionList { dash, catch } = blocklist;
+    let blocklist = PlayerBlocklistDeclaration { dash, catch };
+
+    Ok((unum, PlayerLabel { player }, init_state, blocklist))
 }
 
 #[cfg(test)]
@@ -175,6 +247,10 @@ mod tests {
                     "players": [{
                         "unum": 1,
                         "goalie": true,
+                        "init_state": {
+                            "pos": { "x": 40.0, "y": -5.0 },
+                            "stamina": 4000
+                        },
                         "policy": {
                             "kind": "agent",
                             "agent": "ssp",
@@ -190,6 +266,7 @@ mod tests {
                     "players": [{
                         "unum": 2,
                         "goalie": false,
+                        "blocklist": { "catch": true },
                         "policy": {
                             "kind": "bot",
                             "image": "HELIOS/helios-base"
@@ -208,6 +285,8 @@ mod tests {
         assert_eq!(metadata.annotations.team_r, "Righties");
         assert!(!metadata.annotations.referee.enabled);
         assert_eq!(metadata.annotations.stopping.timeup, Some(6000));
+        assert_eq!(metadata.annotations.stopping.goal_l, Some(3));
+        assert_eq!(metadata.annotations.stopping.goal_r, None);
 
         let ball = metadata
             .annotations
@@ -217,6 +296,15 @@ mod tests {
         assert_eq!(ball.x, 0.5);
         assert_eq!(ball.y, 0.25);
 
+        let init_r = &metadata.annotations.init.right[&right_unum];
+        assert_eq!(init_r.pos.as_ref().map(|p| p.x), Some(40.0));
+        assert_eq!(init_r.stamina, Some(4000));
+        assert!(metadata.annotations.init.left.is_empty());
+
+        let blocklist_l = &metadata.annotations.blocklist.left[&left_unum];
+        assert!(blocklist_l.catch && !blocklist_l.dash);
+        assert!(metadata.annotations.blocklist.right.is_empty());
+
         assert!(matches!(
             &metadata.labels.left[&left_unum].player,
             PlayerDeclaration::Helios { .. }
@@ -319,4 +407,46 @@ mod tests {
         ));
         assert!(metadata.annotations.coach_r.is_none());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn converts_server_params_into_annotations_and_labels() {
+        let config = |params: serde_json::Value| -> ConfigV1 {
+            serde_json::from_value(json!({
+                "server_params": params,
+                "teams": {
+                    "left": {
+                        "name": "HB1",
+                        "side": "left",
+                        "players": [{ "unum": 1 }]
+                    },
+                    "right": {
+                        "name": "HB2",
+                        "side": "right",
+                        "players": [{ "unum": 1 }]
+                    }
+                }
+            }))
+            .expect("config should deserialize")
+        };
+
+        let plain = MetaData::try_from(config(json!({}))).expect("config should convert");
+        let tuned = MetaData::try_from(config(json!({ "stamina_max": 8000, "use_offside": false })))
+            .expect("config should convert");
+
+        assert!(plain.annotations.server_params.is_empty());
+        assert!(plain.labels.server_params.is_none());
+        assert_eq!(tuned.annotations.server_params["stamina_max"], "8000");
+        assert_eq!(tuned.annotations.server_params["use_offside"], "false");
+        assert_ne!(
+            plain.labels.as_hash().expect("labels should hash"),
+            tuned.labels.as_hash().expect("labels should hash"),
+        );
+
+        let unknown = MetaData::try_from(config(json!({ "no_such_param": 1 })));
+        assert!(matches!(unknown, Err(BuilderError::InvalidValue { field: "server_params", .. })));
+        let ill_typed = MetaData::try_from(config(json!({ "stamina_max": "a lot" })));
+        assert!(matches!(ill_typed, Err(BuilderError::InvalidValue { field: "server_params", .. })));
+        let reserved = MetaData::try_from(config(json!({ "port": 7000 })));
+        assert!(matches!(reserved, Err(BuilderError::InvalidField { field: "server_params", .. })));
+    }
+}
@@ -26,6 +26,9 @@ pub struct Labels {
     pub left_num: u8, // p.l.n = u8
     #[serde(skip)]
     pub right_num: u8, // p.r.n = u8
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server_params: Option<String>, // server.params = hash of the overrides, so they get their own fleet
     
     #[serde(skip)]
     buf_map: OnceLock<HashMap<String, String>>,
@@ -53,12 +56,24 @@ impl Labels {
         Self {
             left, right,
             left_num, right_num,
+            server_params: None,
             buf_map: OnceLock::new(),
             buf_hash: OnceLock::new(),
             buf_ordered_map: OnceLock::new(),
         }
     }
     
+    /// Keys the labels by the `server_params` overrides, a no-op when there are none.
+    pub fn with_server_params(mut self, params: &BTreeMap<String, String>) -> Self {
+        if !params.is_empty() {
+            self.server_params = Some(Self::gen_hash(params));
+            self.buf_map = OnceLock::new();
+            self.buf_ordered_map = OnceLock::new();
+            self.buf_hash = OnceLock::new();
+        }
+        self
+    }
+
     pub fn validate(&self) -> BuilderResult<()> {
         self.try_as_map()?;
         Ok(())
@@ -100,7 +115,9 @@ impl Labels {
             };
         }
 
-        Ok(Self::new(left, right))
+        let mut ret = Self::new(left, right);
+        ret.server_params = map.get("server.params").cloned();
+        Ok(ret)
     }
 
     pub fn try_into_map(self) -> BuilderResult<HashMap<String, String>> {
@@ -108,6 +125,9 @@ impl Labels {
 
         map.insert("p.l.n".to_string(), self.left_num.to_string());
         map.insert("p.r.n".to_string(), self.right_num.to_string());
+        if let Some(server_params) = self.server_params {
+            map.insert("server.params".to_string(), server_params);
+        }
 
         for (unum, label) in self.left {
             let encoded = label_serdes::ser(&label)?;
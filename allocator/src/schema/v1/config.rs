@@ -1,4 +1,5 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
 use serde::{Deserialize, Serialize};
 
 use crate::schema::v1::utils::pos_in_court;
@@ -16,6 +17,9 @@ pub struct ConfigV1 {
     pub stopping: StoppingEventV1,
     #[serde(default)]
     pub init_state: GlobalInitStateV1,
+    /// `server::` parameter overrides, keyed by the bare rcssserver name, e.g. `stamina_max`.
+    #[serde(default)]
+    pub server_params: BTreeMap<String, ServerParamV1>,
     #[serde(default)]
     pub env:    Option<HashMap<String, String>>
 }
@@ -29,6 +33,24 @@ impl Schema for ConfigV1 {
     }
 }
 
+#[derive(Deserialize, Serialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum ServerParamV1 {
+    Bool(bool),
+    Number(serde_json::Number),
+    Text(String),
+}
+
+impl Display for ServerParamV1 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ServerParamV1::Bool(b) => write!(f, "{b}"),
+            ServerParamV1::Number(n) => write!(f, "{n}"),
+            ServerParamV1::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
 #[derive(Deserialize, Serialize, Clone, Debug)]
 pub struct RefereeV1 {
     pub enable: bool
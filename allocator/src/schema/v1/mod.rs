@@ -8,11 +8,11 @@ mod agent;
 
 use super::Schema;
 
-pub use config::ConfigV1;
+pub use config::{ConfigV1, ServerParamV1};
 
 pub use team::{TeamsV1, TeamV1, TeamSideV1};
 pub use agent::AgentV1;
-pub use player::{CoachV1, PlayerV1};
+pub use player::{CoachV1, PlayerActionList, PlayerInitStateV1, PlayerV1};
 pub use policy::PolicyV1;
 
 pub use position::Position;
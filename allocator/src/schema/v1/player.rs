@@ -52,8 +52,10 @@ impl Schema for PlayerV1 {
 /// Default all unset
 #[derive(Deserialize, Serialize, Default, Clone, Debug)]
 pub struct PlayerInitStateV1 {
-    pos: Option<Position>,
-    stamina: Option<u16>,
+    pub pos: Option<Position>,
+    /// rcssserver cannot set a player's stamina, any value makes the trainer `recover`
+    /// every player to `stamina_max` before kick-off.
+    pub stamina: Option<u16>,
 }
 
 impl Schema for PlayerInitStateV1 {
@@ -68,9 +70,10 @@ impl Schema for PlayerInitStateV1 {
 
 /// Default for all false
 #[derive(Deserialize, Serialize, Default, Clone, Debug)]
+#[serde(default)]
 pub struct PlayerActionList {
-    dash: bool,
-    r#catch: bool,
+    pub dash: bool,
+    pub r#catch: bool,
 }
 
 impl Schema for PlayerActionList {
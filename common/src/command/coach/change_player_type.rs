@@ -0,0 +1,85 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, format};
+use serde::{Deserialize, Serialize};
+
+use super::{Command, CoachCommand};
+use crate::utils::sexp::Sexp;
+
+/// `(change_player_type UNUM PLAYER_TYPE)`, substituting a heterogeneous player type.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandChangePlayerType {
+    pub unum: u8,
+    pub player_type: u8,
+}
+
+impl Command for CommandChangePlayerType {
+    type Kind = CoachCommand;
+    type Ok = CommandChangePlayerTypeOk;
+    type Error = CommandChangePlayerTypeError;
+
+    fn kind(&self) -> Self::Kind {
+        CoachCommand::ChangePlayerType
+    }
+
+    fn encode(&self) -> ArcStr {
+        format!("(change_player_type {} {})", self.unum, self.player_type)
+    }
+
+    fn parse_ret_ok(tokens: &[Sexp]) -> Option<Self::Ok> {
+        if tokens.len() != 2 {
+            return None;
+        }
+        Some(CommandChangePlayerTypeOk {
+            unum: tokens[0].parse_atom()?,
+            player_type: tokens[1].parse_atom()?,
+        })
+    }
+
+    fn parse_ret_err(tokens: &[Sexp]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandChangePlayerTypeOk {
+    pub unum: u8,
+    pub player_type: u8,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandChangePlayerTypeError {
+    #[error("The UNUM or PLAYER_TYPE argument was omitted or malformed.")]
+    IllegalCommandForm,
+    #[error("The coach is not bound to a team.")]
+    NoTeamFound,
+    #[error("No player with the given UNUM.")]
+    NoSuchPlayer,
+    #[error("Substitutions are not allowed during play_on.")]
+    CannotSubWhilePlayOn,
+    #[error("No substitutions left.")]
+    NoSubsLeft,
+    #[error("The maximum number of players of that type is already on the field.")]
+    MaxOfThatTypeOnField,
+    #[error("The goalie cannot be changed to a heterogeneous type.")]
+    CannotChangeGoalie,
+}
+
+impl FromStr for CommandChangePlayerTypeError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandChangePlayerTypeError as FromStr>::Err> {
+        match s {
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            "no_team_found" => Ok(Self::NoTeamFound),
+            "no_such_player" => Ok(Self::NoSuchPlayer),
+            "cannot_sub_while_playon" => Ok(Self::CannotSubWhilePlayOn),
+            "no_subs_left" => Ok(Self::NoSubsLeft),
+            "max_of_that_type_on_field" => Ok(Self::MaxOfThatTypeOnField),
+            "cannot_change_goalie" => Ok(Self::CannotChangeGoalie),
+            _ => Err(()),
+        }
+    }
+}
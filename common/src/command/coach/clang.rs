@@ -0,0 +1,329 @@
+//! Builder for the coach language (CLang) carried by the online coach `say`.
+//!
+//! Only the v8 grammar is modelled; anything else can go through the `Raw` variants.
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Top-level CLang message, the argument of `(say MESSAGE)`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum ClangMessage {
+    /// `(meta (ver N))`
+    Version(u8),
+    /// `(freeform "TEXT")`
+    Freeform(String),
+    /// `(info TOKEN...)`
+    Info(Vec<ClangToken>),
+    /// `(advice TOKEN...)`
+    Advice(Vec<ClangToken>),
+    /// `(define DEFINITION...)`
+    Define(Vec<ClangDefinition>),
+    /// `(del (RULE_ID...))`, or `(del all)` when empty.
+    Delete(Vec<String>),
+    Raw(String),
+}
+
+/// `(TTL CONDITION DIRECTIVE...)`
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ClangToken {
+    pub ttl: u32,
+    pub condition: ClangCondition,
+    pub directives: Vec<ClangDirective>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum ClangDefinition {
+    Condition { name: String, condition: ClangCondition },
+    Directive { name: String, directive: ClangDirective },
+    Region { name: String, region: ClangRegion },
+    Action { name: String, action: ClangAction },
+}
+
+/// `our`/`opp` followed by a unum set, `{0}` meaning the whole team.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ClangPlayers {
+    pub our: bool,
+    pub unums: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum ClangCondition {
+    True,
+    False,
+    /// `(ppos our {2 3} MIN MAX REGION)`
+    PlayerPos { players: ClangPlayers, min: u8, max: u8, region: ClangRegion },
+    /// `(bpos REGION)`
+    BallPos(ClangRegion),
+    /// `(bowner our {4})`
+    BallOwner(ClangPlayers),
+    /// `(playm MODE)`
+    PlayMode(String),
+    And(Vec<ClangCondition>),
+    Or(Vec<ClangCondition>),
+    Not(Box<ClangCondition>),
+    Named(String),
+    Raw(String),
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum ClangDirective {
+    /// `(do our {2} ACTION...)`
+    Do { players: ClangPlayers, actions: Vec<ClangAction> },
+    /// `(dont our {2} ACTION...)`
+    Dont { players: ClangPlayers, actions: Vec<ClangAction> },
+    Named(String),
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum ClangAction {
+    Pos(ClangRegion),
+    Home(ClangRegion),
+    BallTo(ClangRegion),
+    BallToPlayers(Vec<u8>),
+    Mark(Vec<u8>),
+    MarkLine(ClangRegion),
+    OffsideLine(ClangRegion),
+    /// `(htype N)`, the heterogeneous player type.
+    HeteroType(u8),
+    Hold,
+    Intercept,
+    Shoot,
+    Pass(Vec<u8>),
+    Dribble(ClangRegion),
+    Clear(ClangRegion),
+    Named(String),
+    Raw(String),
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum ClangRegion {
+    /// `(pt X Y)`
+    Point { x: f32, y: f32 },
+    /// `(pt ball)`
+    Ball,
+    /// `(rec (pt X1 Y1) (pt X2 Y2))`
+    Rect { x1: f32, y1: f32, x2: f32, y2: f32 },
+    /// `(reg REGION...)`, the union of regions.
+    Union(Vec<ClangRegion>),
+    /// `(null)`
+    Null,
+    Named(String),
+    Raw(String),
+}
+
+impl ClangMessage {
+    pub fn freeform(text: impl Into<String>) -> Self {
+        ClangMessage::Freeform(text.into())
+    }
+
+    pub fn info() -> ClangTokensBuilder {
+        ClangTokensBuilder { advice: false, tokens: Vec::new() }
+    }
+
+    pub fn advice() -> ClangTokensBuilder {
+        ClangTokensBuilder { advice: true, tokens: Vec::new() }
+    }
+}
+
+/// Accumulates tokens for an `info` or `advice` message.
+#[derive(Clone, Debug)]
+pub struct ClangTokensBuilder {
+    advice: bool,
+    tokens: Vec<ClangToken>,
+}
+
+impl ClangTokensBuilder {
+    pub fn token(mut self, ttl: u32, condition: ClangCondition, directives: Vec<ClangDirective>) -> Self {
+        self.tokens.push(ClangToken { ttl, condition, directives });
+        self
+    }
+
+    pub fn build(self) -> ClangMessage {
+        if self.advice {
+            ClangMessage::Advice(self.tokens)
+        } else {
+            ClangMessage::Info(self.tokens)
+        }
+    }
+}
+
+impl ClangPlayers {
+    pub fn our(unums: impl Into<Vec<u8>>) -> Self {
+        Self { our: true, unums: unums.into() }
+    }
+
+    pub fn opp(unums: impl Into<Vec<u8>>) -> Self {
+        Self { our: false, unums: unums.into() }
+    }
+}
+
+fn write_unums(f: &mut fmt::Formatter<'_>, unums: &[u8]) -> fmt::Result {
+    f.write_str("{")?;
+    for (i, unum) in unums.iter().enumerate() {
+        if i != 0 {
+            f.write_str(" ")?;
+        }
+        write!(f, "{unum}")?;
+    }
+    f.write_str("}")
+}
+
+fn write_all<T: fmt::Display>(f: &mut fmt::Formatter<'_>, items: &[T]) -> fmt::Result {
+    for item in items {
+        write!(f, " {item}")?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for ClangMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClangMessage::Version(ver) => write!(f, "(meta (ver {ver}))"),
+            ClangMessage::Freeform(text) => write!(f, "(freeform \"{text}\")"),
+            ClangMessage::Info(tokens) => {
+                f.write_str("(info")?;
+                write_all(f, tokens)?;
+                f.write_str(")")
+            }
+            ClangMessage::Advice(tokens) => {
+                f.write_str("(advice")?;
+                write_all(f, tokens)?;
+                f.write_str(")")
+            }
+            ClangMessage::Define(defs) => {
+                f.write_str("(define")?;
+                write_all(f, defs)?;
+                f.write_str(")")
+            }
+            ClangMessage::Delete(ids) if ids.is_empty() => f.write_str("(del all)"),
+            ClangMessage::Delete(ids) => write!(f, "(del ({}))", ids.join(" ")),
+            ClangMessage::Raw(raw) => f.write_str(raw),
+        }
+    }
+}
+
+impl fmt::Display for ClangToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({} {}", self.ttl, self.condition)?;
+        write_all(f, &self.directives)?;
+        f.write_str(")")
+    }
+}
+
+impl fmt::Display for ClangDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClangDefinition::Condition { name, condition } => write!(f, "(definec \"{name}\" {condition})"),
+            ClangDefinition::Directive { name, directive } => write!(f, "(defined \"{name}\" {directive})"),
+            ClangDefinition::Region { name, region } => write!(f, "(definer \"{name}\" {region})"),
+            ClangDefinition::Action { name, action } => write!(f, "(definea \"{name}\" {action})"),
+        }
+    }
+}
+
+impl fmt::Display for ClangPlayers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(if self.our { "our " } else { "opp " })?;
+        write_unums(f, &self.unums)
+    }
+}
+
+impl fmt::Display for ClangCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClangCondition::True => f.write_str("(true)"),
+            ClangCondition::False => f.write_str("(false)"),
+            ClangCondition::PlayerPos { players, min, max, region } => {
+                write!(f, "(ppos {players} {min} {max} {region})")
+            }
+            ClangCondition::BallPos(region) => write!(f, "(bpos {region})"),
+            ClangCondition::BallOwner(players) => write!(f, "(bowner {players})"),
+            ClangCondition::PlayMode(mode) => write!(f, "(playm {mode})"),
+            ClangCondition::And(conds) => {
+                f.write_str("(and")?;
+                write_all(f, conds)?;
+                f.write_str(")")
+            }
+            ClangCondition::Or(conds) => {
+                f.write_str("(or")?;
+                write_all(f, conds)?;
+                f.write_str(")")
+            }
+            ClangCondition::Not(cond) => write!(f, "(not {cond})"),
+            ClangCondition::Named(name) => write!(f, "\"{name}\""),
+            ClangCondition::Raw(raw) => f.write_str(raw),
+        }
+    }
+}
+
+impl fmt::Display for ClangDirective {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (head, players, actions) = match self {
+            ClangDirective::Do { players, actions } => ("do", players, actions),
+            ClangDirective::Dont { players, actions } => ("dont", players, actions),
+            ClangDirective::Named(name) => return write!(f, "\"{name}\""),
+        };
+        write!(f, "({head} {players}")?;
+        write_all(f, actions)?;
+        f.write_str(")")
+    }
+}
+
+impl fmt::Display for ClangAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClangAction::Pos(region) => write!(f, "(pos {region})"),
+            ClangAction::Home(region) => write!(f, "(home {region})"),
+            ClangAction::BallTo(region) => write!(f, "(bto {region})"),
+            ClangAction::BallToPlayers(unums) => {
+                f.write_str("(bto ")?;
+                write_unums(f, unums)?;
+                f.write_str(")")
+            }
+            ClangAction::Mark(unums) => {
+                f.write_str("(mark ")?;
+                write_unums(f, unums)?;
+                f.write_str(")")
+            }
+            ClangAction::MarkLine(region) => write!(f, "(markl {region})"),
+            ClangAction::OffsideLine(region) => write!(f, "(oline {region})"),
+            ClangAction::HeteroType(t) => write!(f, "(htype {t})"),
+            ClangAction::Hold => f.write_str("(hold)"),
+            ClangAction::Intercept => f.write_str("(intercept)"),
+            ClangAction::Shoot => f.write_str("(shoot)"),
+            ClangAction::Pass(unums) => {
+                f.write_str("(pass ")?;
+                write_unums(f, unums)?;
+                f.write_str(")")
+            }
+            ClangAction::Dribble(region) => write!(f, "(dribble {region})"),
+            ClangAction::Clear(region) => write!(f, "(clear {region})"),
+            ClangAction::Named(name) => write!(f, "\"{name}\""),
+            ClangAction::Raw(raw) => f.write_str(raw),
+        }
+    }
+}
+
+impl fmt::Display for ClangRegion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClangRegion::Point { x, y } => write!(f, "(pt {x} {y})"),
+            ClangRegion::Ball => f.write_str("(pt ball)"),
+            ClangRegion::Rect { x1, y1, x2, y2 } => write!(f, "(rec (pt {x1} {y1}) (pt {x2} {y2}))"),
+            ClangRegion::Union(regions) => {
+                f.write_str("(reg")?;
+                write_all(f, regions)?;
+                f.write_str(")")
+            }
+            ClangRegion::Null => f.write_str("(null)"),
+            ClangRegion::Named(name) => write!(f, "\"{name}\""),
+            ClangRegion::Raw(raw) => f.write_str(raw),
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use std::str::FromStr;
+
+use crate::types::EyeMode;
+use arcstr::{ArcStr, format};
+use serde::{Deserialize, Serialize};
+
+use super::{Command, CoachCommand};
+use crate::utils::sexp::Sexp;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandEye {
+    pub mode: EyeMode,
+}
+
+impl Command for CommandEye {
+    type Kind = CoachCommand;
+    type Ok = CommandEyeOk;
+    type Error = CommandEyeError;
+
+    fn kind(&self) -> Self::Kind {
+        CoachCommand::Eye
+    }
+    fn encode(&self) -> ArcStr {
+        format!("(eye {})", self.mode.encode())
+    }
+
+    fn parse_ret_ok(tokens: &[Sexp]) -> Option<Self::Ok> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        let eye_mode = tokens[0].parse_atom();
+        eye_mode.map(|mode| CommandEyeOk { mode })
+    }
+
+    fn parse_ret_err(tokens: &[Sexp]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandEyeOk {
+    pub mode: EyeMode,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandEyeError {
+    #[error("MODE did not match on or off.")]
+    IllegalMode,
+    #[error("The MODE argument was omitted.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandEyeError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandEyeError as FromStr>::Err> {
+        match s {
+            "illegal_mode" => Ok(Self::IllegalMode),
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
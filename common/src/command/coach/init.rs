@@ -0,0 +1,83 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, format};
+use serde::{Deserialize, Serialize};
+
+use super::{Command, CoachCommand};
+use crate::types;
+use crate::utils::sexp::Sexp;
+
+/// `(init TEAM_NAME [(version V)])`
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandInit {
+    pub team_name: String,
+    #[serde(default)]
+    pub version: Option<u8>,
+}
+
+impl Default for CommandInit {
+    fn default() -> Self {
+        Self {
+            team_name: String::new(),
+            version: Some(19),
+        }
+    }
+}
+
+impl Command for CommandInit {
+    type Kind = CoachCommand;
+    type Ok = CommandInitOk;
+    type Error = CommandInitError;
+
+    fn kind(&self) -> Self::Kind {
+        CoachCommand::Init
+    }
+
+    fn encode(&self) -> ArcStr {
+        match self.version {
+            Some(version) => format!("(init {} (version {version}))", self.team_name),
+            None => format!("(init {})", self.team_name),
+        }
+    }
+
+    /// `(init SIDE ok)`, rewritten by the resolver to `[SIDE]`.
+    fn parse_ret_ok(tokens: &[Sexp]) -> Option<Self::Ok> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        Some(CommandInitOk {
+            side: tokens[0].parse_atom()?,
+        })
+    }
+
+    fn parse_ret_err(tokens: &[Sexp]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandInitOk {
+    pub side: types::Side,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandInitError {
+    #[error("The team does not exist or already has an online coach.")]
+    NoSuchTeamOrAlreadyHaveCoach,
+    #[error("The TEAM_NAME argument was omitted or malformed.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandInitError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandInitError as FromStr>::Err> {
+        match s {
+            "no_such_team_or_already_have_coach" => Ok(Self::NoSuchTeamOrAlreadyHaveCoach),
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
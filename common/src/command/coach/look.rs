@@ -0,0 +1,41 @@
+use std::str::FromStr;
+
+use super::{Command, CoachCommand};
+use crate::types::WorldState;
+use crate::utils::sexp::Sexp;
+use arcstr::{ArcStr, literal};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandLook;
+impl Command for CommandLook {
+    type Kind = CoachCommand;
+    type Ok = CommandLookOk;
+    type Error = CommandLookError;
+
+    fn kind(&self) -> Self::Kind {
+        CoachCommand::Look
+    }
+
+    fn encode(&self) -> ArcStr {
+        literal!("(look)")
+    }
+
+    fn parse_ret_ok(tokens: &[Sexp]) -> Option<Self::Ok> {
+        WorldState::parse(tokens)
+    }
+
+    // never error
+}
+
+pub type CommandLookOk = WorldState;
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandLookError {}
+
+impl FromStr for CommandLookError {
+    type Err = ();
+    fn from_str(_: &str) -> Result<Self, <CommandLookError as FromStr>::Err> {
+        Err(())
+    }
+}
@@ -0,0 +1,153 @@
+pub mod clang;
+pub mod change_player_type;
+pub mod eye;
+pub mod init;
+pub mod look;
+pub mod say;
+pub mod score;
+pub mod team_graphic;
+pub mod team_names;
+
+pub use change_player_type::CommandChangePlayerType as ChangePlayerType;
+pub use eye::CommandEye as Eye;
+pub use init::CommandInit as Init;
+pub use look::CommandLook as Look;
+pub use say::CommandSay as Say;
+pub use score::CommandScore as Score;
+pub use team_graphic::CommandTeamGraphic as TeamGraphic;
+pub use team_names::CommandTeamNames as TeamNames;
+
+use arcstr::{ArcStr, literal};
+use std::any::Any;
+
+use super::{Command, CommandAny};
+use crate::utils::sexp::Sexp;
+
+#[derive(Copy, Clone, Hash, Eq, PartialEq, Debug)]
+pub enum CoachCommand {
+    Init,
+    ChangePlayerType,
+    Say,
+    TeamGraphic,
+    Eye,
+    Look,
+    TeamNames,
+    Score,
+}
+
+impl CommandAny for CoachCommand {
+    fn encode(&self) -> ArcStr {
+        match self {
+            CoachCommand::Init => literal!("init"),
+            CoachCommand::ChangePlayerType => literal!("change_player_type"),
+            CoachCommand::Say => literal!("say"),
+            CoachCommand::TeamGraphic => literal!("team_graphic"),
+            CoachCommand::Eye => literal!("eye"),
+            CoachCommand::Look => literal!("look"),
+            CoachCommand::TeamNames => literal!("team_names"),
+            CoachCommand::Score => literal!("score"),
+        }
+    }
+    fn decode(s: &str) -> Option<Self> {
+        match s {
+            "init" => Some(CoachCommand::Init),
+            "change_player_type" => Some(CoachCommand::ChangePlayerType),
+            "say" => Some(CoachCommand::Say),
+            "team_graphic" => Some(CoachCommand::TeamGraphic),
+            "eye" => Some(CoachCommand::Eye),
+            "look" => Some(CoachCommand::Look),
+            "team_names" => Some(CoachCommand::TeamNames),
+            "score" => Some(CoachCommand::Score),
+            _ => None,
+        }
+    }
+    fn parse_ret_ok(&self, tokens: &[Sexp]) -> Option<Box<dyn Any + Send>> {
+        match self {
+            CoachCommand::Init => {
+                Init::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            CoachCommand::ChangePlayerType => {
+                ChangePlayerType::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            CoachCommand::Say => {
+                Say::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            CoachCommand::TeamGraphic => {
+                TeamGraphic::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            CoachCommand::Eye => {
+                Eye::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            CoachCommand::Look => {
+                Look::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            CoachCommand::TeamNames => {
+                TeamNames::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            CoachCommand::Score => {
+                Score::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+        }
+    }
+    fn parse_ret_err(&self, tokens: &[Sexp]) -> Option<Box<dyn Any + Send>> {
+        match self {
+            CoachCommand::Init => {
+                Init::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            CoachCommand::ChangePlayerType => {
+                ChangePlayerType::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            CoachCommand::Say => {
+                Say::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            CoachCommand::TeamGraphic => {
+                TeamGraphic::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            CoachCommand::Eye => {
+                Eye::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            CoachCommand::Look => {
+                Look::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            CoachCommand::TeamNames => {
+                TeamNames::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            CoachCommand::Score => {
+                Score::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::clang::*;
+    use super::*;
+
+    #[test]
+    fn test_encode_say() {
+        let message = ClangMessage::advice()
+            .token(
+                100,
+                ClangCondition::And(vec![
+                    ClangCondition::PlayMode("play_on".to_string()),
+                    ClangCondition::BallOwner(ClangPlayers::our([4])),
+                ]),
+                vec![ClangDirective::Do {
+                    players: ClangPlayers::our([7, 8]),
+                    actions: vec![ClangAction::Pos(ClangRegion::Rect { x1: 0.0, y1: -10.0, x2: 20.0, y2: 10.0 })],
+                }],
+            )
+            .build();
+
+        assert_eq!(
+            Say { message }.encode(),
+            "(say (advice (100 (and (playm play_on) (bowner our {4})) \
+             (do our {7 8} (pos (rec (pt 0 -10) (pt 20 10)))))))"
+        );
+        assert_eq!(
+            Say { message: ClangMessage::freeform("hello") }.encode(),
+            "(say (freeform \"hello\"))"
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, format};
+use serde::{Deserialize, Serialize};
+
+use super::clang::ClangMessage;
+use super::{Command, CoachCommand};
+use crate::utils::sexp::Sexp;
+
+/// `(say CLANG_MESSAGE)`
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandSay {
+    pub message: ClangMessage,
+}
+
+impl Command for CommandSay {
+    type Kind = CoachCommand;
+    type Ok = CommandSayOk;
+    type Error = CommandSayError;
+
+    fn kind(&self) -> Self::Kind {
+        CoachCommand::Say
+    }
+
+    fn encode(&self) -> ArcStr {
+        format!("(say {})", self.message)
+    }
+
+    fn parse_ret_ok(tokens: &[Sexp]) -> Option<Self::Ok> {
+        tokens.is_empty().then_some(())
+    }
+
+    fn parse_ret_err(tokens: &[Sexp]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+pub type CommandSayOk = ();
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandSayError {
+    #[error("The message could not be parsed as CLang.")]
+    IllegalCommandForm,
+    #[error("Too many messages said in the current period.")]
+    SaidTooManyMessages,
+}
+
+impl FromStr for CommandSayError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandSayError as FromStr>::Err> {
+        match s {
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            "said_too_many_messages" => Ok(Self::SaidTooManyMessages),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, literal};
+use serde::{Deserialize, Serialize};
+
+use super::{Command, CoachCommand};
+use crate::utils::sexp::Sexp;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandScore;
+
+impl Command for CommandScore {
+    type Kind = CoachCommand;
+    type Ok = CommandScoreOk;
+    type Error = CommandScoreError;
+
+    fn kind(&self) -> Self::Kind {
+        CoachCommand::Score
+    }
+
+    fn encode(&self) -> ArcStr {
+        literal!("(score)")
+    }
+
+    /// `(score TIME LEFT RIGHT)`, with or without a leading `ok`.
+    fn parse_ret_ok(tokens: &[Sexp]) -> Option<Self::Ok> {
+        if tokens.len() != 3 {
+            return None;
+        }
+        Some(CommandScoreOk {
+            time: tokens[0].parse_atom()?,
+            left: tokens[1].parse_atom()?,
+            right: tokens[2].parse_atom()?,
+        })
+    }
+
+    // never error
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandScoreOk {
+    pub time: u16,
+    pub left: u8,
+    pub right: u8,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandScoreError {}
+
+impl FromStr for CommandScoreError {
+    type Err = ();
+    fn from_str(_: &str) -> Result<Self, <CommandScoreError as FromStr>::Err> {
+        Err(())
+    }
+}
@@ -0,0 +1,69 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, format};
+use serde::{Deserialize, Serialize};
+
+use super::{Command, CoachCommand};
+use crate::utils::sexp::Sexp;
+
+/// `(team_graphic (X Y "XPM_LINE" ...))`, one 8x8 tile of the 256x64 team logo.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandTeamGraphic {
+    pub x: u8,
+    pub y: u8,
+    pub xpm: Vec<String>,
+}
+
+impl Command for CommandTeamGraphic {
+    type Kind = CoachCommand;
+    type Ok = CommandTeamGraphicOk;
+    type Error = CommandTeamGraphicError;
+
+    fn kind(&self) -> Self::Kind {
+        CoachCommand::TeamGraphic
+    }
+
+    fn encode(&self) -> ArcStr {
+        let xpm = self.xpm.iter().map(|l| std::format!("\"{l}\"")).collect::<Vec<_>>();
+        format!("(team_graphic ({} {} {}))", self.x, self.y, xpm.join(" "))
+    }
+
+    fn parse_ret_ok(tokens: &[Sexp]) -> Option<Self::Ok> {
+        if tokens.len() != 2 {
+            return None;
+        }
+        Some(CommandTeamGraphicOk {
+            x: tokens[0].parse_atom()?,
+            y: tokens[1].parse_atom()?,
+        })
+    }
+
+    fn parse_ret_err(tokens: &[Sexp]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandTeamGraphicOk {
+    pub x: u8,
+    pub y: u8,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandTeamGraphicError {
+    #[error("The tile position or XPM data was malformed.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandTeamGraphicError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandTeamGraphicError as FromStr>::Err> {
+        match s {
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
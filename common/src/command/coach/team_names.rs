@@ -0,0 +1,72 @@
+use std::str::FromStr;
+
+use super::{Command, CoachCommand};
+use crate::utils::sexp::Sexp;
+use arcstr::{ArcStr, literal};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandTeamNames;
+impl Command for CommandTeamNames {
+    type Kind = CoachCommand;
+    type Ok = CommandTeamNamesOk;
+    type Error = CommandTeamNamesError;
+
+    fn kind(&self) -> Self::Kind {
+        CoachCommand::TeamNames
+    }
+
+    fn encode(&self) -> ArcStr {
+        literal!("(team_names)")
+    }
+
+    fn parse_ret_ok(tokens: &[Sexp]) -> Option<Self::Ok> {
+        let parse_team = |team: &Sexp| {
+            let team_tokens = team.list()?;
+            if team_tokens.len() != 3 || !team_tokens[0].is_atom("team") {
+                return None;
+            }
+
+            let team_name = team_tokens[2].atom()?.to_string();
+            match team_tokens[1].atom()? {
+                "l" => Some((Some(team_name), None)),
+                "r" => Some((None, Some(team_name))),
+                _ => None,
+            }
+        };
+
+        let teams = match tokens {
+            [team_1, team_2] => {
+                let team_1 = parse_team(team_1)?;
+                let team_2 = parse_team(team_2)?;
+                (team_1.0.or(team_2.0), team_1.1.or(team_2.1))
+            }
+            [team] => parse_team(team)?,
+            [] => (None, None),
+            _ => return None,
+        };
+
+        Some(CommandTeamNamesOk {
+            left: teams.0,
+            right: teams.1,
+        })
+    }
+
+    // never error
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandTeamNamesOk {
+    pub left: Option<String>,
+    pub right: Option<String>,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandTeamNamesError {}
+
+impl FromStr for CommandTeamNamesError {
+    type Err = ();
+    fn from_str(_: &str) -> Result<Self, <CommandTeamNamesError as FromStr>::Err> {
+        Err(())
+    }
+}
@@ -0,0 +1,94 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, format, literal};
+use serde::{Deserialize, Serialize};
+
+use super::{Command, PlayerCommand};
+use crate::utils::sexp::Sexp;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum AttentionTeam {
+    Our,
+    Opp,
+}
+
+impl AttentionTeam {
+    pub fn encode(self) -> &'static str {
+        match self {
+            AttentionTeam::Our => "our",
+            AttentionTeam::Opp => "opp",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum AttentionToTarget {
+    Off,
+    Player { team: AttentionTeam, unum: u8 },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandAttentionTo {
+    pub target: AttentionToTarget,
+}
+
+impl Command for CommandAttentionTo {
+    type Kind = PlayerCommand;
+    type Ok = CommandAttentionToOk;
+    type Error = CommandAttentionToError;
+
+    fn kind(&self) -> Self::Kind {
+        PlayerCommand::AttentionTo
+    }
+
+    fn encode(&self) -> ArcStr {
+        match self.target {
+            AttentionToTarget::Off => literal!("(attentionto off)"),
+            AttentionToTarget::Player { team, unum } => {
+                format!("(attentionto {} {unum})", team.encode())
+            }
+        }
+    }
+
+    // never ok
+
+    fn parse_ret_err(tokens: &[Sexp]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+pub type CommandAttentionToOk = ();
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandAttentionToError {
+    #[error("The TEAM or UNUM argument was omitted or malformed.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandAttentionToError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandAttentionToError as FromStr>::Err> {
+        match s {
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_attention_to() {
+        let cmd = CommandAttentionTo { target: AttentionToTarget::Off };
+        assert_eq!(cmd.encode(), "(attentionto off)");
+        let cmd = CommandAttentionTo { target: AttentionToTarget::Player { team: AttentionTeam::Opp, unum: 9 } };
+        assert_eq!(cmd.encode(), "(attentionto opp 9)");
+    }
+}
@@ -0,0 +1,53 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, format};
+use serde::{Deserialize, Serialize};
+
+use super::{Command, PlayerCommand};
+use crate::utils::sexp::Sexp;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandCatch {
+    pub dir: f32,
+}
+
+impl Command for CommandCatch {
+    type Kind = PlayerCommand;
+    type Ok = CommandCatchOk;
+    type Error = CommandCatchError;
+
+    fn kind(&self) -> Self::Kind {
+        PlayerCommand::Catch
+    }
+
+    fn encode(&self) -> ArcStr {
+        format!("(catch {})", self.dir)
+    }
+
+    // never ok
+
+    fn parse_ret_err(tokens: &[Sexp]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+pub type CommandCatchOk = ();
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandCatchError {
+    #[error("The DIRECTION argument was omitted or malformed.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandCatchError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandCatchError as FromStr>::Err> {
+        match s {
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, format};
+use serde::{Deserialize, Serialize};
+
+use super::{Command, PlayerCommand};
+use crate::types::{ViewQuality, ViewWidth};
+use crate::utils::sexp::Sexp;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandChangeView {
+    pub width: ViewWidth,
+    /// Omitted since v18, where quality is always high.
+    #[serde(default)]
+    pub quality: Option<ViewQuality>,
+}
+
+impl Command for CommandChangeView {
+    type Kind = PlayerCommand;
+    type Ok = CommandChangeViewOk;
+    type Error = CommandChangeViewError;
+
+    fn kind(&self) -> Self::Kind {
+        PlayerCommand::ChangeView
+    }
+
+    fn encode(&self) -> ArcStr {
+        match self.quality {
+            Some(quality) => format!("(change_view {} {})", self.width.encode(), quality.encode()),
+            None => format!("(change_view {})", self.width.encode()),
+        }
+    }
+
+    // never ok
+
+    fn parse_ret_err(tokens: &[Sexp]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+pub type CommandChangeViewOk = ();
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandChangeViewError {
+    #[error("The WIDTH argument was omitted or malformed.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandChangeViewError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandChangeViewError as FromStr>::Err> {
+        match s {
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_change_view() {
+        let cmd = CommandChangeView { width: ViewWidth::Wide, quality: None };
+        assert_eq!(cmd.encode(), "(change_view wide)");
+        let cmd = CommandChangeView { width: ViewWidth::Narrow, quality: Some(ViewQuality::Low) };
+        assert_eq!(cmd.encode(), "(change_view narrow low)");
+    }
+}
@@ -0,0 +1,69 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, format};
+use serde::{Deserialize, Serialize};
+
+use super::{Command, PlayerCommand};
+use crate::utils::sexp::Sexp;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandDash {
+    pub power: f32,
+    #[serde(default)]
+    pub dir: Option<f32>,
+}
+
+impl Command for CommandDash {
+    type Kind = PlayerCommand;
+    type Ok = CommandDashOk;
+    type Error = CommandDashError;
+
+    fn kind(&self) -> Self::Kind {
+        PlayerCommand::Dash
+    }
+
+    fn encode(&self) -> ArcStr {
+        match self.dir {
+            Some(dir) => format!("(dash {} {dir})", self.power),
+            None => format!("(dash {})", self.power),
+        }
+    }
+
+    // never ok
+
+    fn parse_ret_err(tokens: &[Sexp]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+pub type CommandDashOk = ();
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandDashError {
+    #[error("The POWER argument was omitted or malformed.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandDashError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandDashError as FromStr>::Err> {
+        match s {
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_dash() {
+        assert_eq!(CommandDash { power: 100.0, dir: None }.encode(), "(dash 100)");
+        assert_eq!(CommandDash { power: 50.5, dir: Some(-90.0) }.encode(), "(dash 50.5 -90)");
+    }
+}
@@ -1,7 +1,8 @@
 use std::str::FromStr;
 use crate::types;
 use super::{Command, PlayerCommand};
-use arcstr::{ArcStr, format, literal};
+use crate::utils::sexp::Sexp;
+use arcstr::ArcStr;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug)]
@@ -46,25 +47,40 @@ impl Command for CommandInit {
         ret.into()
     }
 
-    fn parse_ret_ok(tokens: &[&str]) -> Option<Self::Ok> {
-        todo!()
-        // tokens.is_empty().then_some(())
+    fn parse_ret_ok(tokens: &[Sexp]) -> Option<Self::Ok> {
+        if tokens.len() != 3 {
+            return None;
+        }
+        Some(CommandInitOk {
+            side: tokens[0].parse_atom()?,
+            unum: tokens[1].parse_atom()?,
+            play_mode: tokens[2].parse_atom()?,
+        })
     }
 
-    // never error
+    fn parse_ret_err(tokens: &[Sexp]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct CommandInitOk {
-    side: types::Side,
-    unum: u8,
-    play_mode: types::PlayMode,
+    pub side: types::Side,
+    pub unum: u8,
+    pub play_mode: types::PlayMode,
 }
 
 #[derive(thiserror::Error, Debug)]
 pub enum CommandInitError {
     #[error("no more team or player or goalie")]
-    NoMoreTeamOrPlayerOrGoalie
+    NoMoreTeamOrPlayerOrGoalie,
+    #[error("the TEAM_NAME was omitted or malformed")]
+    IllegalTeamName,
+    #[error("the protocol version is not supported by the server")]
+    IllegalClientVersion,
 }
 
 impl FromStr for CommandInitError {
@@ -72,7 +88,29 @@ impl FromStr for CommandInitError {
     fn from_str(s: &str) -> Result<Self, <CommandInitError as FromStr>::Err> {
         match s {
             "no_more_team_or_player_or_goalie" => Ok(CommandInitError::NoMoreTeamOrPlayerOrGoalie),
+            "illegal_teamname" => Ok(CommandInitError::IllegalTeamName),
+            "illegal_client_version" => Ok(CommandInitError::IllegalClientVersion),
             _ => Err(())
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_init_reply() {
+        let reply = Sexp::parse("(init r 7 before_kick_off)").unwrap();
+        let ok = CommandInit::parse_ret_ok(&reply.list().unwrap()[1..]).unwrap();
+        assert_eq!(ok.side, types::Side::RIGHT);
+        assert_eq!(ok.unum, 7);
+        assert_eq!(ok.play_mode, types::PlayMode::PM_BeforeKickOff);
+
+        let reply = Sexp::parse("(error no_more_team_or_player_or_goalie)").unwrap();
+        assert!(matches!(
+            CommandInit::parse_ret_err(&reply.list().unwrap()[1..]),
+            Some(CommandInitError::NoMoreTeamOrPlayerOrGoalie)
+        ));
+    }
+}
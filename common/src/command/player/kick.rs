@@ -0,0 +1,54 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, format};
+use serde::{Deserialize, Serialize};
+
+use super::{Command, PlayerCommand};
+use crate::utils::sexp::Sexp;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandKick {
+    pub power: f32,
+    pub dir: f32,
+}
+
+impl Command for CommandKick {
+    type Kind = PlayerCommand;
+    type Ok = CommandKickOk;
+    type Error = CommandKickError;
+
+    fn kind(&self) -> Self::Kind {
+        PlayerCommand::Kick
+    }
+
+    fn encode(&self) -> ArcStr {
+        format!("(kick {} {})", self.power, self.dir)
+    }
+
+    // never ok
+
+    fn parse_ret_err(tokens: &[Sexp]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+pub type CommandKickOk = ();
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandKickError {
+    #[error("The POWER or DIRECTION argument was omitted or malformed.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandKickError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandKickError as FromStr>::Err> {
+        match s {
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
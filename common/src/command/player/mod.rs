@@ -1,44 +1,210 @@
+pub mod attention_to;
+pub mod bye;
+pub mod catch;
+pub mod change_view;
+pub mod dash;
 pub mod init;
+pub mod kick;
+pub mod r#move;
+pub mod point_to;
+pub mod say;
+pub mod score;
+pub mod sense_body;
+pub mod synch_see;
+pub mod tackle;
+pub mod turn;
+pub mod turn_neck;
 
+pub use attention_to::{CommandAttentionTo, AttentionTeam, AttentionToTarget};
+pub use bye::CommandBye;
+pub use catch::CommandCatch;
+pub use change_view::CommandChangeView;
+pub use dash::CommandDash;
 pub use init::CommandInit;
+pub use kick::CommandKick;
+pub use r#move::CommandMove;
+pub use point_to::{CommandPointTo, PointToTarget};
+pub use say::CommandSay;
+pub use score::CommandScore;
+pub use sense_body::CommandSenseBody;
+pub use synch_see::CommandSynchSee;
+pub use tackle::CommandTackle;
+pub use turn::CommandTurn;
+pub use turn_neck::CommandTurnNeck;
 
 use arcstr::{ArcStr, literal};
 use std::any::Any;
 
 use super::{Command, CommandAny};
+use crate::utils::sexp::Sexp;
 
 #[derive(Copy, Clone, Hash, Eq, PartialEq, Debug)]
 pub enum PlayerCommand {
     Init,
+    Dash,
+    Turn,
+    TurnNeck,
+    Kick,
+    Catch,
+    Move,
+    Tackle,
+    Say,
+    PointTo,
+    AttentionTo,
+    ChangeView,
+    SenseBody,
+    Score,
+    SynchSee,
+    Bye,
 }
 
 impl CommandAny for PlayerCommand {
     fn encode(&self) -> ArcStr {
         match self {
             PlayerCommand::Init => literal!("init"),
+            PlayerCommand::Dash => literal!("dash"),
+            PlayerCommand::Turn => literal!("turn"),
+            PlayerCommand::TurnNeck => literal!("turn_neck"),
+            PlayerCommand::Kick => literal!("kick"),
+            PlayerCommand::Catch => literal!("catch"),
+            PlayerCommand::Move => literal!("move"),
+            PlayerCommand::Tackle => literal!("tackle"),
+            PlayerCommand::Say => literal!("say"),
+            PlayerCommand::PointTo => literal!("pointto"),
+            PlayerCommand::AttentionTo => literal!("attentionto"),
+            PlayerCommand::ChangeView => literal!("change_view"),
+            PlayerCommand::SenseBody => literal!("sense_body"),
+            PlayerCommand::Score => literal!("score"),
+            PlayerCommand::SynchSee => literal!("synch_see"),
+            PlayerCommand::Bye => literal!("bye"),
         }
     }
 
     fn decode(s: &str) -> Option<Self> {
         match s {
             "init" => Some(PlayerCommand::Init),
+            "dash" => Some(PlayerCommand::Dash),
+            "turn" => Some(PlayerCommand::Turn),
+            "turn_neck" => Some(PlayerCommand::TurnNeck),
+            "kick" => Some(PlayerCommand::Kick),
+            "catch" => Some(PlayerCommand::Catch),
+            "move" => Some(PlayerCommand::Move),
+            "tackle" => Some(PlayerCommand::Tackle),
+            "say" => Some(PlayerCommand::Say),
+            "pointto" => Some(PlayerCommand::PointTo),
+            "attentionto" => Some(PlayerCommand::AttentionTo),
+            "change_view" => Some(PlayerCommand::ChangeView),
+            "sense_body" => Some(PlayerCommand::SenseBody),
+            "score" => Some(PlayerCommand::Score),
+            "synch_see" => Some(PlayerCommand::SynchSee),
+            "bye" => Some(PlayerCommand::Bye),
             _ => None,
         }
     }
 
-    fn parse_ret_ok(&self, tokens: &[&str]) -> Option<Box<dyn Any + Send>> {
+    fn parse_ret_ok(&self, tokens: &[Sexp]) -> Option<Box<dyn Any + Send>> {
         match self {
             PlayerCommand::Init => {
                 CommandInit::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
             }
+            PlayerCommand::Dash => {
+                CommandDash::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Turn => {
+                CommandTurn::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::TurnNeck => {
+                CommandTurnNeck::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Kick => {
+                CommandKick::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Catch => {
+                CommandCatch::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Move => {
+                CommandMove::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Tackle => {
+                CommandTackle::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Say => {
+                CommandSay::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::PointTo => {
+                CommandPointTo::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::AttentionTo => {
+                CommandAttentionTo::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::ChangeView => {
+                CommandChangeView::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::SenseBody => {
+                CommandSenseBody::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Score => {
+                CommandScore::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::SynchSee => {
+                CommandSynchSee::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Bye => {
+                CommandBye::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
         }
     }
 
-    fn parse_ret_err(&self, tokens: &[&str]) -> Option<Box<dyn Any + Send>> {
+    fn parse_ret_err(&self, tokens: &[Sexp]) -> Option<Box<dyn Any + Send>> {
         match self {
             PlayerCommand::Init => {
                 CommandInit::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
             }
+            PlayerCommand::Dash => {
+                CommandDash::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Turn => {
+                CommandTurn::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::TurnNeck => {
+                CommandTurnNeck::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Kick => {
+                CommandKick::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Catch => {
+                CommandCatch::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Move => {
+                CommandMove::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Tackle => {
+                CommandTackle::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Say => {
+                CommandSay::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::PointTo => {
+                CommandPointTo::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::AttentionTo => {
+                CommandAttentionTo::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::ChangeView => {
+                CommandChangeView::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::SenseBody => {
+                CommandSenseBody::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Score => {
+                CommandScore::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::SynchSee => {
+                CommandSynchSee::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Bye => {
+                CommandBye::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
         }
     }
 }
@@ -0,0 +1,54 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, format};
+use serde::{Deserialize, Serialize};
+
+use super::{Command, PlayerCommand};
+use crate::utils::sexp::Sexp;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandMove {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Command for CommandMove {
+    type Kind = PlayerCommand;
+    type Ok = CommandMoveOk;
+    type Error = CommandMoveError;
+
+    fn kind(&self) -> Self::Kind {
+        PlayerCommand::Move
+    }
+
+    fn encode(&self) -> ArcStr {
+        format!("(move {} {})", self.x, self.y)
+    }
+
+    // never ok
+
+    fn parse_ret_err(tokens: &[Sexp]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+pub type CommandMoveOk = ();
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandMoveError {
+    #[error("The X or Y argument was omitted or malformed.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandMoveError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandMoveError as FromStr>::Err> {
+        match s {
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, format, literal};
+use serde::{Deserialize, Serialize};
+
+use super::{Command, PlayerCommand};
+use crate::utils::sexp::Sexp;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum PointToTarget {
+    Off,
+    Point { dist: f32, dir: f32 },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandPointTo {
+    pub target: PointToTarget,
+}
+
+impl Command for CommandPointTo {
+    type Kind = PlayerCommand;
+    type Ok = CommandPointToOk;
+    type Error = CommandPointToError;
+
+    fn kind(&self) -> Self::Kind {
+        PlayerCommand::PointTo
+    }
+
+    fn encode(&self) -> ArcStr {
+        match self.target {
+            PointToTarget::Off => literal!("(pointto off)"),
+            PointToTarget::Point { dist, dir } => format!("(pointto {dist} {dir})"),
+        }
+    }
+
+    // never ok
+
+    fn parse_ret_err(tokens: &[Sexp]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+pub type CommandPointToOk = ();
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandPointToError {
+    #[error("The DIST or DIR argument was omitted or malformed.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandPointToError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandPointToError as FromStr>::Err> {
+        match s {
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_point_to() {
+        let cmd = CommandPointTo { target: PointToTarget::Off };
+        assert_eq!(cmd.encode(), "(pointto off)");
+        let cmd = CommandPointTo { target: PointToTarget::Point { dist: 10.0, dir: 45.5 } };
+        assert_eq!(cmd.encode(), "(pointto 10 45.5)");
+    }
+}
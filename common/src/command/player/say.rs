@@ -0,0 +1,53 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, format};
+use serde::{Deserialize, Serialize};
+
+use super::{Command, PlayerCommand};
+use crate::utils::sexp::Sexp;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandSay {
+    pub message: String,
+}
+
+impl Command for CommandSay {
+    type Kind = PlayerCommand;
+    type Ok = CommandSayOk;
+    type Error = CommandSayError;
+
+    fn kind(&self) -> Self::Kind {
+        PlayerCommand::Say
+    }
+
+    fn encode(&self) -> ArcStr {
+        format!("(say \"{}\")", self.message)
+    }
+
+    // never ok
+
+    fn parse_ret_err(tokens: &[Sexp]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+pub type CommandSayOk = ();
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandSayError {
+    #[error("The MESSAGE argument was omitted or malformed.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandSayError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandSayError as FromStr>::Err> {
+        match s {
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
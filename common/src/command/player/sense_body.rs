@@ -0,0 +1,43 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, literal};
+use serde::{Deserialize, Serialize};
+
+use super::{Command, PlayerCommand};
+use crate::sensor::SenseBody;
+use crate::utils::sexp::Sexp;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandSenseBody;
+
+impl Command for CommandSenseBody {
+    type Kind = PlayerCommand;
+    type Ok = CommandSenseBodyOk;
+    type Error = CommandSenseBodyError;
+
+    fn kind(&self) -> Self::Kind {
+        PlayerCommand::SenseBody
+    }
+
+    fn encode(&self) -> ArcStr {
+        literal!("(sense_body)")
+    }
+
+    fn parse_ret_ok(tokens: &[Sexp]) -> Option<Self::Ok> {
+        SenseBody::parse(tokens)
+    }
+
+    // never error
+}
+
+pub type CommandSenseBodyOk = SenseBody;
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandSenseBodyError {}
+
+impl FromStr for CommandSenseBodyError {
+    type Err = ();
+    fn from_str(_: &str) -> Result<Self, <CommandSenseBodyError as FromStr>::Err> {
+        Err(())
+    }
+}
@@ -0,0 +1,42 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, literal};
+use serde::{Deserialize, Serialize};
+
+use super::{Command, PlayerCommand};
+use crate::utils::sexp::Sexp;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandSynchSee;
+
+impl Command for CommandSynchSee {
+    type Kind = PlayerCommand;
+    type Ok = CommandSynchSeeOk;
+    type Error = CommandSynchSeeError;
+
+    fn kind(&self) -> Self::Kind {
+        PlayerCommand::SynchSee
+    }
+
+    fn encode(&self) -> ArcStr {
+        literal!("(synch_see)")
+    }
+
+    fn parse_ret_ok(tokens: &[Sexp]) -> Option<Self::Ok> {
+        tokens.is_empty().then_some(())
+    }
+
+    // never error
+}
+
+pub type CommandSynchSeeOk = ();
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandSynchSeeError {}
+
+impl FromStr for CommandSynchSeeError {
+    type Err = ();
+    fn from_str(_: &str) -> Result<Self, <CommandSynchSeeError as FromStr>::Err> {
+        Err(())
+    }
+}
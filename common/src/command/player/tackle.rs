@@ -0,0 +1,72 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, format};
+use serde::{Deserialize, Serialize};
+
+use super::{Command, PlayerCommand};
+use crate::utils::sexp::Sexp;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandTackle {
+    /// Power before v12, direction since.
+    pub power_or_dir: f32,
+    /// Intentional foul, since v14.
+    #[serde(default)]
+    pub foul: Option<bool>,
+}
+
+impl Command for CommandTackle {
+    type Kind = PlayerCommand;
+    type Ok = CommandTackleOk;
+    type Error = CommandTackleError;
+
+    fn kind(&self) -> Self::Kind {
+        PlayerCommand::Tackle
+    }
+
+    fn encode(&self) -> ArcStr {
+        match self.foul {
+            Some(foul) => format!("(tackle {} {foul})", self.power_or_dir),
+            None => format!("(tackle {})", self.power_or_dir),
+        }
+    }
+
+    // never ok
+
+    fn parse_ret_err(tokens: &[Sexp]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+pub type CommandTackleOk = ();
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandTackleError {
+    #[error("The POWER_OR_DIR argument was omitted or malformed.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandTackleError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandTackleError as FromStr>::Err> {
+        match s {
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_tackle() {
+        assert_eq!(CommandTackle { power_or_dir: 30.0, foul: None }.encode(), "(tackle 30)");
+        assert_eq!(CommandTackle { power_or_dir: -30.0, foul: Some(true) }.encode(), "(tackle -30 true)");
+        assert_eq!(CommandTackle { power_or_dir: 0.0, foul: Some(false) }.encode(), "(tackle 0 false)");
+    }
+}
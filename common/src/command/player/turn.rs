@@ -0,0 +1,53 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, format};
+use serde::{Deserialize, Serialize};
+
+use super::{Command, PlayerCommand};
+use crate::utils::sexp::Sexp;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandTurn {
+    pub moment: f32,
+}
+
+impl Command for CommandTurn {
+    type Kind = PlayerCommand;
+    type Ok = CommandTurnOk;
+    type Error = CommandTurnError;
+
+    fn kind(&self) -> Self::Kind {
+        PlayerCommand::Turn
+    }
+
+    fn encode(&self) -> ArcStr {
+        format!("(turn {})", self.moment)
+    }
+
+    // never ok
+
+    fn parse_ret_err(tokens: &[Sexp]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+pub type CommandTurnOk = ();
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandTurnError {
+    #[error("The MOMENT argument was omitted or malformed.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandTurnError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandTurnError as FromStr>::Err> {
+        match s {
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, format};
+use serde::{Deserialize, Serialize};
+
+use super::{Command, PlayerCommand};
+use crate::utils::sexp::Sexp;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandTurnNeck {
+    pub angle: f32,
+}
+
+impl Command for CommandTurnNeck {
+    type Kind = PlayerCommand;
+    type Ok = CommandTurnNeckOk;
+    type Error = CommandTurnNeckError;
+
+    fn kind(&self) -> Self::Kind {
+        PlayerCommand::TurnNeck
+    }
+
+    fn encode(&self) -> ArcStr {
+        format!("(turn_neck {})", self.angle)
+    }
+
+    // never ok
+
+    fn parse_ret_err(tokens: &[Sexp]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+pub type CommandTurnNeckOk = ();
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandTurnNeckError {
+    #[error("The ANGLE argument was omitted or malformed.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandTurnNeckError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandTurnNeckError as FromStr>::Err> {
+        match s {
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
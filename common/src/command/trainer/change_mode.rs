@@ -5,6 +5,7 @@ use arcstr::{ArcStr, format};
 use serde::{Deserialize, Serialize};
 
 use super::{Command, CommandAny, TrainerCommand};
+use crate::utils::sexp::Sexp;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct CommandChangeMode {
@@ -24,16 +25,15 @@ impl Command for CommandChangeMode {
         format!("({} {})", self.kind().encode(), self.play_mode.encode())
     }
 
-    fn parse_ret_err(tokens: &[&str]) -> Option<Self::Error> {
+    fn parse_ret_ok(tokens: &[Sexp]) -> Option<Self::Ok> {
+        tokens.is_empty().then_some(())
+    }
+
+    fn parse_ret_err(tokens: &[Sexp]) -> Option<Self::Error> {
         if tokens.len() != 1 {
             return None;
         }
-        let tokens = tokens.join(" ");
-        if tokens.is_empty() {
-            return None;
-        }
-
-        tokens.parse().ok()
+        tokens[0].parse_atom()
     }
 }
 pub type CommandChangeModeOk = ();
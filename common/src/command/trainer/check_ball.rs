@@ -5,6 +5,7 @@ use arcstr::{ArcStr, literal};
 use serde::{Deserialize, Serialize};
 
 use super::{Command, TrainerCommand};
+use crate::utils::sexp::Sexp;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct CommandCheckBall;
@@ -20,15 +21,15 @@ impl Command for CommandCheckBall {
         literal!("(check_ball)")
     }
 
-    fn parse_ret_ok(tokens: &[&str]) -> Option<Self::Ok>
+    fn parse_ret_ok(tokens: &[Sexp]) -> Option<Self::Ok>
     where
         Self: Sized,
     {
         if tokens.len() != 2 {
             return None;
         }
-        let time = tokens[0].parse::<u16>().ok()?;
-        let position = tokens[1].parse::<BallPosition>().ok()?;
+        let time = tokens[0].parse_atom::<u16>()?;
+        let position = tokens[1].parse_atom::<BallPosition>()?;
         Some(CommandCheckBallOk { time, position })
     }
 
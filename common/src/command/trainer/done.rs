@@ -0,0 +1,41 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, literal};
+use serde::{Deserialize, Serialize};
+
+use super::{Command, TrainerCommand};
+
+/// `(done)`, only meaningful in synch mode: the trainer has finished thinking
+/// for the current cycle, the server advances once every client has sent it.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandDone;
+
+impl Command for CommandDone {
+    type Kind = TrainerCommand;
+    type Ok = CommandDoneOk;
+    type Error = CommandDoneError;
+
+    fn kind(&self) -> Self::Kind {
+        TrainerCommand::Done
+    }
+
+    fn encode(&self) -> ArcStr {
+        literal!("(done)")
+    }
+
+    // never ok
+
+    // never error
+}
+
+pub type CommandDoneOk = ();
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandDoneError {}
+
+impl FromStr for CommandDoneError {
+    type Err = ();
+    fn from_str(_: &str) -> Result<Self, <CommandDoneError as FromStr>::Err> {
+        Err(())
+    }
+}
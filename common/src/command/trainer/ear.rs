@@ -5,6 +5,7 @@ use arcstr::{ArcStr, format};
 use serde::{Deserialize, Serialize};
 
 use super::{Command, CommandAny, TrainerCommand};
+use crate::utils::sexp::Sexp;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct CommandEar {
@@ -23,18 +24,18 @@ impl Command for CommandEar {
         format!("(ear {})", self.mode.encode())
     }
 
-    fn parse_ret_ok(tokens: &[&str]) -> Option<Self::Ok> {
+    fn parse_ret_ok(tokens: &[Sexp]) -> Option<Self::Ok> {
         if tokens.len() != 1 {
             return None;
         }
-        tokens[0].parse().ok()
+        tokens[0].parse_atom()
     }
 
-    fn parse_ret_err(tokens: &[&str]) -> Option<Self::Error> {
+    fn parse_ret_err(tokens: &[Sexp]) -> Option<Self::Error> {
         if tokens.len() != 1 {
             return None;
         }
-        tokens[0].parse().ok()
+        tokens[0].parse_atom()
     }
 }
 
@@ -1,6 +1,7 @@
 use std::str::FromStr;
 
 use super::{Command, TrainerCommand};
+use crate::utils::sexp::Sexp;
 use arcstr::{ArcStr, format, literal};
 use serde::{Deserialize, Serialize};
 
@@ -26,7 +27,7 @@ impl Command for CommandInit {
         }
     }
 
-    fn parse_ret_ok(tokens: &[&str]) -> Option<Self::Ok> {
+    fn parse_ret_ok(tokens: &[Sexp]) -> Option<Self::Ok> {
         tokens.is_empty().then_some(())
     }
 
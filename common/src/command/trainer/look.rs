@@ -1,6 +1,8 @@
 use std::str::FromStr;
 
 use super::{Command, TrainerCommand};
+use crate::types::WorldState;
+use crate::utils::sexp::Sexp;
 use arcstr::{ArcStr, literal};
 use serde::{Deserialize, Serialize};
 
@@ -19,14 +21,14 @@ impl Command for CommandLook {
         literal!("(look)")
     }
 
-    fn parse_ret_ok(tokens: &[&str]) -> Option<Self::Ok> {
-        todo!("really complex to implement")
+    fn parse_ret_ok(tokens: &[Sexp]) -> Option<Self::Ok> {
+        WorldState::parse(tokens)
     }
 
     // never error
 }
 
-pub type CommandLookOk = ();
+pub type CommandLookOk = WorldState;
 
 #[derive(thiserror::Error, Debug)]
 pub enum CommandLookError {}
@@ -1,13 +1,67 @@
 use std::str::FromStr;
 
-use arcstr::ArcStr;
+use arcstr::{ArcStr, format, literal};
 use serde::{Deserialize, Serialize};
 
-use super::{Command, TrainerCommand};
+use super::{Command, CommandAny, TrainerCommand};
+use crate::utils::sexp::Sexp;
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum MoveTarget {
+    Ball,
+    Player { team: String, unum: u8 },
+}
+
+impl MoveTarget {
+    pub fn encode(&self) -> ArcStr {
+        match self {
+            MoveTarget::Ball => literal!("(ball)"),
+            MoveTarget::Player { team, unum } => format!("(player {team} {unum})"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct MoveVelocity {
+    pub x: f32,
+    pub y: f32,
+}
+
+/// `(move OBJECT X Y [VDIR [DELTA_X DELTA_Y]])`
+///
+/// rcssserver only reads the velocity after a direction, so `vel` without `dir`
+/// is encoded with a direction of `0`.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct CommandMove {
-    pub todo: (),
+    pub target: MoveTarget,
+    pub x: f32,
+    pub y: f32,
+    #[serde(default)]
+    pub dir: Option<f32>,
+    #[serde(default)]
+    pub vel: Option<MoveVelocity>,
+}
+
+impl CommandMove {
+    pub fn ball(x: f32, y: f32) -> Self {
+        Self { target: MoveTarget::Ball, x, y, dir: None, vel: None }
+    }
+
+    pub fn player(team: impl Into<String>, unum: u8, x: f32, y: f32) -> Self {
+        let target = MoveTarget::Player { team: team.into(), unum };
+        Self { target, x, y, dir: None, vel: None }
+    }
+
+    pub fn with_dir(mut self, dir: f32) -> Self {
+        self.dir = Some(dir);
+        self
+    }
+
+    pub fn with_vel(mut self, x: f32, y: f32) -> Self {
+        self.vel = Some(MoveVelocity { x, y });
+        self
+    }
 }
 
 impl Command for CommandMove {
@@ -20,15 +74,23 @@ impl Command for CommandMove {
     }
 
     fn encode(&self) -> ArcStr {
-        todo!()
+        let head = format!("({} {} {} {}", self.kind().encode(), self.target.encode(), self.x, self.y);
+        match (self.dir, self.vel) {
+            (None, None) => format!("{head})"),
+            (Some(dir), None) => format!("{head} {dir})"),
+            (dir, Some(vel)) => format!("{head} {} {} {})", dir.unwrap_or(0.0), vel.x, vel.y),
+        }
     }
 
-    fn parse_ret_ok(tokens: &[&str]) -> Option<Self::Ok> {
+    fn parse_ret_ok(tokens: &[Sexp]) -> Option<Self::Ok> {
         tokens.is_empty().then_some(())
     }
 
-    fn parse_ret_err(tokens: &[&str]) -> Option<Self::Error> {
-        todo!("really complex too")
+    fn parse_ret_err(tokens: &[Sexp]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
     }
 }
 
@@ -36,9 +98,9 @@ pub type CommandMoveOk = ();
 
 #[derive(thiserror::Error, Debug)]
 pub enum CommandMoveError {
-    #[error("The specified mode was not valid.")]
-    IllegalMode,
-    #[error("The PLAY_MODE argument was omitted")]
+    #[error("The OBJECT argument was not `(ball)` or `(player TEAM UNUM)`.")]
+    IllegalObjectForm,
+    #[error("The position arguments were omitted or malformed.")]
     IllegalCommandForm,
 }
 
@@ -46,9 +108,38 @@ impl FromStr for CommandMoveError {
     type Err = ();
     fn from_str(s: &str) -> Result<Self, <CommandMoveError as FromStr>::Err> {
         match s {
-            "illegal_mode" => Ok(Self::IllegalMode),
+            "illegal_object_form" => Ok(Self::IllegalObjectForm),
             "illegal_command_form" => Ok(Self::IllegalCommandForm),
             _ => Err(()),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(CommandMove::ball(0.0, -1.5).encode(), "(move (ball) 0 -1.5)");
+        assert_eq!(
+            CommandMove::player("Helios", 7, 10.0, 5.0).with_dir(90.0).encode(),
+            "(move (player Helios 7) 10 5 90)"
+        );
+        assert_eq!(
+            CommandMove::ball(1.0, 2.0).with_vel(0.5, -0.5).encode(),
+            "(move (ball) 1 2 0 0.5 -0.5)"
+        );
+    }
+
+    #[test]
+    fn test_parse_ret() {
+        assert!(CommandMove::parse_ret_ok(&[]).is_some());
+        assert!(CommandMove::parse_ret_ok(&[Sexp::Atom("x")]).is_none());
+        assert!(matches!(
+            CommandMove::parse_ret_err(&[Sexp::Atom("illegal_object_form")]),
+            Some(CommandMoveError::IllegalObjectForm)
+        ));
+        assert!(CommandMove::parse_ret_err(&[Sexp::Atom("illegal_mode")]).is_none());
+    }
+}
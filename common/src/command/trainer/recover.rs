@@ -4,6 +4,7 @@ use arcstr::{ArcStr, literal};
 use serde::{Deserialize, Serialize};
 
 use super::{Command, TrainerCommand};
+use crate::utils::sexp::Sexp;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct CommandRecover;
@@ -21,7 +22,7 @@ impl Command for CommandRecover {
         literal!("(recover)")
     }
 
-    fn parse_ret_ok(tokens: &[&str]) -> Option<Self::Ok> {
+    fn parse_ret_ok(tokens: &[Sexp]) -> Option<Self::Ok> {
         tokens.is_empty().then_some(())
     }
 
@@ -1,6 +1,7 @@
 use std::str::FromStr;
 
 use super::{Command, TrainerCommand};
+use crate::utils::sexp::Sexp;
 use arcstr::{ArcStr, literal};
 use serde::{Deserialize, Serialize};
 
@@ -19,31 +20,29 @@ impl Command for CommandTeamNames {
         literal!("(team_names)")
     }
 
-    fn parse_ret_ok(tokens: &[&str]) -> Option<Self::Ok> {
-        let parse_team = |team_tokens: &[&str]| {
-            if team_tokens.len() != 3 {
-                return None;
-            }
-            if team_tokens[0] != "team" {
+    fn parse_ret_ok(tokens: &[Sexp]) -> Option<Self::Ok> {
+        let parse_team = |team: &Sexp| {
+            let team_tokens = team.list()?;
+            if team_tokens.len() != 3 || !team_tokens[0].is_atom("team") {
                 return None;
             }
 
-            let team_name = team_tokens[2].to_string();
-            match team_tokens[1] {
+            let team_name = team_tokens[2].atom()?.to_string();
+            match team_tokens[1].atom()? {
                 "l" => Some((Some(team_name), None)),
                 "r" => Some((None, Some(team_name))),
                 _ => None,
             }
         };
 
-        let teams = match tokens.len() {
-            6 => {
-                let team_1 = parse_team(&tokens[0..3])?;
-                let team_2 = parse_team(&tokens[3..6])?;
+        let teams = match tokens {
+            [team_1, team_2] => {
+                let team_1 = parse_team(team_1)?;
+                let team_2 = parse_team(team_2)?;
                 (team_1.0.or(team_2.0), team_1.1.or(team_2.1))
             }
-            3 => parse_team(tokens)?,
-            0 => (None, None),
+            [team] => parse_team(team)?,
+            [] => (None, None),
             _ => return None,
         };
 
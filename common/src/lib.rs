@@ -1,6 +1,9 @@
 pub mod client;
 pub mod command;
 pub mod process;
+pub mod rcg;
+pub mod rcl;
+pub mod sensor;
 pub mod types;
 pub mod udp;
 pub mod utils;
@@ -0,0 +1,236 @@
+//! JSON records, one object per line, optionally wrapped in a top-level array:
+//!
+//! ```text
+//! [
+//! {"type":"header","version":"19.0.0"},
+//! {"type":"server_param","params":{"goal_width":14.02}},
+//! {"type":"playmode","time":0,"mode":"before_kick_off"},
+//! {"type":"team","time":0,"left":{"name":"HELIOS","score":0},"right":{"name":null,"score":0}},
+//! {"type":"show","time":1,"stime":0,"ball":{"x":0,"y":0,"vx":0,"vy":0},"players":[
+//!     {"side":"l","unum":1,"type":0,"state":"0x9","x":-49,"y":0,"vx":0,"vy":0,"body":0,"neck":0,
+//!      "vq":"h","vw":90,"stamina":{"v":8000,"e":1,"r":1,"c":130600},"count":{"kick":0,...}}]},
+//! {"type":"msg","time":0,"board":1,"message":"..."}
+//! ]
+//! ```
+//!
+//! Keys are read leniently, a missing optional key is `None` rather than an error.
+
+use serde_json::{Map, Value};
+
+use crate::types::{BallState, PlayMode, Side, ViewQuality};
+
+use super::{Frame, Msg, Params, Penalties, PlayModeChange, Show, ShowCounts, ShowPlayer, ShowStamina, Team};
+
+type Object = Map<String, Value>;
+
+pub(super) fn parse_line(line: &str) -> Result<Option<Frame>, String> {
+    let line = line.trim_start_matches(['[', ',']).trim_end_matches([']', ',']).trim();
+    if line.is_empty() {
+        return Ok(None);
+    }
+    let Ok(Value::Object(object)) = serde_json::from_str::<Value>(line) else {
+        return Err("json".to_string());
+    };
+    let kind = object.get("type").and_then(Value::as_str).unwrap_or_default();
+    let frame = match kind {
+        "server_param" => params(&object).map(Frame::ServerParam),
+        "player_param" => params(&object).map(Frame::PlayerParam),
+        "player_type" => params(&object).map(Frame::PlayerType),
+        "show" => parse_show(&object).map(Frame::Show),
+        "playmode" => parse_playmode(&object).map(Frame::PlayMode),
+        "team" => parse_team(&object).map(Frame::Team),
+        "msg" => parse_msg(&object).map(Frame::Msg),
+        _ => return Ok(None),
+    };
+    frame.map(Some).ok_or_else(|| kind.to_string())
+}
+
+fn params(object: &Object) -> Option<Params> {
+    // either nested under "params" or inline next to "type"
+    let params = object.get("params").and_then(Value::as_object).unwrap_or(object);
+    Some(params.iter()
+        .filter(|(name, _)| name.as_str() != "type")
+        .map(|(name, value)| {
+            let value = match value {
+                Value::String(s) => s.clone(),
+                Value::Bool(b) => (*b as u8).to_string(),
+                v => v.to_string(),
+            };
+            (name.clone(), value)
+        })
+        .collect())
+}
+
+fn f32_of(object: &Object, key: &str) -> Option<f32> {
+    object.get(key)?.as_f64().map(|v| v as f32)
+}
+
+fn u_of<T: TryFrom<u64>>(object: &Object, key: &str) -> Option<T> {
+    object.get(key)?.as_u64()?.try_into().ok()
+}
+
+fn times(object: &Object) -> Option<(u16, Option<u16>)> {
+    Some((u_of(object, "time")?, u_of(object, "stime")))
+}
+
+fn parse_show(object: &Object) -> Option<Show> {
+    let (time, stoppage) = times(object)?;
+    let ball = object.get("ball")?.as_object()?;
+    let ball = BallState {
+        x: f32_of(ball, "x")?,
+        y: f32_of(ball, "y")?,
+        vx: f32_of(ball, "vx").unwrap_or_default(),
+        vy: f32_of(ball, "vy").unwrap_or_default(),
+    };
+    let players = match object.get("players") {
+        Some(Value::Array(players)) => players.iter()
+            .map(|p| parse_show_player(p.as_object()?))
+            .collect::<Option<_>>()?,
+        _ => Vec::new(),
+    };
+    Some(Show { time, stoppage, ball, players })
+}
+
+fn parse_show_player(object: &Object) -> Option<ShowPlayer> {
+    let state = match object.get("state")? {
+        Value::String(s) => u32::from_str_radix(s.trim_start_matches("0x"), 16).ok()?,
+        v => v.as_u64()?.try_into().ok()?,
+    };
+    let point = match (f32_of(object, "px"), f32_of(object, "py")) {
+        (Some(px), Some(py)) => Some((px, py)),
+        _ => None,
+    };
+    let view_quality = object.get("vq").and_then(Value::as_str).and_then(|q| match q {
+        "h" => Some(ViewQuality::High),
+        "l" => Some(ViewQuality::Low),
+        q => ViewQuality::decode(q),
+    });
+    let stamina = object.get("stamina").and_then(Value::as_object).and_then(|s| {
+        Some(ShowStamina {
+            stamina: f32_of(s, "v")?,
+            effort: f32_of(s, "e")?,
+            recovery: f32_of(s, "r")?,
+            capacity: f32_of(s, "c"),
+        })
+    });
+    let focus = object.get("focus").and_then(Value::as_object).and_then(|f| {
+        Some((Side::decode(f.get("side")?.as_str()?)?, u_of(f, "unum")?))
+    });
+    let counts = object.get("count").and_then(Value::as_object).map(|c| {
+        let count = |key| u_of(c, key).unwrap_or_default();
+        ShowCounts {
+            kick: count("kick"),
+            dash: count("dash"),
+            turn: count("turn"),
+            catch: count("catch"),
+            r#move: count("move"),
+            turn_neck: count("turn_neck"),
+            change_view: count("change_view"),
+            say: count("say"),
+            tackle: count("tackle"),
+            pointto: count("pointto"),
+            attentionto: count("attentionto"),
+        }
+    });
+
+    Some(ShowPlayer {
+        side: Side::decode(object.get("side")?.as_str()?)?,
+        unum: u_of(object, "unum")?,
+        player_type: u_of(object, "type").unwrap_or_default(),
+        state,
+        x: f32_of(object, "x")?,
+        y: f32_of(object, "y")?,
+        vx: f32_of(object, "vx").unwrap_or_default(),
+        vy: f32_of(object, "vy").unwrap_or_default(),
+        body: f32_of(object, "body").unwrap_or_default(),
+        neck: f32_of(object, "neck").unwrap_or_default(),
+        point,
+        view_quality,
+        view_width: f32_of(object, "vw"),
+        stamina,
+        focus,
+        counts,
+    })
+}
+
+fn parse_playmode(object: &Object) -> Option<PlayModeChange> {
+    let (time, stoppage) = times(object)?;
+    let play_mode = PlayMode::decode(object.get("mode")?.as_str()?)?;
+    Some(PlayModeChange { time, stoppage, play_mode })
+}
+
+fn parse_team(object: &Object) -> Option<Team> {
+    let (time, stoppage) = times(object)?;
+    let left = object.get("left")?.as_object()?;
+    let right = object.get("right")?.as_object()?;
+    let name = |team: &Object| team.get("name").and_then(Value::as_str)
+        .filter(|&name| name != "null")
+        .map(str::to_string);
+    let penalties = match (u_of(left, "pen_score"), u_of(right, "pen_score")) {
+        (Some(left_score), Some(right_score)) => Some(Penalties {
+            left_score,
+            left_miss: u_of(left, "pen_miss").unwrap_or_default(),
+            right_score,
+            right_miss: u_of(right, "pen_miss").unwrap_or_default(),
+        }),
+        _ => None,
+    };
+    Some(Team {
+        time,
+        stoppage,
+        left: name(left),
+        right: name(right),
+        score: (u_of(left, "score").unwrap_or_default(), u_of(right, "score").unwrap_or_default()),
+        penalties,
+    })
+}
+
+fn parse_msg(object: &Object) -> Option<Msg> {
+    let (time, stoppage) = times(object)?;
+    Some(Msg {
+        time,
+        stoppage,
+        board: object.get("board")?.as_i64()?.try_into().ok()?,
+        message: object.get("message")?.as_str()?.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rcg::{RcgFormat, RcgReader};
+
+    use super::*;
+
+    const LOG: &str = r#"[
+{"type":"header","version":"19.0.0"},
+{"type":"server_param","params":{"goal_width":14.02,"synch_mode":false}},
+{"type":"playmode","time":0,"mode":"before_kick_off"},
+{"type":"team","time":0,"left":{"name":"HELIOS","score":0},"right":{"name":null,"score":0}},
+{"type":"show","time":1,"stime":2,"ball":{"x":1.5,"y":0,"vx":0,"vy":0},"players":[{"side":"l","unum":1,"type":0,"state":"0x9","x":-49,"y":0,"vx":0,"vy":0,"body":0,"neck":0,"vq":"h","vw":90,"stamina":{"v":8000,"e":1,"r":1,"c":130600},"count":{"kick":4}}]},
+{"type":"msg","time":1,"board":1,"message":"(team_graphic_l)"}
+]"#;
+
+    #[test]
+    fn test_read_json_log() {
+        let mut reader = RcgReader::new(LOG.as_bytes());
+        let frames: Vec<Frame> = reader.by_ref().collect::<Result<_, _>>().unwrap();
+        assert_eq!(reader.format(), Some(RcgFormat::Json));
+        assert_eq!(frames.len(), 5);
+
+        let Frame::ServerParam(params) = &frames[0] else { panic!() };
+        assert_eq!((params["goal_width"].as_str(), params["synch_mode"].as_str()), ("14.02", "0"));
+        let Frame::Team(team) = &frames[2] else { panic!() };
+        assert_eq!((team.left.as_deref(), team.right.as_deref()), (Some("HELIOS"), None));
+
+        let Frame::Show(show) = &frames[3] else { panic!() };
+        assert_eq!((show.time, show.stoppage, show.ball.x), (1, Some(2), 1.5));
+        let player = show.player(Side::LEFT, 1).unwrap();
+        assert!(player.is_goalie());
+        assert_eq!(player.view_quality, Some(ViewQuality::High));
+        assert_eq!(player.stamina.unwrap().capacity, Some(130600.0));
+        assert_eq!(player.counts.unwrap().kick, 4);
+
+        let Frame::Msg(msg) = &frames[4] else { panic!() };
+        assert_eq!(msg.message, "(team_graphic_l)");
+    }
+}
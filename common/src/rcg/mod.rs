@@ -0,0 +1,346 @@
+//! Game logs (`.rcg`) as written by rcssserver with `server::game_logging`.
+//!
+//! Both the text logs (`ULG5`/`ULG6` header, one S-expression per line) and the JSON logs
+//! (one object per line, optionally wrapped in a top-level array) are read as a stream of
+//! [`Frame`]s, so a whole match never has to be held in memory:
+//!
+//! ```no_run
+//! use common::rcg::{Frame, RcgReader};
+//!
+//! for frame in RcgReader::open("incomplete.rcg")? {
+//!     if let Frame::Team(team) = frame? {
+//!         println!("{} {}:{} {}", team.left.unwrap_or_default(), team.score.0, team.score.1, team.right.unwrap_or_default());
+//!     }
+//! }
+//! # Ok::<(), common::rcg::RcgError>(())
+//! ```
+
+mod json;
+mod result;
+mod text;
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Lines};
+use std::path::Path;
+
+use log::debug;
+use serde::{Deserialize, Serialize};
+
+use crate::types::{BallState, PlayMode, Side, ViewQuality};
+
+pub use crate::types::Penalties;
+pub use result::{find_logs, read_result};
+
+/// Parameters as logged, values unquoted but otherwise kept as text.
+pub type Params = BTreeMap<String, String>;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Frame {
+    ServerParam(Params),
+    PlayerParam(Params),
+    PlayerType(Params),
+    Show(Show),
+    #[serde(rename = "playmode")]
+    PlayMode(PlayModeChange),
+    Team(Team),
+    Msg(Msg),
+}
+
+impl Frame {
+    /// The cycle of the record, parameters are logged before the match and have none.
+    pub fn time(&self) -> Option<u16> {
+        match self {
+            Frame::Show(show) => Some(show.time),
+            Frame::PlayMode(change) => Some(change.time),
+            Frame::Team(team) => Some(team.time),
+            Frame::Msg(msg) => Some(msg.time),
+            Frame::ServerParam(_) | Frame::PlayerParam(_) | Frame::PlayerType(_) => None,
+        }
+    }
+}
+
+/// `(show TIME [STIME] ((b) X Y VX VY) ((SIDE UNUM) TYPE STATE X Y VX VY BODY NECK ...) ...)`
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Show {
+    pub time: u16,
+    /// Cycles elapsed while the clock was stopped, only logged by recent releases.
+    pub stoppage: Option<u16>,
+    pub ball: BallState,
+    pub players: Vec<ShowPlayer>,
+}
+
+impl Show {
+    pub fn player(&self, side: Side, unum: u8) -> Option<&ShowPlayer> {
+        self.players.iter().find(|p| p.side == side && p.unum == unum)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ShowPlayer {
+    pub side: Side,
+    pub unum: u8,
+    pub player_type: u8,
+    /// Bit flags, `0` when the player is not on the field, see [`ShowPlayer::is_enabled`].
+    pub state: u32,
+    pub x: f32,
+    pub y: f32,
+    pub vx: f32,
+    pub vy: f32,
+    pub body: f32,
+    pub neck: f32,
+    pub point: Option<(f32, f32)>,
+    pub view_quality: Option<ViewQuality>,
+    /// View angle in degrees.
+    pub view_width: Option<f32>,
+    pub stamina: Option<ShowStamina>,
+    pub focus: Option<(Side, u8)>,
+    pub counts: Option<ShowCounts>,
+}
+
+impl ShowPlayer {
+    pub const STATE_GOALIE: u32 = 0x0008;
+    pub const STATE_KICK: u32 = 0x0002;
+    pub const STATE_TACKLE: u32 = 0x1000;
+    pub const STATE_RED_CARD: u32 = 0x80000;
+
+    pub fn is_enabled(&self) -> bool {
+        self.state != 0
+    }
+
+    pub fn is_goalie(&self) -> bool {
+        self.state & Self::STATE_GOALIE != 0
+    }
+}
+
+/// `(s STAMINA EFFORT RECOVERY [CAPACITY])`
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct ShowStamina {
+    pub stamina: f32,
+    pub effort: f32,
+    pub recovery: f32,
+    pub capacity: Option<f32>,
+}
+
+/// `(c KICK DASH TURN CATCH MOVE TURN_NECK CHANGE_VIEW SAY TACKLE POINTTO ATTENTIONTO)`
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default)]
+pub struct ShowCounts {
+    pub kick: u32,
+    pub dash: u32,
+    pub turn: u32,
+    pub catch: u32,
+    pub r#move: u32,
+    pub turn_neck: u32,
+    pub change_view: u32,
+    pub say: u32,
+    pub tackle: u32,
+    pub pointto: u32,
+    pub attentionto: u32,
+}
+
+/// `(playmode TIME [STIME] MODE)`
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PlayModeChange {
+    pub time: u16,
+    pub stoppage: Option<u16>,
+    pub play_mode: PlayMode,
+}
+
+/// `(team TIME [STIME] LEFT RIGHT LEFT_SCORE RIGHT_SCORE [LEFT_PEN_SCORE LEFT_PEN_MISS RIGHT_PEN_SCORE RIGHT_PEN_MISS])`
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Team {
+    pub time: u16,
+    pub stoppage: Option<u16>,
+    /// `None` until the team has connected, logged as `null`.
+    pub left: Option<String>,
+    pub right: Option<String>,
+    pub score: (u8, u8),
+    pub penalties: Option<Penalties>,
+}
+
+/// `(msg TIME [STIME] BOARD "MESSAGE")`, e.g. team graphics or what the online coach said.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Msg {
+    pub time: u16,
+    pub stoppage: Option<u16>,
+    pub board: i32,
+    pub message: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RcgFormat {
+    Text { version: u8 },
+    Json,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum RcgError {
+    #[error("Failed to read game log: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Unknown game log header: '{0}'")]
+    UnknownHeader(String),
+    #[error("Unsupported game log version {0}, expected 5 or 6")]
+    UnsupportedVersion(u8),
+    #[error("Line {line}: malformed '{kind}' record")]
+    Malformed { line: usize, kind: String },
+}
+
+/// Streams the [`Frame`]s of a game log, one line at a time.
+///
+/// Records the model does not cover are skipped, a malformed record yields an error
+/// and the iteration goes on with the next line.
+pub struct RcgReader<R> {
+    lines: Lines<R>,
+    format: Option<RcgFormat>,
+    line: usize,
+}
+
+impl RcgReader<BufReader<File>> {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, RcgError> {
+        Ok(Self::new(BufReader::new(File::open(path)?)))
+    }
+}
+
+impl<R: BufRead> RcgReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self { lines: reader.lines(), format: None, line: 0 }
+    }
+
+    /// Known once the first frame has been read.
+    pub fn format(&self) -> Option<RcgFormat> {
+        self.format
+    }
+
+    fn detect(&mut self, line: &str) -> Result<RcgFormat, RcgError> {
+        if let Some(version) = line.strip_prefix("ULG") {
+            return match version.trim().parse() {
+                Ok(version @ (5 | 6)) => Ok(RcgFormat::Text { version }),
+                Ok(version) => Err(RcgError::UnsupportedVersion(version)),
+                Err(_) => Err(RcgError::UnknownHeader(line.to_string())),
+            };
+        }
+        if line.starts_with(['[', '{']) {
+            return Ok(RcgFormat::Json);
+        }
+        Err(RcgError::UnknownHeader(line.to_string()))
+    }
+}
+
+impl<R: BufRead> Iterator for RcgReader<R> {
+    type Item = Result<Frame, RcgError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let line = match self.lines.next()? {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e.into())),
+            };
+            self.line += 1;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let format = match self.format {
+                Some(format) => format,
+                None => match self.detect(line) {
+                    Ok(format) => {
+                        self.format = Some(format);
+                        // the text header is a line of its own, JSON starts right away
+                        if matches!(format, RcgFormat::Text { .. }) {
+                            continue;
+                        }
+                        format
+                    }
+                    Err(e) => return Some(Err(e)),
+                },
+            };
+
+            let parsed = match format {
+                RcgFormat::Text { .. } => text::parse_line(line),
+                RcgFormat::Json => json::parse_line(line),
+            };
+            match parsed {
+                Ok(Some(frame)) => return Some(Ok(frame)),
+                Ok(None) => debug!("[RcgReader] Skipping line {}: {line:.40}", self.line),
+                Err(kind) => return Some(Err(RcgError::Malformed { line: self.line, kind })),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ULG5: &str = r#"ULG5
+(server_param (goal_width 14.02) (game_log_dir "./log"))
+(player_param (player_types 18))
+(player_type (id 0) (player_speed_max 1.05))
+(msg 0 1 "(team_graphic_l (0 0 \"8 8 1 1\"))")
+(playmode 0 before_kick_off)
+(team 0 HELIOS null 0 0)
+(show 0 ((b) 0 0 0 0) ((l 1) 0 0x9 -49.4 0 0 0 0 0 (v h 90) (s 8000 1 1 130600) (c 0 0 0 0 0 0 0 0 0 0 0)) ((r 1) 0 0 0 0 0 0 0 0 (v h 90) (s 8000 1 1 130600) (c 0 0 0 0 0 0 0 0 0 0 0)))
+(playmode 1 kick_off_l)
+(show 1 ((b) 1.2 -0.5 0.3 0) ((l 1) 3 0x3 -10 5 0.1 0 45 -30 -5 10 (v l 180) (s 7900.5 0.9 1 130000) (f r 9) (c 1 2 3 0 1 0 0 0 0 0 0)))
+(draw 1 (c 0 0 10))
+(team 3000 HELIOS Cyrus 2 1 3 2 4 1)
+"#;
+
+    #[test]
+    fn test_read_text_log() {
+        let mut reader = RcgReader::new(ULG5.as_bytes());
+        let frames: Vec<Frame> = reader.by_ref().collect::<Result<_, _>>().unwrap();
+        assert_eq!(reader.format(), Some(RcgFormat::Text { version: 5 }));
+        assert_eq!(frames.len(), 10);
+
+        let Frame::ServerParam(params) = &frames[0] else { panic!() };
+        assert_eq!(params["game_log_dir"], "./log");
+        let Frame::Msg(msg) = &frames[3] else { panic!() };
+        assert_eq!((msg.board, msg.message.as_str()), (1, "(team_graphic_l (0 0 \"8 8 1 1\"))"));
+        let Frame::Team(team) = &frames[5] else { panic!() };
+        assert_eq!((team.left.as_deref(), team.right.as_deref()), (Some("HELIOS"), None));
+
+        let Frame::Show(show) = &frames[8] else { panic!() };
+        assert_eq!(show.time, 1);
+        assert_eq!(show.ball.x, 1.2);
+        let player = show.player(Side::LEFT, 1).unwrap();
+        assert_eq!((player.player_type, player.state), (3, 0x3));
+        assert_eq!(player.point, Some((-5.0, 10.0)));
+        assert_eq!(player.view_width, Some(180.0));
+        assert_eq!(player.focus, Some((Side::RIGHT, 9)));
+        assert_eq!(player.stamina.unwrap().stamina, 7900.5);
+        assert_eq!(player.counts.unwrap().turn, 3);
+
+        let Frame::PlayMode(change) = &frames[7] else { panic!() };
+        assert_eq!(change.play_mode, PlayMode::PM_KickOff_Left);
+        let Frame::Team(team) = &frames[9] else { panic!() };
+        assert_eq!(team.score, (2, 1));
+        assert_eq!(team.penalties.unwrap().right_score, 4);
+    }
+
+    #[test]
+    fn test_read_text_log_with_stoppage() {
+        let log = "ULG6\n(playmode 120 3 play_on)\n(show 120 3 ((b) 0 0 0 0))\n(team 120 3 A B 0 0)\n(msg 120 3 0 \"hi\")\n";
+        let frames: Vec<Frame> = RcgReader::new(log.as_bytes()).collect::<Result<_, _>>().unwrap();
+        assert_eq!(frames.len(), 4);
+        let Frame::Show(show) = &frames[1] else { panic!() };
+        assert_eq!((show.time, show.stoppage), (120, Some(3)));
+        assert!(frames.iter().all(|f| f.time() == Some(120)));
+    }
+
+    #[test]
+    fn test_malformed_and_unknown() {
+        let log = "ULG5\n(show x)\n(playmode 1 play_on)\n";
+        let mut reader = RcgReader::new(log.as_bytes());
+        assert!(matches!(reader.next(), Some(Err(RcgError::Malformed { line: 2, .. }))));
+        assert!(matches!(reader.next(), Some(Ok(Frame::PlayMode(_)))));
+        assert!(reader.next().is_none());
+
+        let mut reader = RcgReader::new("ULG3\n".as_bytes());
+        assert!(matches!(reader.next(), Some(Err(RcgError::UnsupportedVersion(3)))));
+        let mut reader = RcgReader::new("<html>\n".as_bytes());
+        assert!(matches!(reader.next(), Some(Err(RcgError::UnknownHeader(_)))));
+    }
+}
@@ -0,0 +1,112 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use log::warn;
+
+use crate::types::{EndReason, MatchLogs, MatchResult, PlayMode, ResultSource, Score};
+
+use super::{Frame, RcgError, RcgReader};
+
+/// Read the result of a finished match back from its game log.
+///
+/// Names, score and shoot-out come from the last `team` record. A log without `time_over`
+/// was cut short, and is reported as [`EndReason::Aborted`].
+pub fn read_result(path: impl AsRef<Path>) -> Result<MatchResult, RcgError> {
+    let path = path.as_ref();
+    let mut team = None;
+    let mut cycles = 0;
+    let mut time_over = false;
+
+    for frame in RcgReader::open(path)? {
+        let frame = match frame {
+            Ok(frame) => frame,
+            Err(RcgError::Malformed { line, kind }) => {
+                warn!("[rcg] Skipping malformed '{kind}' record at {}:{line}", path.display());
+                continue
+            },
+            Err(e) => return Err(e),
+        };
+        cycles = frame.time().unwrap_or(cycles).max(cycles);
+        match frame {
+            Frame::Team(t) => team = Some(t),
+            Frame::PlayMode(change) => time_over = change.play_mode == PlayMode::PM_TimeOver,
+            _ => {},
+        }
+    }
+
+    let (score, penalties, team_l, team_r) = match team {
+        Some(t) => (Score { left: t.score.0, right: t.score.1 }, t.penalties, t.left, t.right),
+        None => (Score::default(), None, None, None),
+    };
+    let mut ret = MatchResult::new(score, penalties, ResultSource::GameLog);
+    ret.team_l = team_l;
+    ret.team_r = team_r;
+    ret.cycles = cycles;
+    ret.end_reason = Some(if time_over { EndReason::TimeUp } else { EndReason::Aborted });
+    ret.logs.game_log = Some(path.to_path_buf());
+    Ok(ret)
+}
+
+/// The most recent `.rcg` and `.rcl` in `dirs`, preferring the command log named after the game log.
+///
+/// rcssserver writes to `incomplete.rcg` and only renames it when the match is over.
+pub fn find_logs<P: AsRef<Path>>(dirs: &[P]) -> MatchLogs {
+    let game_log = newest(dirs, "rcg");
+    let text_log = game_log.as_ref()
+        .and_then(|rcg| rcg.file_name())
+        .and_then(|name| {
+            dirs.iter()
+                .map(|dir| dir.as_ref().join(name).with_extension("rcl"))
+                .find(|rcl| rcl.is_file())
+        })
+        .or_else(|| newest(dirs, "rcl"));
+    MatchLogs { game_log, text_log }
+}
+
+fn newest<P: AsRef<Path>>(dirs: &[P], extension: &str) -> Option<PathBuf> {
+    dirs.iter()
+        .filter_map(|dir| fs::read_dir(dir).ok())
+        .flatten()
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let path = entry.path();
+            if path.extension()? != extension {
+                return None;
+            }
+            let modified = entry.metadata().and_then(|m| m.modified()).unwrap_or(SystemTime::UNIX_EPOCH);
+            Some((modified, path))
+        })
+        .max()
+        .map(|(_, path)| path)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::Side;
+
+    use super::*;
+
+    #[test]
+    fn test_read_result() {
+        let dir = std::env::temp_dir().join(format!("rcg-result-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let rcg = dir.join("202601011200-HELIOS_1-vs-Cyrus_1.rcg");
+        fs::write(&rcg, "ULG5\n(team 0 HELIOS Cyrus 0 0)\n(playmode 3000 penalty_setup_l)\n\
+            (team 3000 HELIOS Cyrus 1 1 3 2 4 1)\n(show 3001 ((b) 0 0 0 0))\n(playmode 3001 time_over)\n").unwrap();
+        fs::write(dir.join("202601011200-HELIOS_1-vs-Cyrus_1.rcl"), "").unwrap();
+        fs::write(dir.join("notes.txt"), "").unwrap();
+
+        let logs = find_logs(&[&dir]);
+        assert_eq!(logs.game_log.as_deref(), Some(rcg.as_path()));
+        assert_eq!(logs.text_log, Some(rcg.with_extension("rcl")));
+
+        let result = read_result(&rcg).unwrap();
+        assert_eq!(result.score, Score { left: 1, right: 1 });
+        assert_eq!((result.winner, result.winner_name()), (Side::RIGHT, Some("Cyrus")));
+        assert_eq!((result.cycles, result.end_reason), (3001, Some(EndReason::TimeUp)));
+        assert_eq!(result.source, ResultSource::GameLog);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
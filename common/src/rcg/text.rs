@@ -0,0 +1,213 @@
+//! Text records of `ULG5`/`ULG6` logs, one S-expression per line.
+//!
+//! Newer releases log the stoppage time after the cycle of every record, it is told apart
+//! by the shape of the record rather than the header version.
+
+use crate::types::{BallState, PlayMode, Side, ViewQuality};
+use crate::utils::sexp::Sexp;
+
+use super::{Frame, Msg, Params, Penalties, PlayModeChange, Show, ShowCounts, ShowPlayer, ShowStamina, Team};
+
+/// `Ok(None)` for records the model does not cover, `Err` carries the kind of the malformed record.
+pub(super) fn parse_line(line: &str) -> Result<Option<Frame>, String> {
+    let kind = line.strip_prefix('(')
+        .and_then(|rest| rest.split([' ', '(', ')']).next())
+        .unwrap_or(line);
+    let malformed = || kind.to_string();
+
+    // messages are free text with escaped quotes, which `Sexp` does not unescape
+    if kind == "msg" {
+        return parse_msg(line).map(|msg| Some(Frame::Msg(msg))).ok_or_else(malformed);
+    }
+
+    let Ok(sexp) = Sexp::parse(line) else {
+        return Err(malformed());
+    };
+    let Some(items) = sexp.list() else {
+        return Err(malformed());
+    };
+    let frame = match kind {
+        "server_param" => params(&items[1..]).map(Frame::ServerParam),
+        "player_param" => params(&items[1..]).map(Frame::PlayerParam),
+        "player_type" => params(&items[1..]).map(Frame::PlayerType),
+        "show" => parse_show(&items[1..]).map(Frame::Show),
+        "playmode" => parse_playmode(&items[1..]).map(Frame::PlayMode),
+        "team" => parse_team(&items[1..]).map(Frame::Team),
+        _ => return Ok(None),
+    };
+    frame.map(Some).ok_or_else(malformed)
+}
+
+fn params(items: &[Sexp]) -> Option<Params> {
+    items.iter()
+        .map(|item| match item.list()? {
+            [name, value] => Some((name.atom()?.to_string(), value.atom()?.to_string())),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The cycle and, if logged, the stoppage time, followed by the rest of the record.
+fn times<'s, 'a>(items: &'s [Sexp<'a>], fields: usize) -> Option<(u16, Option<u16>, &'s [Sexp<'a>])> {
+    let (time, rest) = items.split_first()?;
+    let time = time.parse_atom()?;
+    if rest.len() > fields {
+        let (stoppage, rest) = rest.split_first()?;
+        return Some((time, Some(stoppage.parse_atom()?), rest));
+    }
+    Some((time, None, rest))
+}
+
+fn parse_show(items: &[Sexp]) -> Option<Show> {
+    let (time, rest) = items.split_first()?;
+    let time = time.parse_atom()?;
+    let (stoppage, rest) = match rest.first()?.atom() {
+        Some(stoppage) => (Some(stoppage.parse().ok()?), &rest[1..]),
+        None => (None, rest),
+    };
+
+    let (ball, players) = rest.split_first()?;
+    let ball = match ball.list()? {
+        [head, x, y, vx, vy] if head.head() == Some("b") => BallState {
+            x: x.parse_atom()?,
+            y: y.parse_atom()?,
+            vx: vx.parse_atom()?,
+            vy: vy.parse_atom()?,
+        },
+        _ => return None,
+    };
+    let players = players.iter().map(parse_show_player).collect::<Option<_>>()?;
+    Some(Show { time, stoppage, ball, players })
+}
+
+/// `((SIDE UNUM) TYPE STATE X Y VX VY BODY NECK [POINT_X POINT_Y] (v Q W) (s ...) [(f SIDE UNUM)] (c ...))`
+fn parse_show_player(item: &Sexp) -> Option<ShowPlayer> {
+    let items = item.list()?;
+    let (id, rest) = items.split_first()?;
+    let (side, unum) = match id.list()? {
+        [side, unum] => (Side::decode(side.atom()?)?, unum.parse_atom()?),
+        _ => return None,
+    };
+
+    let values: Vec<&str> = rest.iter().map_while(Sexp::atom).collect();
+    let (player_type, state, numbers) = match values.as_slice() {
+        [player_type, state, numbers @ ..] => (player_type.parse().ok()?, parse_state(state)?, numbers),
+        _ => return None,
+    };
+    let numbers: Vec<f32> = numbers.iter().map(|n| n.parse().ok()).collect::<Option<_>>()?;
+    let (x, y, vx, vy, body, neck, point) = match *numbers.as_slice() {
+        [x, y, vx, vy, body, neck] => (x, y, vx, vy, body, neck, None),
+        [x, y, vx, vy, body, neck, px, py] => (x, y, vx, vy, body, neck, Some((px, py))),
+        _ => return None,
+    };
+
+    let mut player = ShowPlayer {
+        side, unum, player_type, state, x, y, vx, vy, body, neck, point,
+        view_quality: None,
+        view_width: None,
+        stamina: None,
+        focus: None,
+        counts: None,
+    };
+    for field in &rest[values.len()..] {
+        let (head, values) = field.list().and_then(<[_]>::split_first)?;
+        match head.atom()? {
+            "v" => {
+                let [quality, width] = values else { return None };
+                player.view_quality = Some(match quality.atom()? {
+                    "h" => ViewQuality::High,
+                    "l" => ViewQuality::Low,
+                    q => ViewQuality::decode(q)?,
+                });
+                player.view_width = Some(width.parse_atom()?);
+            },
+            "s" => {
+                let numbers: Vec<f32> = values.iter().map(Sexp::parse_atom).collect::<Option<_>>()?;
+                let &[stamina, effort, recovery, ref capacity @ ..] = numbers.as_slice() else {
+                    return None
+                };
+                let capacity = capacity.first().copied();
+                player.stamina = Some(ShowStamina { stamina, effort, recovery, capacity });
+            },
+            "f" => {
+                let [side, unum] = values else { return None };
+                player.focus = Some((Side::decode(side.atom()?)?, unum.parse_atom()?));
+            },
+            "c" => {
+                let counts: Vec<u32> = values.iter().map(Sexp::parse_atom).collect::<Option<_>>()?;
+                // older logs stop before the pointto and attentionto counts
+                let count = |i: usize| counts.get(i).copied().unwrap_or_default();
+                player.counts = Some(ShowCounts {
+                    kick: count(0),
+                    dash: count(1),
+                    turn: count(2),
+                    catch: count(3),
+                    r#move: count(4),
+                    turn_neck: count(5),
+                    change_view: count(6),
+                    say: count(7),
+                    tackle: count(8),
+                    pointto: count(9),
+                    attentionto: count(10),
+                });
+            },
+            _ => {},
+        }
+    }
+    Some(player)
+}
+
+fn parse_state(s: &str) -> Option<u32> {
+    match s.strip_prefix("0x") {
+        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+        None => s.parse().ok(),
+    }
+}
+
+fn parse_playmode(items: &[Sexp]) -> Option<PlayModeChange> {
+    let (time, stoppage, rest) = times(items, 1)?;
+    let [mode] = rest else { return None };
+    Some(PlayModeChange { time, stoppage, play_mode: PlayMode::decode(mode.atom()?)? })
+}
+
+fn parse_team(items: &[Sexp]) -> Option<Team> {
+    // with and without the four penalty shoot-out counts
+    let fields = if items.len() > 7 { 8 } else { 4 };
+    let (time, stoppage, rest) = times(items, fields)?;
+    let name = |s: &Sexp| s.atom().filter(|&name| name != "null").map(str::to_string);
+    let (left, right, score, penalties) = match rest {
+        [left, right, l, r] => (left, right, (l, r), None),
+        [left, right, l, r, ls, lm, rs, rm] => {
+            let penalties = Penalties {
+                left_score: ls.parse_atom()?,
+                left_miss: lm.parse_atom()?,
+                right_score: rs.parse_atom()?,
+                right_miss: rm.parse_atom()?,
+            };
+            (left, right, (l, r), Some(penalties))
+        },
+        _ => return None,
+    };
+    Some(Team {
+        time,
+        stoppage,
+        left: name(left),
+        right: name(right),
+        score: (score.0.parse_atom()?, score.1.parse_atom()?),
+        penalties,
+    })
+}
+
+/// `(msg TIME [STIME] BOARD "MESSAGE")`
+fn parse_msg(line: &str) -> Option<Msg> {
+    let rest = line.strip_prefix("(msg")?.trim_end().strip_suffix(')')?;
+    let (numbers, message) = rest.split_once('"')?;
+    let message = message.strip_suffix('"')?.replace("\\\"", "\"");
+    let numbers: Vec<&str> = numbers.split_whitespace().collect();
+    let (time, stoppage, board) = match numbers.as_slice() {
+        [time, board] => (time.parse().ok()?, None, board.parse().ok()?),
+        [time, stoppage, board] => (time.parse().ok()?, Some(stoppage.parse().ok()?), board.parse().ok()?),
+        _ => return None,
+    };
+    Some(Msg { time, stoppage, board, message })
+}
@@ -0,0 +1,268 @@
+//! Command logs (`.rcl`) as written by rcssserver with `server::text_logging`.
+//!
+//! Every line is one message the server received, prefixed with the cycle and stoppage time
+//! and a tab:
+//!
+//! ```text
+//! 12,0 Recv HELIOS_3: (dash 100)(turn_neck 30)
+//! 12,0 Recv HELIOS_Coach: (say (freeform "go"))
+//! 12,0 (referee) goal_l_1
+//! ```
+//!
+//! [`RclReader`] streams the player messages as one [`RclRecord`] per command. The log only
+//! names the team, so sides are resolved from the team names given to
+//! [`RclReader::with_teams`], e.g. from a `.rcg` team record.
+
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Lines};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::command::player::{
+    AttentionTeam, AttentionToTarget, CommandAttentionTo, CommandBye, CommandCatch, CommandChangeView,
+    CommandDash, CommandKick, CommandMove, CommandPointTo, CommandSay, CommandScore, CommandSenseBody,
+    CommandSynchSee, CommandTackle, CommandTurn, CommandTurnNeck, PointToTarget,
+};
+use crate::types::{Side, ViewQuality, ViewWidth};
+use crate::utils::sexp::Sexp;
+
+/// One command a player sent during a cycle.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RclRecord {
+    pub time: u16,
+    pub stoppage: u16,
+    /// [`Side::NEUTRAL`] if the team is not one of the names the reader was given.
+    pub side: Side,
+    pub team: String,
+    pub unum: u8,
+    pub command: RclCommand,
+}
+
+/// A player command, decoded into the typed command where the form is recognised.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(tag = "kind", content = "args", rename_all = "snake_case")]
+pub enum RclCommand {
+    Dash(CommandDash),
+    Turn(CommandTurn),
+    TurnNeck(CommandTurnNeck),
+    Kick(CommandKick),
+    Catch(CommandCatch),
+    Move(CommandMove),
+    Tackle(CommandTackle),
+    Say(CommandSay),
+    PointTo(CommandPointTo),
+    AttentionTo(CommandAttentionTo),
+    ChangeView(CommandChangeView),
+    SenseBody(CommandSenseBody),
+    Score(CommandScore),
+    SynchSee(CommandSynchSee),
+    Bye(CommandBye),
+    /// Anything else, including malformed commands the server rejected, as logged.
+    Raw(String),
+}
+
+impl RclCommand {
+    pub fn decode(sexp: &Sexp) -> Self {
+        Self::decode_typed(sexp).unwrap_or_else(|| RclCommand::Raw(sexp.to_string()))
+    }
+
+    fn decode_typed(sexp: &Sexp) -> Option<Self> {
+        let (head, args) = sexp.list()?.split_first()?;
+        let f32_at = |i: usize| args.get(i)?.parse_atom::<f32>();
+        let ret = match (head.atom()?, args.len()) {
+            ("dash", 1 | 2) => RclCommand::Dash(CommandDash { power: f32_at(0)?, dir: f32_at(1) }),
+            ("turn", 1) => RclCommand::Turn(CommandTurn { moment: f32_at(0)? }),
+            ("turn_neck", 1) => RclCommand::TurnNeck(CommandTurnNeck { angle: f32_at(0)? }),
+            ("kick", 2) => RclCommand::Kick(CommandKick { power: f32_at(0)?, dir: f32_at(1)? }),
+            ("catch", 1) => RclCommand::Catch(CommandCatch { dir: f32_at(0)? }),
+            ("move", 2) => RclCommand::Move(CommandMove { x: f32_at(0)?, y: f32_at(1)? }),
+            ("tackle", 1 | 2) => {
+                let foul = match args.get(1).map(|foul| foul.atom()) {
+                    None => None,
+                    Some(Some("true" | "on")) => Some(true),
+                    Some(Some("false" | "off")) => Some(false),
+                    Some(_) => return None,
+                };
+                RclCommand::Tackle(CommandTackle { power_or_dir: f32_at(0)?, foul })
+            },
+            ("say", 1) => RclCommand::Say(CommandSay { message: args[0].atom()?.to_string() }),
+            ("pointto", 1) if args[0].is_atom("off") => {
+                RclCommand::PointTo(CommandPointTo { target: PointToTarget::Off })
+            },
+            ("pointto", 2) => RclCommand::PointTo(CommandPointTo {
+                target: PointToTarget::Point { dist: f32_at(0)?, dir: f32_at(1)? },
+            }),
+            ("attentionto", 1) if args[0].is_atom("off") => {
+                RclCommand::AttentionTo(CommandAttentionTo { target: AttentionToTarget::Off })
+            },
+            ("attentionto", 2) => {
+                let team = match args[0].atom()? {
+                    "our" => AttentionTeam::Our,
+                    "opp" => AttentionTeam::Opp,
+                    _ => return None,
+                };
+                let unum = args[1].parse_atom()?;
+                RclCommand::AttentionTo(CommandAttentionTo { target: AttentionToTarget::Player { team, unum } })
+            },
+            ("change_view", 1 | 2) => RclCommand::ChangeView(CommandChangeView {
+                width: ViewWidth::decode(args[0].atom()?)?,
+                quality: match args.get(1) {
+                    Some(quality) => Some(ViewQuality::decode(quality.atom()?)?),
+                    None => None,
+                },
+            }),
+            ("sense_body", 0) => RclCommand::SenseBody(CommandSenseBody),
+            ("score", 0) => RclCommand::Score(CommandScore),
+            ("synch_see", 0) => RclCommand::SynchSee(CommandSynchSee),
+            ("bye", 0) => RclCommand::Bye(CommandBye),
+            _ => return None,
+        };
+        Some(ret)
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum RclError {
+    #[error("Failed to read command log: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Line {0}: malformed command log entry")]
+    Malformed(usize),
+}
+
+/// Streams the player commands of a command log, one line at a time.
+///
+/// Referee, coach and trainer lines are skipped, a malformed line yields an error and the
+/// iteration goes on with the next line.
+pub struct RclReader<R> {
+    lines: Lines<R>,
+    teams: [Option<String>; 2],
+    pending: VecDeque<RclRecord>,
+    line: usize,
+}
+
+impl RclReader<BufReader<File>> {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, RclError> {
+        Ok(Self::new(BufReader::new(File::open(path)?)))
+    }
+}
+
+impl<R: BufRead> RclReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self { lines: reader.lines(), teams: [None, None], pending: VecDeque::new(), line: 0 }
+    }
+
+    /// Team names of the left and the right side, to resolve [`RclRecord::side`].
+    pub fn with_teams(mut self, left: Option<String>, right: Option<String>) -> Self {
+        self.teams = [left, right];
+        self
+    }
+
+    fn side(&self, team: &str) -> Side {
+        match &self.teams {
+            [Some(left), _] if left == team => Side::LEFT,
+            [_, Some(right)] if right == team => Side::RIGHT,
+            _ => Side::NEUTRAL,
+        }
+    }
+
+    /// `Some(false)` for lines that are not player commands, `None` for malformed ones.
+    fn parse_line(&mut self, line: &str) -> Option<bool> {
+        let (times, message) = line.split_once(char::is_whitespace)?;
+        let (time, stoppage) = match times.split_once(',') {
+            Some((time, stoppage)) => (time.parse().ok()?, stoppage.parse().ok()?),
+            None => (times.parse().ok()?, 0),
+        };
+
+        let Some(message) = message.trim_start().strip_prefix("Recv ") else {
+            return Some(false);
+        };
+        let (sender, commands) = message.split_once(": ")?;
+        // team names may contain underscores, the uniform number is after the last one
+        let Some((team, Ok(unum))) = sender.rsplit_once('_').map(|(team, unum)| (team, unum.parse())) else {
+            return Some(false);
+        };
+
+        let side = self.side(team);
+        for command in Sexp::parse_many(commands).ok()? {
+            self.pending.push_back(RclRecord {
+                time,
+                stoppage,
+                side,
+                team: team.to_string(),
+                unum,
+                command: RclCommand::decode(&command),
+            });
+        }
+        Some(true)
+    }
+}
+
+impl<R: BufRead> Iterator for RclReader<R> {
+    type Item = Result<RclRecord, RclError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(record) = self.pending.pop_front() {
+                return Some(Ok(record));
+            }
+            let line = match self.lines.next()? {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e.into())),
+            };
+            self.line += 1;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            if self.parse_line(line).is_none() {
+                return Some(Err(RclError::Malformed(self.line)));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOG: &str = "0,0\tRecv HELIOS_base_1: (move -49 0)\n\
+        0,0\t(referee) kick_off_l\n\
+        1,0\tRecv HELIOS_base_1: (dash 100 -30)(turn_neck 15)(change_view wide)\n\
+        1,0\tRecv Cyrus_9: (say \"pass me\")(attentionto our 10)(pointto off)(tackle 30 on)\n\
+        1,0\tRecv Cyrus_Coach: (say (freeform \"go\"))\n\
+        1,0\tRecv Coach: (change_mode play_on)\n\
+        2,1\tRecv Cyrus_9: (kick 100)\n\
+        oops\n\
+        3,0\tRecv Cyrus_9: (catch 0)\n";
+
+    #[test]
+    fn test_read_command_log() {
+        let records: Vec<_> = RclReader::new(LOG.as_bytes())
+            .with_teams(Some("HELIOS_base".to_string()), Some("Cyrus".to_string()))
+            .collect();
+        assert_eq!(records.len(), 11);
+        assert!(matches!(records[9], Err(RclError::Malformed(8))));
+
+        let records: Vec<RclRecord> = records.into_iter().filter_map(Result::ok).collect();
+        let first = &records[0];
+        assert_eq!((first.time, first.side, first.team.as_str(), first.unum), (0, Side::LEFT, "HELIOS_base", 1));
+        assert!(matches!(first.command, RclCommand::Move(CommandMove { x: -49.0, y: 0.0 })));
+        assert!(matches!(records[1].command, RclCommand::Dash(CommandDash { power: 100.0, dir: Some(-30.0) })));
+        assert!(matches!(&records[3].command, RclCommand::ChangeView(c) if c.width == ViewWidth::Wide));
+
+        let right: Vec<_> = records.iter().filter(|r| r.side == Side::RIGHT).collect();
+        assert_eq!(right.len(), 6);
+        assert!(matches!(&right[0].command, RclCommand::Say(say) if say.message == "pass me"));
+        assert!(matches!(
+            right[1].command,
+            RclCommand::AttentionTo(CommandAttentionTo { target: AttentionToTarget::Player { unum: 10, .. } }),
+        ));
+        assert!(matches!(right[3].command, RclCommand::Tackle(CommandTackle { foul: Some(true), .. })));
+        // a kick needs a direction, so the server rejected it and it stays raw
+        assert!(matches!(&right[4].command, RclCommand::Raw(raw) if raw == "(kick 100)"));
+        assert_eq!((right[4].time, right[4].stoppage), (2, 1));
+        assert!(matches!(right[5].command, RclCommand::Catch(_)));
+    }
+}
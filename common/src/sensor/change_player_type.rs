@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::sexp::Sexp;
+
+/// `(change_player_type UNUM [TYPE])`, the type is only revealed for teammates.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct ChangePlayerType {
+    pub unum: u8,
+    pub player_type: Option<u8>,
+}
+
+impl ChangePlayerType {
+    /// Parse the tokens following `change_player_type`.
+    pub fn parse(tokens: &[Sexp]) -> Option<Self> {
+        match tokens {
+            [unum] => Some(ChangePlayerType {
+                unum: unum.parse_atom()?,
+                player_type: None,
+            }),
+            [unum, player_type] => Some(ChangePlayerType {
+                unum: unum.parse_atom()?,
+                player_type: Some(player_type.parse_atom()?),
+            }),
+            _ => None,
+        }
+    }
+}
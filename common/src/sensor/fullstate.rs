@@ -0,0 +1,142 @@
+use serde::{Deserialize, Serialize};
+
+use super::sense_body::{Arm, field};
+use crate::types::{PlayMode, Side, ViewQuality, ViewWidth};
+use crate::utils::sexp::Sexp;
+
+/// `(fullstate TIME (pmode MODE) (vmode Q W) (count ...) (arm ...) (score L R) ((b) ...) ((p S N [g] TYPE) ...) ...)`
+///
+/// Only sent with `server::fullstate_l`/`fullstate_r` enabled.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct FullState {
+    pub time: u16,
+    pub play_mode: Option<PlayMode>,
+    pub view_quality: Option<ViewQuality>,
+    pub view_width: Option<ViewWidth>,
+    pub arm: Option<Arm>,
+    pub score: Option<(u8, u8)>,
+    pub ball: Option<FullStateBall>,
+    pub players: Vec<FullStatePlayer>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct FullStateBall {
+    pub x: f32,
+    pub y: f32,
+    pub vx: f32,
+    pub vy: f32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct FullStatePlayer {
+    pub side: Side,
+    pub unum: u8,
+    pub goalie: bool,
+    pub player_type: u8,
+    pub x: f32,
+    pub y: f32,
+    pub vx: f32,
+    pub vy: f32,
+    pub body: f32,
+    pub neck: f32,
+    pub point_dist: Option<f32>,
+    pub point_dir: Option<f32>,
+    /// `(stamina STAMINA EFFORT RECOVERY [CAPACITY])`
+    pub stamina: Option<Vec<f32>>,
+    pub tackling: bool,
+    pub kicking: bool,
+}
+
+impl FullState {
+    /// Parse the tokens following `fullstate`.
+    pub fn parse(tokens: &[Sexp]) -> Option<Self> {
+        let (time, items) = tokens.split_first()?;
+        let mut ret = FullState {
+            time: time.parse_atom()?,
+            ..Default::default()
+        };
+
+        for item in items {
+            let (key, values) = item.list()?.split_first()?;
+            match key {
+                Sexp::Atom("pmode") => ret.play_mode = values.first()?.parse_atom(),
+                Sexp::Atom("vmode") => {
+                    ret.view_quality = values.first()?.parse_atom();
+                    ret.view_width = values.get(1)?.parse_atom();
+                }
+                Sexp::Atom("arm") => {
+                    let target = field(values, "target")?;
+                    ret.arm = Some(Arm {
+                        movable: field(values, "movable")?.first()?.parse_atom()?,
+                        expires: field(values, "expires")?.first()?.parse_atom()?,
+                        target: (target.first()?.parse_atom()?, target.get(1)?.parse_atom()?),
+                        count: field(values, "count")?.first()?.parse_atom()?,
+                    })
+                }
+                Sexp::Atom("score") => {
+                    ret.score = Some((values.first()?.parse_atom()?, values.get(1)?.parse_atom()?))
+                }
+                Sexp::List(name) => match name.first()?.atom()? {
+                    "b" => {
+                        let [x, y, vx, vy] = numbers(values)[..] else { return None };
+                        ret.ball = Some(FullStateBall { x, y, vx, vy });
+                    }
+                    "p" => ret.players.push(FullStatePlayer::parse(name, values)?),
+                    _ => {}
+                },
+                _ => {}
+            }
+        }
+
+        Some(ret)
+    }
+}
+
+impl FullStatePlayer {
+    fn parse(name: &[Sexp], values: &[Sexp]) -> Option<Self> {
+        let side = name.get(1)?.parse_atom()?;
+        let unum = name.get(2)?.parse_atom()?;
+        let (goalie, player_type) = match &name[3..] {
+            [Sexp::Atom("g"), player_type] => (true, player_type.parse_atom()?),
+            [player_type] => (false, player_type.parse_atom()?),
+            _ => return None,
+        };
+
+        let nums = numbers(values);
+        let (x, y, vx, vy, body, neck, point_dist, point_dir) = match nums[..] {
+            [x, y, vx, vy, body, neck] => (x, y, vx, vy, body, neck, None, None),
+            [x, y, vx, vy, body, neck, dist, dir] => (x, y, vx, vy, body, neck, Some(dist), Some(dir)),
+            _ => return None,
+        };
+
+        let stamina = field(values, "stamina")
+            .map(|s| s.iter().map(Sexp::parse_atom).collect::<Option<Vec<f32>>>())
+            .and_then(|s| s);
+
+        Some(FullStatePlayer {
+            side,
+            unum,
+            goalie,
+            player_type,
+            x,
+            y,
+            vx,
+            vy,
+            body,
+            neck,
+            point_dist,
+            point_dir,
+            stamina,
+            tackling: values.iter().any(|v| v.is_atom("t")),
+            kicking: values.iter().any(|v| v.is_atom("k")),
+        })
+    }
+}
+
+/// The leading run of numeric atoms.
+fn numbers(values: &[Sexp]) -> Vec<f32> {
+    values
+        .iter()
+        .map_while(|v| v.atom()?.parse::<f32>().ok())
+        .collect()
+}
@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::Side;
+use crate::utils::sexp::Sexp;
+
+/// `(hear TIME SENDER ["MESSAGE"|(MESSAGE)])`
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Hear {
+    pub time: u16,
+    pub sender: HearSender,
+    /// `None` when a teammate's message exceeded the hear capacity.
+    /// An online coach's CLang or `freeform` message is kept as its s-expression.
+    pub message: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum HearSender {
+    Referee,
+    #[serde(rename = "self")]
+    Myself,
+    OnlineCoach { side: Side },
+    /// `DIR our UNUM` / `DIR opp` for players, or `(p "TEAM" UNUM)` for coaches.
+    Player {
+        dir: Option<f32>,
+        team: Option<String>,
+        unum: Option<u8>,
+    },
+}
+
+impl Hear {
+    /// Parse the tokens following `hear`.
+    pub fn parse(tokens: &[Sexp]) -> Option<Self> {
+        let (time, rest) = tokens.split_first()?;
+        let time = time.parse_atom()?;
+        let (sender, rest) = rest.split_first()?;
+
+        let (sender, rest) = match sender {
+            Sexp::Atom("referee") => (HearSender::Referee, rest),
+            Sexp::Atom("self") => (HearSender::Myself, rest),
+            Sexp::Atom("online_coach_left") => (HearSender::OnlineCoach { side: Side::LEFT }, rest),
+            Sexp::Atom("online_coach_right") => (HearSender::OnlineCoach { side: Side::RIGHT }, rest),
+            Sexp::Atom(dir) => {
+                let dir = dir.parse().ok()?;
+                match rest {
+                    [Sexp::Atom(team @ ("our" | "opp")), rest @ ..] => {
+                        let (unum, rest) = match rest {
+                            [unum, rest @ ..] if unum.parse_atom::<u8>().is_some() => {
+                                (unum.parse_atom(), rest)
+                            }
+                            _ => (None, rest),
+                        };
+                        let team = Some(team.to_string());
+                        (HearSender::Player { dir: Some(dir), team, unum }, rest)
+                    }
+                    _ => (HearSender::Player { dir: Some(dir), team: None, unum: None }, rest),
+                }
+            }
+            Sexp::List(player) => {
+                let team = player.get(1).and_then(Sexp::atom).map(str::to_string);
+                let unum = player.get(2).and_then(Sexp::parse_atom);
+                (HearSender::Player { dir: None, team, unum }, rest)
+            }
+        };
+
+        let message = match rest {
+            [] => None,
+            [Sexp::Atom(message)] => Some(message.to_string()),
+            [message] => Some(message.to_string()),
+            _ => return None,
+        };
+
+        Some(Hear { time, sender, message })
+    }
+}
@@ -0,0 +1,193 @@
+mod change_player_type;
+mod fullstate;
+mod hear;
+mod see;
+mod sense_body;
+
+pub use change_player_type::ChangePlayerType;
+pub use fullstate::{FullState, FullStateBall, FullStatePlayer};
+pub use hear::{Hear, HearSender};
+pub use see::{ObjectKind, See, SeenObject};
+pub use sense_body::{ActionCounts, Arm, Focus, Foul, SenseBody, Stamina, Tackle};
+
+use serde::{Deserialize, Serialize};
+
+use crate::utils::sexp::{Sexp, SexpError};
+
+/// A message pushed by rcssserver to a player, decoded from its S-expression form.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SensorMessage {
+    See(See),
+    Hear(Hear),
+    SenseBody(SenseBody),
+    #[serde(rename = "fullstate")]
+    FullState(FullState),
+    ChangePlayerType(ChangePlayerType),
+    /// `(ok COMMAND ARGS...)`
+    Ok { command: String, args: Vec<String> },
+    /// `(error REASON)`
+    Error { reason: String },
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum SensorError {
+    #[error("Invalid S-expression: {0}")]
+    Sexp(#[from] SexpError),
+    #[error("Unknown sensor message: '{0}'")]
+    Unknown(String),
+    #[error("Malformed '{0}' message")]
+    Malformed(&'static str),
+}
+
+impl SensorMessage {
+    pub fn parse(msg: &str) -> Result<Self, SensorError> {
+        let sexp = Sexp::parse(msg)?;
+        let (head, tokens) = sexp
+            .list()
+            .and_then(|l| l.split_first())
+            .ok_or_else(|| SensorError::Unknown(msg.to_string()))?;
+
+        let head = head.atom().ok_or_else(|| SensorError::Unknown(msg.to_string()))?;
+        let ret = match head {
+            "see" => See::parse(tokens).map(Self::See).ok_or(SensorError::Malformed("see"))?,
+            "hear" => Hear::parse(tokens).map(Self::Hear).ok_or(SensorError::Malformed("hear"))?,
+            "sense_body" => SenseBody::parse(tokens)
+                .map(Self::SenseBody)
+                .ok_or(SensorError::Malformed("sense_body"))?,
+            "fullstate" => FullState::parse(tokens)
+                .map(Self::FullState)
+                .ok_or(SensorError::Malformed("fullstate"))?,
+            "change_player_type" => ChangePlayerType::parse(tokens)
+                .map(Self::ChangePlayerType)
+                .ok_or(SensorError::Malformed("change_player_type"))?,
+            "ok" => {
+                let (command, args) = tokens.split_first().ok_or(SensorError::Malformed("ok"))?;
+                Self::Ok {
+                    command: command.to_string(),
+                    args: args.iter().map(Sexp::to_string).collect(),
+                }
+            }
+            "error" => Self::Error {
+                reason: tokens.iter().map(Sexp::to_string).collect::<Vec<_>>().join(" "),
+            },
+            _ => return Err(SensorError::Unknown(head.to_string())),
+        };
+
+        Ok(ret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{PlayMode, Side, ViewWidth};
+
+    #[test]
+    fn test_parse_see() {
+        let msg = "(see 120 ((f c) 10.2 -15) ((g r) 60.3 4 0 0) ((b) 5.5 -3 0.1 0.2) \
+            ((p \"HELIOS\" 7 goalie) 8.2 20 0 0 45 10 t) ((P) 1.2 170) ((l r) 55 -88))";
+        let SensorMessage::See(see) = SensorMessage::parse(msg).unwrap() else { panic!() };
+
+        assert_eq!(see.time, 120);
+        assert_eq!(see.objects.len(), 6);
+        assert_eq!(see.ball().unwrap().dist_chg, Some(0.1));
+        assert_eq!(see.objects[1].kind, ObjectKind::Goal { side: Some(Side::RIGHT) });
+
+        let goalie = see.players().next().unwrap();
+        assert!(goalie.tackling);
+        assert_eq!(goalie.head_dir, Some(10.0));
+
+        let close = see.players().nth(1).unwrap();
+        assert!(close.close);
+        assert_eq!(close.kind, ObjectKind::Player { team: None, unum: None, goalie: false });
+    }
+
+    #[test]
+    fn test_parse_see_carded_player() {
+        let msg = "(see 40 ((p \"HELIOS\" 4) 12 -8 0 0 90 0 t f y) ((p \"CYRUS\" 2) 20 15 r) ((b) 3 0))";
+        let SensorMessage::See(see) = SensorMessage::parse(msg).unwrap() else { panic!() };
+        assert_eq!(see.objects.len(), 3);
+
+        let players = see.players().collect::<Vec<_>>();
+        assert!(players[0].tackling && players[0].fouled);
+        assert_eq!(players[0].card.as_deref(), Some("yellow"));
+        assert_eq!(players[0].body_dir, Some(90.0));
+
+        assert!(!players[1].fouled);
+        assert_eq!(players[1].card.as_deref(), Some("red"));
+        assert_eq!((players[1].dist, players[1].dir), (Some(20.0), 15.0));
+    }
+
+    #[test]
+    fn test_parse_hear() {
+        let SensorMessage::Hear(hear) = SensorMessage::parse("(hear 0 referee kick_off_l)").unwrap() else { panic!() };
+        assert_eq!(hear.sender, HearSender::Referee);
+        assert_eq!(hear.message.as_deref(), Some("kick_off_l"));
+
+        let SensorMessage::Hear(hear) = SensorMessage::parse("(hear 42 -30 our 3 \"pass me\")").unwrap() else { panic!() };
+        assert_eq!(
+            hear.sender,
+            HearSender::Player { dir: Some(-30.0), team: Some("our".to_string()), unum: Some(3) }
+        );
+        assert_eq!(hear.message.as_deref(), Some("pass me"));
+    }
+
+    #[test]
+    fn test_parse_hear_coach_message() {
+        let msg = "(hear 0 online_coach_left (freeform \"keep the ball\"))";
+        let SensorMessage::Hear(hear) = SensorMessage::parse(msg).unwrap() else { panic!() };
+        assert_eq!(hear.sender, HearSender::OnlineCoach { side: Side::LEFT });
+        assert_eq!(hear.message.as_deref(), Some("(freeform \"keep the ball\")"));
+
+        let msg = "(hear 120 online_coach_right (info (6000 (true) (do our {2} (pos (rec (pt -10 -10) (pt 0 0)))))))";
+        let SensorMessage::Hear(hear) = SensorMessage::parse(msg).unwrap() else { panic!() };
+        assert_eq!(hear.message.as_deref(), Some("(info (6000 (true) (do our {2} (pos (rec (pt -10 -10) (pt 0 0))))))"));
+    }
+
+    #[test]
+    fn test_parse_sense_body() {
+        let msg = "(sense_body 0 (view_mode high normal) (stamina 8000 1 130600) (speed 0 0) \
+            (head_angle 0) (kick 0) (dash 3) (turn 0) (say 0) (turn_neck 0) (catch 0) (move 0) \
+            (change_view 0) (arm (movable 0) (expires 0) (target 0 0) (count 0)) \
+            (focus (target l 9) (count 1)) (tackle (expires 0) (count 0)) (collision none) \
+            (foul (charged 0) (card none)))";
+        let SensorMessage::SenseBody(body) = SensorMessage::parse(msg).unwrap() else { panic!() };
+
+        assert_eq!(body.view_width, Some(ViewWidth::Normal));
+        assert_eq!(body.stamina.unwrap().capacity, Some(130600.0));
+        assert_eq!(body.counts.dash, 3);
+        assert_eq!(body.focus.unwrap().target, Some((Side::LEFT, 9)));
+        assert!(body.collision.is_empty());
+    }
+
+    #[test]
+    fn test_parse_fullstate() {
+        let msg = "(fullstate 10 (pmode play_on) (vmode high normal) (score 1 0) \
+            ((b) 0 0 1.2 0) ((p l 1 g 0) -50 0 0 0 0 0 (stamina 8000 1 1 130600)) \
+            ((p r 2 3) 10 5 0 0 180 0 1.5 30 k))";
+        let SensorMessage::FullState(state) = SensorMessage::parse(msg).unwrap() else { panic!() };
+
+        assert_eq!(state.play_mode, Some(PlayMode::PM_PlayOn));
+        assert_eq!(state.score, Some((1, 0)));
+        assert_eq!(state.players.len(), 2);
+        assert!(state.players[0].goalie);
+        assert_eq!(state.players[0].stamina.as_ref().unwrap().len(), 4);
+        assert_eq!(state.players[1].player_type, 3);
+        assert_eq!(state.players[1].point_dir, Some(30.0));
+        assert!(state.players[1].kicking);
+    }
+
+    #[test]
+    fn test_parse_misc() {
+        assert!(matches!(
+            SensorMessage::parse("(change_player_type 5 12)").unwrap(),
+            SensorMessage::ChangePlayerType(ChangePlayerType { unum: 5, player_type: Some(12) })
+        ));
+        assert!(matches!(
+            SensorMessage::parse("(error unknown_command)").unwrap(),
+            SensorMessage::Error { reason } if reason == "unknown_command"
+        ));
+        assert!(matches!(SensorMessage::parse("(think)"), Err(SensorError::Unknown(_))));
+    }
+}
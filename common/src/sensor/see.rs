@@ -0,0 +1,165 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::Side;
+use crate::utils::sexp::Sexp;
+
+/// `(see TIME ((NAME) [DIST] DIR [DIST_CHG DIR_CHG [BODY_DIR HEAD_DIR [POINT_DIR]]] [t|k] [f] [y|r]) ...)`
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct See {
+    pub time: u16,
+    pub objects: Vec<SeenObject>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ObjectKind {
+    /// Components of the flag name, e.g. `p l t` for `(f p l t)`.
+    Flag { name: String },
+    Goal { side: Option<Side> },
+    Line { name: String },
+    Ball,
+    /// Team and unum are dropped from far to near, see `team_too_far_length`/`unum_too_far_length`.
+    Player {
+        team: Option<String>,
+        unum: Option<u8>,
+        goalie: bool,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SeenObject {
+    #[serde(flatten)]
+    pub kind: ObjectKind,
+    /// Out of the view cone but within `visible_distance`, sent with an upper-case name.
+    pub close: bool,
+    pub dist: Option<f32>,
+    pub dir: f32,
+    pub dist_chg: Option<f32>,
+    pub dir_chg: Option<f32>,
+    pub body_dir: Option<f32>,
+    pub head_dir: Option<f32>,
+    pub point_dir: Option<f32>,
+    pub tackling: bool,
+    pub kicking: bool,
+    /// Charged with a foul.
+    pub fouled: bool,
+    /// `yellow` or `red`, for a player shown a card.
+    pub card: Option<String>,
+}
+
+impl See {
+    /// Parse the tokens following `see`.
+    pub fn parse(tokens: &[Sexp]) -> Option<Self> {
+        let (time, objects) = tokens.split_first()?;
+        let objects = objects
+            .iter()
+            .map(SeenObject::parse)
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(See {
+            time: time.parse_atom()?,
+            objects,
+        })
+    }
+
+    pub fn ball(&self) -> Option<&SeenObject> {
+        self.objects.iter().find(|o| o.kind == ObjectKind::Ball)
+    }
+
+    pub fn players(&self) -> impl Iterator<Item = &SeenObject> {
+        self.objects
+            .iter()
+            .filter(|o| matches!(o.kind, ObjectKind::Player { .. }))
+    }
+}
+
+impl SeenObject {
+    fn parse(object: &Sexp) -> Option<Self> {
+        let (name, values) = object.list()?.split_first()?;
+        let (kind, close) = parse_name(name.list()?)?;
+
+        let mut numbers = Vec::with_capacity(7);
+        let (mut tackling, mut kicking, mut fouled) = (false, false, false);
+        let mut card = None;
+        for value in values {
+            match value.atom()? {
+                "t" => tackling = true,
+                "k" => kicking = true,
+                "f" => fouled = true,
+                "y" => card = Some("yellow".to_string()),
+                "r" => card = Some("red".to_string()),
+                n => numbers.push(n.parse::<f32>().ok()?),
+            }
+        }
+
+        let mut ret = SeenObject {
+            kind,
+            close,
+            dist: None,
+            dir: 0.0,
+            dist_chg: None,
+            dir_chg: None,
+            body_dir: None,
+            head_dir: None,
+            point_dir: None,
+            tackling,
+            kicking,
+            fouled,
+            card,
+        };
+        match numbers[..] {
+            [dir] => ret.dir = dir,
+            [dist, dir, ref rest @ ..] => {
+                ret.dist = Some(dist);
+                ret.dir = dir;
+                match *rest {
+                    [] => {}
+                    [dist_chg, dir_chg, ref rest @ ..] => {
+                        ret.dist_chg = Some(dist_chg);
+                        ret.dir_chg = Some(dir_chg);
+                        match *rest {
+                            [] => {}
+                            [body, head] => (ret.body_dir, ret.head_dir) = (Some(body), Some(head)),
+                            [body, head, point] => {
+                                (ret.body_dir, ret.head_dir) = (Some(body), Some(head));
+                                ret.point_dir = Some(point);
+                            }
+                            _ => return None,
+                        }
+                    }
+                    // pointing players seen from afar: `DIST DIR POINT_DIR`
+                    [point] => ret.point_dir = Some(point),
+                }
+            }
+            _ => return None,
+        }
+
+        Some(ret)
+    }
+}
+
+fn parse_name(name: &[Sexp]) -> Option<(ObjectKind, bool)> {
+    let (head, rest) = name.split_first()?;
+    let rest = rest.iter().map(Sexp::atom).collect::<Option<Vec<_>>>()?;
+    let head = head.atom()?;
+
+    let kind = match head.to_ascii_lowercase().as_str() {
+        "f" => ObjectKind::Flag { name: rest.join(" ") },
+        "g" => ObjectKind::Goal {
+            side: rest.first().and_then(|s| s.parse().ok()),
+        },
+        "l" => ObjectKind::Line { name: rest.join(" ") },
+        "b" => ObjectKind::Ball,
+        "p" => ObjectKind::Player {
+            team: rest.first().map(|t| t.to_string()),
+            unum: match rest.get(1) {
+                Some(unum) => Some(unum.parse().ok()?),
+                None => None,
+            },
+            goalie: rest.get(2) == Some(&"goalie"),
+        },
+        _ => return None,
+    };
+
+    Some((kind, head.chars().all(|c| c.is_ascii_uppercase())))
+}
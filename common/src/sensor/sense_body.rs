@@ -0,0 +1,167 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Side, ViewQuality, ViewWidth};
+use crate::utils::sexp::Sexp;
+
+/// `(sense_body TIME (view_mode Q W) (stamina S E C) (speed A D) (head_angle H) (kick N) ...)`
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct SenseBody {
+    pub time: u16,
+    pub view_quality: Option<ViewQuality>,
+    pub view_width: Option<ViewWidth>,
+    pub stamina: Option<Stamina>,
+    pub speed_amount: f32,
+    pub speed_dir: f32,
+    pub head_angle: f32,
+    pub counts: ActionCounts,
+    pub arm: Option<Arm>,
+    pub focus: Option<Focus>,
+    pub tackle: Option<Tackle>,
+    /// e.g. `ball`, `player`, `post`; empty for `(collision none)`.
+    pub collision: Vec<String>,
+    pub foul: Option<Foul>,
+    pub focus_point: Option<(f32, f32)>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct Stamina {
+    pub stamina: f32,
+    pub effort: f32,
+    pub capacity: Option<f32>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default)]
+pub struct ActionCounts {
+    pub kick: u32,
+    pub dash: u32,
+    pub turn: u32,
+    pub say: u32,
+    pub turn_neck: u32,
+    pub catch: u32,
+    pub r#move: u32,
+    pub change_view: u32,
+    pub change_focus: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct Arm {
+    pub movable: u32,
+    pub expires: u32,
+    pub target: (f32, f32),
+    pub count: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct Focus {
+    pub target: Option<(Side, u8)>,
+    pub count: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct Tackle {
+    pub expires: u32,
+    pub count: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Foul {
+    pub charged: u32,
+    /// `none`, `yellow` or `red`.
+    pub card: String,
+}
+
+impl SenseBody {
+    /// Parse the tokens following `sense_body`.
+    pub fn parse(tokens: &[Sexp]) -> Option<Self> {
+        let (time, items) = tokens.split_first()?;
+        let mut ret = SenseBody {
+            time: time.parse_atom()?,
+            ..Default::default()
+        };
+
+        for item in items {
+            let (key, values) = item.list()?.split_first()?;
+            match key.atom()? {
+                "view_mode" => {
+                    ret.view_quality = values.first()?.parse_atom();
+                    ret.view_width = values.get(1)?.parse_atom();
+                }
+                "stamina" => {
+                    ret.stamina = Some(Stamina {
+                        stamina: values.first()?.parse_atom()?,
+                        effort: values.get(1)?.parse_atom()?,
+                        capacity: values.get(2).and_then(Sexp::parse_atom),
+                    })
+                }
+                "speed" => {
+                    ret.speed_amount = values.first()?.parse_atom()?;
+                    ret.speed_dir = values.get(1).and_then(Sexp::parse_atom).unwrap_or_default();
+                }
+                "head_angle" => ret.head_angle = values.first()?.parse_atom()?,
+                "kick" => ret.counts.kick = values.first()?.parse_atom()?,
+                "dash" => ret.counts.dash = values.first()?.parse_atom()?,
+                "turn" => ret.counts.turn = values.first()?.parse_atom()?,
+                "say" => ret.counts.say = values.first()?.parse_atom()?,
+                "turn_neck" => ret.counts.turn_neck = values.first()?.parse_atom()?,
+                "catch" => ret.counts.catch = values.first()?.parse_atom()?,
+                "move" => ret.counts.r#move = values.first()?.parse_atom()?,
+                "change_view" => ret.counts.change_view = values.first()?.parse_atom()?,
+                "change_focus" => ret.counts.change_focus = values.first()?.parse_atom()?,
+                "arm" => {
+                    let target = field(values, "target")?;
+                    ret.arm = Some(Arm {
+                        movable: field(values, "movable")?.first()?.parse_atom()?,
+                        expires: field(values, "expires")?.first()?.parse_atom()?,
+                        target: (target.first()?.parse_atom()?, target.get(1)?.parse_atom()?),
+                        count: field(values, "count")?.first()?.parse_atom()?,
+                    })
+                }
+                "focus" => {
+                    let target = match field(values, "target")? {
+                        [side, unum] => Some((side.parse_atom()?, unum.parse_atom()?)),
+                        _ => None,
+                    };
+                    ret.focus = Some(Focus {
+                        target,
+                        count: field(values, "count")?.first()?.parse_atom()?,
+                    })
+                }
+                "tackle" => {
+                    ret.tackle = Some(Tackle {
+                        expires: field(values, "expires")?.first()?.parse_atom()?,
+                        count: field(values, "count")?.first()?.parse_atom()?,
+                    })
+                }
+                "collision" => {
+                    ret.collision = values
+                        .iter()
+                        .filter_map(|c| c.atom().or_else(|| c.head()))
+                        .filter(|c| *c != "none")
+                        .map(str::to_string)
+                        .collect()
+                }
+                "foul" => {
+                    ret.foul = Some(Foul {
+                        charged: field(values, "charged")?.first()?.parse_atom()?,
+                        card: field(values, "card")?.first()?.atom()?.to_string(),
+                    })
+                }
+                "focus_point" => {
+                    ret.focus_point = Some((values.first()?.parse_atom()?, values.get(1)?.parse_atom()?))
+                }
+                // unknown items from newer protocol versions are skipped
+                _ => {}
+            }
+        }
+
+        Some(ret)
+    }
+}
+
+/// Values of the `(KEY ...)` item in a list of sub-items.
+pub(super) fn field<'s, 'a>(items: &'s [Sexp<'a>], key: &str) -> Option<&'s [Sexp<'a>]> {
+    items.iter().find_map(|item| {
+        let (head, values) = item.list()?.split_first()?;
+        head.is_atom(key).then_some(values)
+    })
+}
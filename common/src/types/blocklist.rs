@@ -0,0 +1,73 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::command::player::PlayerCommand;
+use super::{Side, Unum};
+
+/// Player commands the proxies drop, by side and unum,
+/// as declared in the `blocklist` GameServer annotation.
+#[derive(Deserialize, Serialize, Default, Debug, Clone)]
+pub struct Blocklist {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub left: BTreeMap<Unum, PlayerBlocklist>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub right: BTreeMap<Unum, PlayerBlocklist>,
+}
+
+#[derive(Deserialize, Serialize, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlayerBlocklist {
+    #[serde(default)]
+    pub dash: bool,
+    #[serde(default)]
+    pub catch: bool,
+}
+
+impl PlayerBlocklist {
+    pub fn blocks(&self, command: &PlayerCommand) -> bool {
+        match command {
+            PlayerCommand::Dash => self.dash,
+            PlayerCommand::Catch => self.catch,
+            _ => false,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        !self.dash && !self.catch
+    }
+}
+
+impl Blocklist {
+    pub fn parse_arg(s: &str) -> Result<Self, String> {
+        serde_json::from_str(s).map_err(|e| e.to_string())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.left.values().chain(self.right.values()).all(PlayerBlocklist::is_empty)
+    }
+
+    pub fn get(&self, side: Side, unum: u8) -> Option<&PlayerBlocklist> {
+        match side {
+            Side::LEFT => self.left.get(&unum),
+            Side::RIGHT => self.right.get(&unum),
+            Side::NEUTRAL => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_annotation() {
+        let blocklist = Blocklist::parse_arg(r#"{"left":{"2":{"catch":true}}}"#).unwrap();
+
+        assert!(!blocklist.is_empty());
+        let player = blocklist.get(Side::LEFT, 2).unwrap();
+        assert!(player.blocks(&PlayerCommand::Catch));
+        assert!(!player.blocks(&PlayerCommand::Dash));
+        assert!(blocklist.get(Side::RIGHT, 2).is_none());
+        assert!(Blocklist::parse_arg("{}").unwrap().is_empty());
+    }
+}
@@ -0,0 +1,66 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Position, Side, Unum};
+
+/// Where the ball and players are placed before kick-off, in global coordinates,
+/// as declared in the `init` GameServer annotation.
+#[derive(Deserialize, Serialize, Default, Debug, Clone)]
+pub struct InitState {
+    pub ball: Option<Position>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub left: BTreeMap<Unum, PlayerInitState>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub right: BTreeMap<Unum, PlayerInitState>,
+}
+
+#[derive(Deserialize, Serialize, Default, Debug, Clone)]
+pub struct PlayerInitState {
+    pub pos: Option<Position>,
+    /// rcssserver can only restore stamina, any value triggers a trainer `recover`
+    /// which brings every player back to `stamina_max`.
+    pub stamina: Option<u16>,
+}
+
+impl PlayerInitState {
+    pub fn is_empty(&self) -> bool {
+        self.pos.is_none() && self.stamina.is_none()
+    }
+}
+
+impl InitState {
+    pub fn parse_arg(s: &str) -> Result<Self, String> {
+        serde_json::from_str(s).map_err(|e| e.to_string())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ball.is_none() && self.left.is_empty() && self.right.is_empty()
+    }
+
+    pub fn players(&self) -> impl Iterator<Item = (Side, u8, &PlayerInitState)> {
+        let left = self.left.iter().map(|(unum, p)| (Side::LEFT, **unum, p));
+        let right = self.right.iter().map(|(unum, p)| (Side::RIGHT, **unum, p));
+        left.chain(right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_annotation() {
+        let init = InitState::parse_arg(
+            r#"{"ball":{"x":0.5,"y":0.25},"right":{"1":{"pos":{"x":40.0,"y":-5.0},"stamina":4000}}}"#
+        ).unwrap();
+
+        assert!(!init.is_empty());
+        let players = init.players().collect::<Vec<_>>();
+        assert_eq!(players.len(), 1);
+        assert_eq!(players[0].0, Side::RIGHT);
+        assert_eq!(players[0].1, 1);
+        assert_eq!(players[0].2.pos.unwrap().x, 40.0);
+        assert!(InitState::parse_arg("{}").unwrap().is_empty());
+    }
+}
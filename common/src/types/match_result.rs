@@ -0,0 +1,121 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use super::Side;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Score {
+    pub left: u8,
+    pub right: u8,
+}
+
+/// Goals scored and missed in a penalty shoot-out.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Penalties {
+    pub left_score: u8,
+    pub left_miss: u8,
+    pub right_score: u8,
+    pub right_miss: u8,
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum EndReason {
+    TimeUp,
+    GoalL,
+    GoalR,
+    /// The server went away before any stopping condition was met.
+    Aborted,
+}
+
+/// Where a [`MatchResult`] was taken from.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ResultSource {
+    /// Followed live from the referee messages heard by the trainer.
+    Referee,
+    /// Read back from the `.rcg` once the match was over.
+    GameLog,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MatchLogs {
+    pub game_log: Option<PathBuf>,
+    pub text_log: Option<PathBuf>,
+}
+
+/// How a match ended and who won it.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct MatchResult {
+    pub team_l: Option<String>,
+    pub team_r: Option<String>,
+    pub score: Score,
+    pub penalties: Option<Penalties>,
+    /// [`Side::NEUTRAL`] for a draw.
+    pub winner: Side,
+    pub end_reason: Option<EndReason>,
+    /// Cycles played, the time of the last record.
+    pub cycles: u16,
+    /// Wall clock time from spawning the server to the end of the match, if known.
+    pub duration_ms: Option<i64>,
+    pub logs: MatchLogs,
+    pub source: ResultSource,
+}
+
+impl MatchResult {
+    pub fn new(score: Score, penalties: Option<Penalties>, source: ResultSource) -> Self {
+        Self {
+            team_l: None,
+            team_r: None,
+            score,
+            penalties,
+            winner: Self::decide(score, penalties),
+            end_reason: None,
+            cycles: 0,
+            duration_ms: None,
+            logs: MatchLogs::default(),
+            source,
+        }
+    }
+
+    /// Goals decide, a shoot-out only breaks a draw.
+    fn decide(score: Score, penalties: Option<Penalties>) -> Side {
+        let (left, right) = match penalties {
+            Some(p) if score.left == score.right => (p.left_score, p.right_score),
+            _ => (score.left, score.right),
+        };
+        match left.cmp(&right) {
+            std::cmp::Ordering::Greater => Side::LEFT,
+            std::cmp::Ordering::Less => Side::RIGHT,
+            std::cmp::Ordering::Equal => Side::NEUTRAL,
+        }
+    }
+
+    /// Name of the winning team, `None` for a draw or an unknown name.
+    pub fn winner_name(&self) -> Option<&str> {
+        match self.winner {
+            Side::LEFT => self.team_l.as_deref(),
+            Side::RIGHT => self.team_r.as_deref(),
+            Side::NEUTRAL => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_winner() {
+        let result = MatchResult::new(Score { left: 2, right: 1 }, None, ResultSource::Referee);
+        assert_eq!(result.winner, Side::LEFT);
+        assert_eq!(MatchResult::new(Score::default(), None, ResultSource::Referee).winner, Side::NEUTRAL);
+
+        let penalties = Penalties { left_score: 3, left_miss: 2, right_score: 4, right_miss: 1 };
+        let mut result = MatchResult::new(Score { left: 1, right: 1 }, Some(penalties), ResultSource::GameLog);
+        result.team_r = Some("Cyrus".to_string());
+        assert_eq!(result.winner, Side::RIGHT);
+        assert_eq!(result.winner_name(), Some("Cyrus"));
+    }
+}
@@ -1,14 +1,28 @@
 mod ball_position;
+mod blocklist;
 mod ear_mode;
 mod eye_mode;
+mod init_state;
+mod match_result;
 mod play_mode;
+mod position;
 mod side;
+mod unum;
+mod view_mode;
+mod world_state;
 
 pub use ball_position::BallPosition;
+pub use blocklist::{Blocklist, PlayerBlocklist};
 pub use ear_mode::EarMode;
 pub use eye_mode::EyeMode;
+pub use init_state::{InitState, PlayerInitState};
+pub use match_result::{EndReason, MatchLogs, MatchResult, Penalties, ResultSource, Score};
 pub use play_mode::PlayMode;
+pub use position::Position;
 pub use side::Side;
+pub use unum::{Unum, unum};
+pub use view_mode::{ViewQuality, ViewWidth};
+pub use world_state::{BallState, GoalState, PlayerState, WorldState};
 
 pub static STR_HAY: [&str; 100] = [
     "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16",
@@ -2,7 +2,9 @@
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
+use super::Side;
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
 #[repr(C)]
 pub enum PlayMode {
@@ -63,7 +65,230 @@ pub enum PlayMode {
 }
 
 impl PlayMode {
+    /// The name used by rcssserver, see `PLAYMODE_STRINGS` in `types.h`.
     pub fn encode(self) -> &'static str {
-        super::usize_to_str(self as usize)
+        match self {
+            PlayMode::PM_Null => "",
+            PlayMode::PM_BeforeKickOff => "before_kick_off",
+            PlayMode::PM_TimeOver => "time_over",
+            PlayMode::PM_PlayOn => "play_on",
+            PlayMode::PM_KickOff_Left => "kick_off_l",
+            PlayMode::PM_KickOff_Right => "kick_off_r",
+            PlayMode::PM_KickIn_Left => "kick_in_l",
+            PlayMode::PM_KickIn_Right => "kick_in_r",
+            PlayMode::PM_FreeKick_Left => "free_kick_l",
+            PlayMode::PM_FreeKick_Right => "free_kick_r",
+            PlayMode::PM_CornerKick_Left => "corner_kick_l",
+            PlayMode::PM_CornerKick_Right => "corner_kick_r",
+            PlayMode::PM_GoalKick_Left => "goal_kick_l",
+            PlayMode::PM_GoalKick_Right => "goal_kick_r",
+            PlayMode::PM_AfterGoal_Left => "goal_l",
+            PlayMode::PM_AfterGoal_Right => "goal_r",
+            PlayMode::PM_Drop_Ball => "drop_ball",
+            PlayMode::PM_OffSide_Left => "offside_l",
+            PlayMode::PM_OffSide_Right => "offside_r",
+            PlayMode::PM_PK_Left => "penalty_kick_l",
+            PlayMode::PM_PK_Right => "penalty_kick_r",
+            PlayMode::PM_FirstHalfOver => "first_half_over",
+            PlayMode::PM_Pause => "pause",
+            PlayMode::PM_Human => "human_judge",
+            PlayMode::PM_Foul_Charge_Left => "foul_charge_l",
+            PlayMode::PM_Foul_Charge_Right => "foul_charge_r",
+            PlayMode::PM_Foul_Push_Left => "foul_push_l",
+            PlayMode::PM_Foul_Push_Right => "foul_push_r",
+            PlayMode::PM_Foul_MultipleAttacker_Left => "foul_multiple_attack_l",
+            PlayMode::PM_Foul_MultipleAttacker_Right => "foul_multiple_attack_r",
+            PlayMode::PM_Foul_BallOut_Left => "foul_ballout_l",
+            PlayMode::PM_Foul_BallOut_Right => "foul_ballout_r",
+            PlayMode::PM_Back_Pass_Left => "back_pass_l",
+            PlayMode::PM_Back_Pass_Right => "back_pass_r",
+            PlayMode::PM_Free_Kick_Fault_Left => "free_kick_fault_l",
+            PlayMode::PM_Free_Kick_Fault_Right => "free_kick_fault_r",
+            PlayMode::PM_CatchFault_Left => "catch_fault_l",
+            PlayMode::PM_CatchFault_Right => "catch_fault_r",
+            PlayMode::PM_IndFreeKick_Left => "indirect_free_kick_l",
+            PlayMode::PM_IndFreeKick_Right => "indirect_free_kick_r",
+            PlayMode::PM_PenaltySetup_Left => "penalty_setup_l",
+            PlayMode::PM_PenaltySetup_Right => "penalty_setup_r",
+            PlayMode::PM_PenaltyReady_Left => "penalty_ready_l",
+            PlayMode::PM_PenaltyReady_Right => "penalty_ready_r",
+            PlayMode::PM_PenaltyTaken_Left => "penalty_taken_l",
+            PlayMode::PM_PenaltyTaken_Right => "penalty_taken_r",
+            PlayMode::PM_PenaltyMiss_Left => "penalty_miss_l",
+            PlayMode::PM_PenaltyMiss_Right => "penalty_miss_r",
+            PlayMode::PM_PenaltyScore_Left => "penalty_score_l",
+            PlayMode::PM_PenaltyScore_Right => "penalty_score_r",
+            PlayMode::PM_Illegal_Defense_Left => "illegal_defense_l",
+            PlayMode::PM_Illegal_Defense_Right => "illegal_defense_r",
+            PlayMode::PM_MAX => "",
+        }
+    }
+
+    /// Decode a play mode name, including referee forms such as `goal_l_3`.
+    pub fn decode(s: &str) -> Option<Self> {
+        if let Some((side, _)) = Self::decode_goal(s) {
+            return Some(match side {
+                Side::LEFT => PlayMode::PM_AfterGoal_Left,
+                _ => PlayMode::PM_AfterGoal_Right,
+            });
+        }
+
+        match s {
+            "before_kick_off" => Some(PlayMode::PM_BeforeKickOff),
+            "time_over" => Some(PlayMode::PM_TimeOver),
+            "play_on" => Some(PlayMode::PM_PlayOn),
+            "kick_off_l" => Some(PlayMode::PM_KickOff_Left),
+            "kick_off_r" => Some(PlayMode::PM_KickOff_Right),
+            "kick_in_l" => Some(PlayMode::PM_KickIn_Left),
+            "kick_in_r" => Some(PlayMode::PM_KickIn_Right),
+            "free_kick_l" => Some(PlayMode::PM_FreeKick_Left),
+            "free_kick_r" => Some(PlayMode::PM_FreeKick_Right),
+            "corner_kick_l" => Some(PlayMode::PM_CornerKick_Left),
+            "corner_kick_r" => Some(PlayMode::PM_CornerKick_Right),
+            "goal_kick_l" => Some(PlayMode::PM_GoalKick_Left),
+            "goal_kick_r" => Some(PlayMode::PM_GoalKick_Right),
+            "goal_l" => Some(PlayMode::PM_AfterGoal_Left),
+            "goal_r" => Some(PlayMode::PM_AfterGoal_Right),
+            "drop_ball" => Some(PlayMode::PM_Drop_Ball),
+            "offside_l" => Some(PlayMode::PM_OffSide_Left),
+            "offside_r" => Some(PlayMode::PM_OffSide_Right),
+            "penalty_kick_l" => Some(PlayMode::PM_PK_Left),
+            "penalty_kick_r" => Some(PlayMode::PM_PK_Right),
+            "first_half_over" => Some(PlayMode::PM_FirstHalfOver),
+            "pause" => Some(PlayMode::PM_Pause),
+            "human_judge" => Some(PlayMode::PM_Human),
+            "foul_charge_l" => Some(PlayMode::PM_Foul_Charge_Left),
+            "foul_charge_r" => Some(PlayMode::PM_Foul_Charge_Right),
+            "foul_push_l" => Some(PlayMode::PM_Foul_Push_Left),
+            "foul_push_r" => Some(PlayMode::PM_Foul_Push_Right),
+            "foul_multiple_attack_l" => Some(PlayMode::PM_Foul_MultipleAttacker_Left),
+            "foul_multiple_attack_r" => Some(PlayMode::PM_Foul_MultipleAttacker_Right),
+            "foul_ballout_l" => Some(PlayMode::PM_Foul_BallOut_Left),
+            "foul_ballout_r" => Some(PlayMode::PM_Foul_BallOut_Right),
+            "back_pass_l" => Some(PlayMode::PM_Back_Pass_Left),
+            "back_pass_r" => Some(PlayMode::PM_Back_Pass_Right),
+            "free_kick_fault_l" => Some(PlayMode::PM_Free_Kick_Fault_Left),
+            "free_kick_fault_r" => Some(PlayMode::PM_Free_Kick_Fault_Right),
+            "catch_fault_l" => Some(PlayMode::PM_CatchFault_Left),
+            "catch_fault_r" => Some(PlayMode::PM_CatchFault_Right),
+            "indirect_free_kick_l" => Some(PlayMode::PM_IndFreeKick_Left),
+            "indirect_free_kick_r" => Some(PlayMode::PM_IndFreeKick_Right),
+            "penalty_setup_l" => Some(PlayMode::PM_PenaltySetup_Left),
+            "penalty_setup_r" => Some(PlayMode::PM_PenaltySetup_Right),
+            "penalty_ready_l" => Some(PlayMode::PM_PenaltyReady_Left),
+            "penalty_ready_r" => Some(PlayMode::PM_PenaltyReady_Right),
+            "penalty_taken_l" => Some(PlayMode::PM_PenaltyTaken_Left),
+            "penalty_taken_r" => Some(PlayMode::PM_PenaltyTaken_Right),
+            "penalty_miss_l" => Some(PlayMode::PM_PenaltyMiss_Left),
+            "penalty_miss_r" => Some(PlayMode::PM_PenaltyMiss_Right),
+            "penalty_score_l" => Some(PlayMode::PM_PenaltyScore_Left),
+            "penalty_score_r" => Some(PlayMode::PM_PenaltyScore_Right),
+            "illegal_defense_l" => Some(PlayMode::PM_Illegal_Defense_Left),
+            "illegal_defense_r" => Some(PlayMode::PM_Illegal_Defense_Right),
+            _ => None,
+        }
+    }
+
+    /// Decode the referee `goal_l_N`/`goal_r_N` message into the scoring side and its goal count.
+    pub fn decode_goal(s: &str) -> Option<(Side, u8)> {
+        let (side, count) = s.strip_prefix("goal_")?.split_once('_')?;
+        let side = match side {
+            "l" => Side::LEFT,
+            "r" => Side::RIGHT,
+            _ => return None,
+        };
+        Some((side, count.parse().ok()?))
+    }
+
+    /// The team the play mode is in favour of, [`Side::NEUTRAL`] when it applies to both.
+    pub fn side(self) -> Side {
+        match self.encode() {
+            s if s.ends_with("_l") => Side::LEFT,
+            s if s.ends_with("_r") => Side::RIGHT,
+            _ => Side::NEUTRAL,
+        }
+    }
+}
+
+impl std::str::FromStr for PlayMode {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <PlayMode as std::str::FromStr>::Err> {
+        Self::decode(s).ok_or(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let modes = [
+            PlayMode::PM_BeforeKickOff,
+            PlayMode::PM_TimeOver,
+            PlayMode::PM_PlayOn,
+            PlayMode::PM_KickOff_Left,
+            PlayMode::PM_KickOff_Right,
+            PlayMode::PM_KickIn_Left,
+            PlayMode::PM_KickIn_Right,
+            PlayMode::PM_FreeKick_Left,
+            PlayMode::PM_FreeKick_Right,
+            PlayMode::PM_CornerKick_Left,
+            PlayMode::PM_CornerKick_Right,
+            PlayMode::PM_GoalKick_Left,
+            PlayMode::PM_GoalKick_Right,
+            PlayMode::PM_AfterGoal_Left,
+            PlayMode::PM_AfterGoal_Right,
+            PlayMode::PM_Drop_Ball,
+            PlayMode::PM_OffSide_Left,
+            PlayMode::PM_OffSide_Right,
+            PlayMode::PM_PK_Left,
+            PlayMode::PM_PK_Right,
+            PlayMode::PM_FirstHalfOver,
+            PlayMode::PM_Pause,
+            PlayMode::PM_Human,
+            PlayMode::PM_Foul_Charge_Left,
+            PlayMode::PM_Foul_Charge_Right,
+            PlayMode::PM_Foul_Push_Left,
+            PlayMode::PM_Foul_Push_Right,
+            PlayMode::PM_Foul_MultipleAttacker_Left,
+            PlayMode::PM_Foul_MultipleAttacker_Right,
+            PlayMode::PM_Foul_BallOut_Left,
+            PlayMode::PM_Foul_BallOut_Right,
+            PlayMode::PM_Back_Pass_Left,
+            PlayMode::PM_Back_Pass_Right,
+            PlayMode::PM_Free_Kick_Fault_Left,
+            PlayMode::PM_Free_Kick_Fault_Right,
+            PlayMode::PM_CatchFault_Left,
+            PlayMode::PM_CatchFault_Right,
+            PlayMode::PM_IndFreeKick_Left,
+            PlayMode::PM_IndFreeKick_Right,
+            PlayMode::PM_PenaltySetup_Left,
+            PlayMode::PM_PenaltySetup_Right,
+            PlayMode::PM_PenaltyReady_Left,
+            PlayMode::PM_PenaltyReady_Right,
+            PlayMode::PM_PenaltyTaken_Left,
+            PlayMode::PM_PenaltyTaken_Right,
+            PlayMode::PM_PenaltyMiss_Left,
+            PlayMode::PM_PenaltyMiss_Right,
+            PlayMode::PM_PenaltyScore_Left,
+            PlayMode::PM_PenaltyScore_Right,
+            PlayMode::PM_Illegal_Defense_Left,
+            PlayMode::PM_Illegal_Defense_Right,
+        ];
+        for mode in modes {
+            assert_eq!(PlayMode::decode(mode.encode()), Some(mode), "{mode:?}");
+        }
+    }
+
+    #[test]
+    fn test_decode_referee() {
+        assert_eq!("goal_l_3".parse(), Ok(PlayMode::PM_AfterGoal_Left));
+        assert_eq!(PlayMode::decode_goal("goal_r_12"), Some((Side::RIGHT, 12)));
+        assert_eq!(PlayMode::decode_goal("goal_kick_l"), None);
+        assert_eq!("penalty_score_r".parse(), Ok(PlayMode::PM_PenaltyScore_Right));
+        assert_eq!(PlayMode::PM_IndFreeKick_Left.side(), Side::LEFT);
+        assert_eq!(PlayMode::PM_Drop_Ball.side(), Side::NEUTRAL);
+        assert!("half_time".parse::<PlayMode>().is_err());
     }
 }
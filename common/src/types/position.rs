@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+/// A point on the field, in global coordinates.
+#[derive(Deserialize, Serialize, Default, Debug, Clone, Copy, PartialEq)]
+pub struct Position {
+    pub x: f64,
+    pub y: f64,
+}
+
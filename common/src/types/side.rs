@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
 #[allow(non_camel_case_types)]
 #[repr(i8)]
 #[serde(rename_all = "lowercase")]
@@ -9,3 +9,28 @@ pub enum Side {
     NEUTRAL = 0,
     RIGHT = -1
 }
+
+impl Side {
+    pub fn encode(self) -> &'static str {
+        match self {
+            Side::LEFT => "l",
+            Side::NEUTRAL => "n",
+            Side::RIGHT => "r",
+        }
+    }
+    pub fn decode(s: &str) -> Option<Self> {
+        match s {
+            "l" | "left" => Some(Side::LEFT),
+            "n" | "neutral" => Some(Side::NEUTRAL),
+            "r" | "right" => Some(Side::RIGHT),
+            _ => None,
+        }
+    }
+}
+
+impl std::str::FromStr for Side {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <Side as std::str::FromStr>::Err> {
+        Self::decode(s).ok_or(())
+    }
+}
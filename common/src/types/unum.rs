@@ -1,6 +1,7 @@
+use std::borrow::Borrow;
 use std::fmt::Display;
 use std::ops::Deref;
-use common::errors::{BuilderError, BuilderResult};
+use crate::errors::{BuilderError, BuilderResult};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -21,6 +22,13 @@ impl Deref for Unum {
     }
 }
 
+/// Lets maps keyed by `Unum` be looked up by a plain number.
+impl Borrow<u8> for Unum {
+    fn borrow(&self) -> &u8 {
+        &self.0
+    }
+}
+
 impl TryFrom<u8> for Unum {
     type Error = BuilderError;
 
@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub enum ViewWidth {
+    Narrow,
+    Normal,
+    Wide,
+}
+
+impl ViewWidth {
+    pub fn encode(self) -> &'static str {
+        match self {
+            ViewWidth::Narrow => "narrow",
+            ViewWidth::Normal => "normal",
+            ViewWidth::Wide => "wide",
+        }
+    }
+    pub fn decode(s: &str) -> Option<Self> {
+        match s {
+            "narrow" => Some(ViewWidth::Narrow),
+            "normal" => Some(ViewWidth::Normal),
+            "wide" => Some(ViewWidth::Wide),
+            _ => None,
+        }
+    }
+}
+
+impl std::str::FromStr for ViewWidth {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <ViewWidth as std::str::FromStr>::Err> {
+        Self::decode(s).ok_or(())
+    }
+}
+
+impl Serialize for ViewWidth {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.encode())
+    }
+}
+
+impl<'de> Deserialize<'de> for ViewWidth {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        ViewWidth::decode(&s).ok_or_else(|| serde::de::Error::custom("invalid ViewWidth"))
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub enum ViewQuality {
+    High,
+    Low,
+}
+
+impl ViewQuality {
+    pub fn encode(self) -> &'static str {
+        match self {
+            ViewQuality::High => "high",
+            ViewQuality::Low => "low",
+        }
+    }
+    pub fn decode(s: &str) -> Option<Self> {
+        match s {
+            "high" => Some(ViewQuality::High),
+            "low" => Some(ViewQuality::Low),
+            _ => None,
+        }
+    }
+}
+
+impl std::str::FromStr for ViewQuality {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <ViewQuality as std::str::FromStr>::Err> {
+        Self::decode(s).ok_or(())
+    }
+}
+
+impl Serialize for ViewQuality {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.encode())
+    }
+}
+
+impl<'de> Deserialize<'de> for ViewQuality {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        ViewQuality::decode(&s).ok_or_else(|| serde::de::Error::custom("invalid ViewQuality"))
+    }
+}
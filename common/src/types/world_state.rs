@@ -0,0 +1,171 @@
+use serde::{Deserialize, Serialize};
+
+use super::Side;
+use crate::utils::sexp::Sexp;
+
+/// Ground-truth snapshot of the field, as replied by the trainer `look`:
+///
+/// `(ok look TIME ((g l) X Y) ((g r) X Y) ((b) X Y VX VY) ((p "TEAM" UNUM [goalie]) X Y VX VY BODY NECK [POINT_DIR] [t|k] [y|r]) ...)`
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct WorldState {
+    pub time: u16,
+    pub goal_l: Option<GoalState>,
+    pub goal_r: Option<GoalState>,
+    pub ball: Option<BallState>,
+    pub players: Vec<PlayerState>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct GoalState {
+    pub x: f32,
+    pub y: f32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct BallState {
+    pub x: f32,
+    pub y: f32,
+    pub vx: f32,
+    pub vy: f32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PlayerState {
+    pub team: String,
+    /// `look` only carries team names, see [`WorldState::resolve_sides`].
+    pub side: Option<Side>,
+    pub unum: u8,
+    pub goalie: bool,
+    pub x: f32,
+    pub y: f32,
+    pub vx: f32,
+    pub vy: f32,
+    pub body: f32,
+    pub neck: f32,
+    pub point_dir: Option<f32>,
+}
+
+impl WorldState {
+    /// Parse the tokens following `ok look`.
+    pub fn parse(tokens: &[Sexp]) -> Option<Self> {
+        let (time, objects) = tokens.split_first()?;
+        let mut ret = WorldState {
+            time: time.parse_atom()?,
+            ..Default::default()
+        };
+
+        for object in objects {
+            let object = object.list()?;
+            let (name, values) = object.split_first()?;
+            let name = name.list()?;
+            let values = values
+                .iter()
+                // players trail tackle/kick and card flags after their numbers
+                .filter(|v| !matches!(v.atom(), Some("t" | "k" | "y" | "r")))
+                .map(|v| v.parse_atom::<f32>())
+                .collect::<Option<Vec<_>>>()?;
+
+            match name.first()?.atom()? {
+                "g" => {
+                    let [x, y] = values[..] else { return None };
+                    match name.get(1)?.atom()? {
+                        "l" => ret.goal_l = Some(GoalState { x, y }),
+                        "r" => ret.goal_r = Some(GoalState { x, y }),
+                        _ => return None,
+                    }
+                }
+                "b" => {
+                    let [x, y, vx, vy] = values[..] else { return None };
+                    ret.ball = Some(BallState { x, y, vx, vy });
+                }
+                "p" => {
+                    let team = name.get(1)?.atom()?.to_string();
+                    let unum = name.get(2)?.parse_atom()?;
+                    let goalie = name.get(3).is_some_and(|g| g.is_atom("goalie"));
+                    let (x, y, vx, vy, body, neck, point_dir) = match values[..] {
+                        [x, y, vx, vy, body, neck] => (x, y, vx, vy, body, neck, None),
+                        [x, y, vx, vy, body, neck, point_dir] => {
+                            (x, y, vx, vy, body, neck, Some(point_dir))
+                        }
+                        _ => return None,
+                    };
+                    ret.players.push(PlayerState {
+                        team,
+                        side: None,
+                        unum,
+                        goalie,
+                        x,
+                        y,
+                        vx,
+                        vy,
+                        body,
+                        neck,
+                        point_dir,
+                    });
+                }
+                _ => return None,
+            }
+        }
+
+        Some(ret)
+    }
+
+    /// Fill in [`PlayerState::side`] from the team names, e.g. from a `team_names` reply.
+    pub fn resolve_sides(&mut self, left: Option<&str>, right: Option<&str>) {
+        for player in self.players.iter_mut() {
+            player.side = if left == Some(player.team.as_str()) {
+                Some(Side::LEFT)
+            } else if right == Some(player.team.as_str()) {
+                Some(Side::RIGHT)
+            } else {
+                None
+            };
+        }
+    }
+
+    pub fn player(&self, side: Side, unum: u8) -> Option<&PlayerState> {
+        self.players
+            .iter()
+            .find(|p| p.unum == unum && p.side == Some(side))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_look() {
+        let msg = "(ok look 42 ((g l) -52.5 0) ((g r) 52.5 0) ((b) 1.5 -2 0.3 0) \
+            ((p \"Left\" 1 goalie) -50 0 0 0 0 0) ((p \"Right\" 9) 10 5 0.1 0.2 90 -30 45))";
+        let sexp = Sexp::parse(msg).unwrap();
+        let tokens = &sexp.list().unwrap()[2..];
+
+        let mut state = WorldState::parse(tokens).unwrap();
+        state.resolve_sides(Some("Left"), Some("Right"));
+
+        assert_eq!(state.time, 42);
+        assert_eq!(state.goal_l.unwrap().x, -52.5);
+        assert_eq!(state.ball.unwrap().vx, 0.3);
+        assert_eq!(state.players.len(), 2);
+        assert!(state.players[0].goalie);
+
+        let right_9 = state.player(Side::RIGHT, 9).unwrap();
+        assert_eq!(right_9.neck, -30.0);
+        assert_eq!(right_9.point_dir, Some(45.0));
+    }
+
+    #[test]
+    fn test_parse_look_player_flags() {
+        let msg = "(ok look 7 ((b) 0 0 0 0) ((p \"Left\" 2) -10 3 0 0 45 0 t y) \
+            ((p \"Right\" 4) 8 1 0 0 180 10 -20 k) ((p \"Right\" 5) 9 2 0 0 0 0 r))";
+        let sexp = Sexp::parse(msg).unwrap();
+        let tokens = &sexp.list().unwrap()[2..];
+
+        let state = WorldState::parse(tokens).unwrap();
+        assert_eq!(state.players.len(), 3);
+        assert_eq!((state.players[0].body, state.players[0].point_dir), (45.0, None));
+        assert_eq!(state.players[1].point_dir, Some(-20.0));
+        assert_eq!(state.players[2].x, 9.0);
+    }
+}
@@ -4,8 +4,9 @@ use std::path::{Path, PathBuf};
 
 use clap::Args;
 use env_logger::{Builder, Env, Target};
+use serde::Serialize;
 
-#[derive(Debug, Clone, Args)]
+#[derive(Debug, Clone, Args, Serialize)]
 pub struct LoggingArgs {
     #[arg(long, env = "LOGGER_ROOT_FILE", help = "Path to a file containing the resolved shared log root directory")]
     pub shared_log_root_file: Option<PathBuf>,
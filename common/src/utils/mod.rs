@@ -1,2 +1,3 @@
 pub mod logging;
-pub mod ringbuf;
\ No newline at end of file
+pub mod ringbuf;
+pub mod sexp;
@@ -0,0 +1,177 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// A borrowed S-expression as spoken by rcssserver, e.g. `(ok look 0 ((b) 0 0 0 0))`.
+///
+/// Quoted atoms (`"HELIOS"`) are unquoted, but otherwise kept as-is.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Sexp<'a> {
+    Atom(&'a str),
+    List(Vec<Sexp<'a>>),
+}
+
+#[derive(thiserror::Error, Debug, PartialEq)]
+pub enum SexpError {
+    #[error("unexpected end of input")]
+    UnexpectedEof,
+    #[error("unexpected ')' at {0}")]
+    UnexpectedClose(usize),
+    #[error("unterminated string starting at {0}")]
+    UnterminatedString(usize),
+    #[error("trailing input at {0}")]
+    TrailingInput(usize),
+}
+
+impl<'a> Sexp<'a> {
+    /// Parse exactly one expression, ignoring surrounding whitespace and trailing `\0`.
+    pub fn parse(s: &'a str) -> Result<Self, SexpError> {
+        let s = s.trim_end_matches(['\0', '\n', '\r']);
+        let mut parser = Parser { src: s, pos: 0 };
+        let ret = parser.next_expr()?.ok_or(SexpError::UnexpectedEof)?;
+        parser.skip_ws();
+        if parser.pos != s.len() {
+            return Err(SexpError::TrailingInput(parser.pos));
+        }
+        Ok(ret)
+    }
+
+    /// Parse a whitespace separated sequence of expressions.
+    pub fn parse_many(s: &'a str) -> Result<Vec<Self>, SexpError> {
+        let s = s.trim_end_matches(['\0', '\n', '\r']);
+        let mut parser = Parser { src: s, pos: 0 };
+        let mut ret = Vec::new();
+        while let Some(expr) = parser.next_expr()? {
+            ret.push(expr);
+        }
+        Ok(ret)
+    }
+
+    pub fn atom(&self) -> Option<&'a str> {
+        match self {
+            Sexp::Atom(s) => Some(s),
+            Sexp::List(_) => None,
+        }
+    }
+
+    pub fn list(&self) -> Option<&[Sexp<'a>]> {
+        match self {
+            Sexp::Atom(_) => None,
+            Sexp::List(l) => Some(l),
+        }
+    }
+
+    /// Parse an atom with [`FromStr`], `None` for lists or parse failures.
+    pub fn parse_atom<T: FromStr>(&self) -> Option<T> {
+        self.atom()?.parse().ok()
+    }
+
+    pub fn is_atom(&self, s: &str) -> bool {
+        self.atom() == Some(s)
+    }
+
+    /// The leading atom of a list, e.g. `see` for `(see 0 ...)`.
+    pub fn head(&self) -> Option<&'a str> {
+        self.list()?.first()?.atom()
+    }
+}
+
+impl fmt::Display for Sexp<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Sexp::Atom(s) if s.is_empty() || s.contains([' ', '(', ')']) => write!(f, "\"{s}\""),
+            Sexp::Atom(s) => f.write_str(s),
+            Sexp::List(l) => {
+                f.write_str("(")?;
+                for (i, item) in l.iter().enumerate() {
+                    if i != 0 {
+                        f.write_str(" ")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn skip_ws(&mut self) {
+        let rest = &self.src[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn next_expr(&mut self) -> Result<Option<Sexp<'a>>, SexpError> {
+        self.skip_ws();
+        let bytes = self.src.as_bytes();
+        let Some(&c) = bytes.get(self.pos) else {
+            return Ok(None);
+        };
+
+        match c {
+            b'(' => {
+                self.pos += 1;
+                let mut items = Vec::new();
+                loop {
+                    self.skip_ws();
+                    match bytes.get(self.pos) {
+                        None => return Err(SexpError::UnexpectedEof),
+                        Some(b')') => {
+                            self.pos += 1;
+                            return Ok(Some(Sexp::List(items)));
+                        }
+                        Some(_) => items.push(self.next_expr()?.ok_or(SexpError::UnexpectedEof)?),
+                    }
+                }
+            }
+            b')' => Err(SexpError::UnexpectedClose(self.pos)),
+            b'"' => {
+                let start = self.pos + 1;
+                let len = self.src[start..].find('"').ok_or(SexpError::UnterminatedString(self.pos))?;
+                self.pos = start + len + 1;
+                Ok(Some(Sexp::Atom(&self.src[start..start + len])))
+            }
+            _ => {
+                let start = self.pos;
+                while let Some(&c) = bytes.get(self.pos) {
+                    if c == b'(' || c == b')' || c.is_ascii_whitespace() {
+                        break;
+                    }
+                    self.pos += 1;
+                }
+                Ok(Some(Sexp::Atom(&self.src[start..self.pos])))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_nested() {
+        let sexp = Sexp::parse("(ok look 12 ((p \"HELIOS base\" 1 goalie) -50 0))\0").unwrap();
+        let list = sexp.list().unwrap();
+        assert_eq!(sexp.head(), Some("ok"));
+        assert_eq!(list[2].parse_atom::<u16>(), Some(12));
+
+        let player = list[3].list().unwrap();
+        let name = player[0].list().unwrap();
+        assert_eq!(name[1], Sexp::Atom("HELIOS base"));
+        assert_eq!(player[1].parse_atom::<f32>(), Some(-50.0));
+        assert_eq!(sexp.to_string(), "(ok look 12 ((p \"HELIOS base\" 1 goalie) -50 0))");
+    }
+
+    #[test]
+    fn test_parse_malformed() {
+        assert_eq!(Sexp::parse("(ok"), Err(SexpError::UnexpectedEof));
+        assert_eq!(Sexp::parse(")"), Err(SexpError::UnexpectedClose(0)));
+        assert_eq!(Sexp::parse("(a) (b)"), Err(SexpError::TrailingInput(4)));
+        assert_eq!(Sexp::parse_many("(a) (b)").unwrap().len(), 2);
+    }
+}
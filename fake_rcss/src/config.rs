@@ -0,0 +1,196 @@
+use std::collections::BTreeMap;
+use std::net::{IpAddr, Ipv4Addr};
+use std::str::FromStr;
+use std::time::Duration;
+
+use super::error::{Error, Result};
+
+/// The parameters the fake acts on, read from rcssserver style `server::name=value` arguments.
+///
+/// Every other `server::`/`player::` parameter is accepted as-is and echoed back in
+/// `server_param`/`player_param`, so clients see what they were started with.
+#[derive(Clone, Debug)]
+pub struct Config {
+    pub host: IpAddr,
+    pub port: u16,
+    pub coach_port: u16,
+    pub olcoach_port: u16,
+    pub synch_mode: bool,
+    pub simulator_step: Duration,
+    /// Length of a half in seconds, `0` or less never ends the match.
+    pub half_time: i32,
+    pub nr_normal_halfs: u16,
+    pub goal_width: f32,
+    pub player_types: u16,
+
+    server: BTreeMap<String, String>,
+    player: BTreeMap<String, String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            host: IpAddr::V4(Ipv4Addr::LOCALHOST),
+            port: 6000,
+            coach_port: 6001,
+            olcoach_port: 6002,
+            synch_mode: false,
+            simulator_step: Duration::from_millis(100),
+            half_time: 300,
+            nr_normal_halfs: 2,
+            goal_width: 14.02,
+            player_types: 18,
+            server: BTreeMap::new(),
+            player: BTreeMap::new(),
+        }
+    }
+}
+
+impl Config {
+    /// Parse the command line rcssserver would be started with, e.g. `server::port=6000`.
+    pub fn from_args<I, S>(args: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut ret = Self::default();
+        for arg in args {
+            let arg = arg.as_ref();
+            let invalid = |reason| Error::InvalidArg { arg: arg.to_string(), reason };
+
+            let (key, value) = arg.trim_start_matches('-').split_once('=')
+                .ok_or_else(|| invalid("expected `namespace::name=value`"))?;
+            let (namespace, name) = key.split_once("::")
+                .ok_or_else(|| invalid("expected a `namespace::` prefix"))?;
+            ret.set(namespace, name, value).map_err(invalid)?;
+        }
+        Ok(ret)
+    }
+
+    /// Ephemeral ports on localhost, for servers run in process.
+    pub fn ephemeral() -> Self {
+        Self { port: 0, coach_port: 0, olcoach_port: 0, ..Self::default() }
+    }
+
+    pub fn set(&mut self, namespace: &str, name: &str, value: &str) -> std::result::Result<&mut Self, &'static str> {
+        let value = value.trim().trim_matches(['"', '\'']);
+        match namespace {
+            "server" => {
+                match name {
+                    "port" => self.port = parse(value)?,
+                    "coach_port" => self.coach_port = parse(value)?,
+                    "olcoach_port" => self.olcoach_port = parse(value)?,
+                    "synch_mode" => self.synch_mode = parse_bool(value)?,
+                    "simulator_step" => match parse::<u64>(value)? {
+                        0 => return Err("simulator_step must be positive"),
+                        ms => self.simulator_step = Duration::from_millis(ms),
+                    },
+                    "half_time" => self.half_time = parse(value)?,
+                    "nr_normal_halfs" => self.nr_normal_halfs = parse(value)?,
+                    "goal_width" => self.goal_width = parse(value)?,
+                    _ => {},
+                }
+                self.server.insert(name.to_string(), value.to_string());
+            },
+            "player" => {
+                if name == "player_types" {
+                    self.player_types = parse(value)?;
+                }
+                self.player.insert(name.to_string(), value.to_string());
+            },
+            // nothing is saved, so the saver settings are accepted and ignored
+            "CSVSaver" => {},
+            _ => return Err("unknown namespace"),
+        }
+        Ok(self)
+    }
+
+    /// Length of a half in cycles, `None` if the match never ends.
+    pub fn half_cycles(&self) -> Option<u16> {
+        let cycles = self.half_time as i64 * 1000 / self.simulator_step.as_millis().max(1) as i64;
+        (cycles > 0).then(|| cycles.min(u16::MAX as i64) as u16)
+    }
+
+    /// `server_param`, `player_param` and every `player_type`, as sent right after `init`.
+    pub fn param_messages(&self) -> Vec<String> {
+        let mut server = self.server.clone();
+        for (name, value) in [
+            ("port", self.port.to_string()),
+            ("coach_port", self.coach_port.to_string()),
+            ("olcoach_port", self.olcoach_port.to_string()),
+            ("synch_mode", self.synch_mode.to_string()),
+            ("simulator_step", self.simulator_step.as_millis().to_string()),
+            ("half_time", self.half_time.to_string()),
+            ("nr_normal_halfs", self.nr_normal_halfs.to_string()),
+            ("goal_width", self.goal_width.to_string()),
+        ] {
+            server.insert(name.to_string(), value);
+        }
+        let mut player = self.player.clone();
+        player.insert("player_types".to_string(), self.player_types.to_string());
+
+        let mut ret = vec![
+            format!("(server_param {})", encode_params(&server)),
+            format!("(player_param {})", encode_params(&player)),
+        ];
+        ret.extend((0..self.player_types).map(|id| format!("(player_type (id {id}) {DEFAULT_PLAYER_TYPE})")));
+        ret
+    }
+}
+
+/// Every player type is the default one, the fake does not simulate bodies.
+const DEFAULT_PLAYER_TYPE: &str = "(player_speed_max 1.05) (stamina_inc_max 45) (player_decay 0.4) \
+    (inertia_moment 5) (dash_power_rate 0.006) (player_size 0.3) (kickable_margin 0.7) (kick_rand 0.1) \
+    (extra_stamina 50) (effort_max 1) (effort_min 0.6) (kick_power_rate 0.027) \
+    (foul_detect_probability 0.5) (catchable_area_l_stretch 1)";
+
+fn parse<T: FromStr>(value: &str) -> std::result::Result<T, &'static str> {
+    value.parse().map_err(|_| "invalid value")
+}
+
+fn parse_bool(value: &str) -> std::result::Result<bool, &'static str> {
+    match value {
+        "true" | "on" | "1" => Ok(true),
+        "false" | "off" | "0" => Ok(false),
+        _ => Err("expected a boolean"),
+    }
+}
+
+/// rcssserver sends booleans as `0`/`1` and quotes strings.
+fn encode_params(params: &BTreeMap<String, String>) -> String {
+    params.iter()
+        .map(|(name, value)| match value.as_str() {
+            "true" => format!("({name} 1)"),
+            "false" => format!("({name} 0)"),
+            v if v.parse::<f64>().is_ok() => format!("({name} {v})"),
+            v => format!("({name} \"{v}\")"),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_args() {
+        let config = Config::from_args([
+            "server::port=7000", "server::synch_mode=true", "server::game_log_dir='./log'",
+            "player::player_types=2", "CSVSaver::save=false",
+        ]).unwrap();
+        assert_eq!(config.port, 7000);
+        assert_eq!(config.coach_port, 6001);
+        assert!(config.synch_mode);
+        assert_eq!(config.half_cycles(), Some(3000));
+
+        let messages = config.param_messages();
+        assert_eq!(messages.len(), 4);
+        assert!(messages[0].contains("(game_log_dir \"./log\")"));
+        assert!(messages[0].contains("(synch_mode 1)"));
+        assert!(messages[1].contains("(player_types 2)"));
+
+        assert!(matches!(Config::from_args(["server::port=lots"]), Err(Error::InvalidArg { .. })));
+        assert!(matches!(Config::from_args(["port=6000"]), Err(Error::InvalidArg { .. })));
+    }
+}
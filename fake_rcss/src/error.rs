@@ -0,0 +1,18 @@
+use std::net::SocketAddr;
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Invalid argument \"{arg}\", {reason}")]
+    InvalidArg {
+        arg: String,
+        reason: &'static str,
+    },
+
+    #[error("Failed to bind UDP socket on \"{addr}\"")]
+    Bind {
+        addr: SocketAddr,
+        source: std::io::Error,
+    },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
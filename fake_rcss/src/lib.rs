@@ -0,0 +1,22 @@
+//! An in-process stand-in for rcssserver, speaking its UDP protocol to players,
+//! the trainer and online coaches, so clients can be tested end to end offline.
+//!
+//! Run it in process with [`FakeServer::spawn`], or as the `fake-rcssserver` binary,
+//! which takes the same `server::name=value` arguments and can be used wherever an
+//! rcssserver executable is expected, e.g. `RcssBinary::new(path).with_wrapper(vec![])`.
+
+mod config;
+mod error;
+mod server;
+mod world;
+
+pub use config::Config;
+pub use error::{Error, Result};
+pub use server::FakeServer;
+pub use world::{ClientId, Outgoing, World};
+
+/// The release reported by `--version`.
+pub const VERSION: &str = "19.0.0";
+
+/// The line `ServerProcess` waits for before it considers the server ready.
+pub const READY_LINE: &str = "Hit CTRL-C to exit";
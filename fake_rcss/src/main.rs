@@ -0,0 +1,41 @@
+use log::error;
+use tokio::signal::unix::{SignalKind, signal};
+
+use common::utils::logging::init_stdout_logger;
+use fake_rcss::{Config, FakeServer, READY_LINE, VERSION};
+
+#[tokio::main]
+async fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "--version" || arg == "-v") {
+        println!("rcssserver-{VERSION}");
+        return;
+    }
+
+    init_stdout_logger("info");
+
+    let config = match Config::from_args(&args) {
+        Ok(config) => config,
+        Err(e) => {
+            error!("{e}");
+            std::process::exit(1);
+        },
+    };
+    let server = match FakeServer::spawn(config).await {
+        Ok(server) => server,
+        Err(e) => {
+            error!("{e}");
+            std::process::exit(1);
+        },
+    };
+
+    println!("rcssserver-{VERSION} (fake)");
+    println!("{READY_LINE}");
+
+    let mut terminate = signal(SignalKind::terminate()).expect("Failed to listen for SIGTERM");
+    tokio::select! {
+        _ = tokio::signal::ctrl_c() => {},
+        _ = terminate.recv() => {},
+    }
+    server.shutdown().await;
+}
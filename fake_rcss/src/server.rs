@@ -0,0 +1,302 @@
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use dashmap::DashMap;
+use log::{debug, info, trace, warn};
+use tokio::net::UdpSocket;
+use tokio::task::{JoinHandle, JoinSet};
+use tokio::time::MissedTickBehavior;
+
+use common::client::{BUFFER_SIZE, Kind};
+use common::types::PlayMode;
+
+use super::error::{Error, Result};
+use super::world::{ClientId, Outgoing, World};
+use super::Config;
+
+#[derive(Debug)]
+struct Shared {
+    world: Mutex<World>,
+    sockets: DashMap<ClientId, Arc<UdpSocket>>,
+}
+
+impl Shared {
+    fn world(&self) -> MutexGuard<'_, World> {
+        self.world.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn dispatch(&self, outgoing: Vec<Outgoing>) {
+        for (id, msg) in outgoing {
+            let Some(socket) = self.sockets.get(&id).map(|s| Arc::clone(s.value())) else {
+                continue
+            };
+            trace!("[FakeServer] -> {id}: {msg}");
+            // rcssserver terminates every message with a `\0`
+            if let Err(e) = socket.try_send(format!("{msg}\0").as_bytes()) {
+                debug!("[FakeServer] Failed to send to client {id}: {e}");
+            }
+        }
+    }
+
+    fn disconnect(&self, id: ClientId) {
+        self.sockets.remove(&id);
+        let outgoing = self.world().disconnect(id);
+        self.dispatch(outgoing);
+    }
+}
+
+/// An rcssserver stand-in listening on the player, trainer and online coach ports.
+///
+/// Every client is redirected to a socket of its own on its first message, as
+/// `UdpConnection::send_and_conn_new_recv` expects. Without synch mode the clock ticks
+/// every `simulator_step`, otherwise it waits for a `(done)` from every client.
+#[derive(Debug)]
+pub struct FakeServer {
+    shared: Arc<Shared>,
+    player_addr: SocketAddr,
+    trainer_addr: SocketAddr,
+    coach_addr: SocketAddr,
+    tasks: Vec<JoinHandle<()>>,
+}
+
+impl FakeServer {
+    pub async fn spawn(config: Config) -> Result<Self> {
+        let bind = |port| {
+            let addr = SocketAddr::new(config.host, port);
+            async move { UdpSocket::bind(addr).await.map_err(|source| Error::Bind { addr, source }) }
+        };
+        let player = bind(config.port).await?;
+        let trainer = bind(config.coach_port).await?;
+        let coach = bind(config.olcoach_port).await?;
+
+        let local_addr = |socket: &UdpSocket| socket.local_addr()
+            .map_err(|source| Error::Bind { addr: SocketAddr::new(config.host, 0), source });
+        let player_addr = local_addr(&player)?;
+        let trainer_addr = local_addr(&trainer)?;
+        let coach_addr = local_addr(&coach)?;
+
+        let synch_mode = config.synch_mode;
+        let simulator_step = config.simulator_step;
+        let shared = Arc::new(Shared {
+            world: Mutex::new(World::new(config)),
+            sockets: DashMap::new(),
+        });
+
+        let mut tasks = vec![
+            tokio::spawn(listen(Arc::clone(&shared), Kind::Player, player)),
+            tokio::spawn(listen(Arc::clone(&shared), Kind::Trainer, trainer)),
+            tokio::spawn(listen(Arc::clone(&shared), Kind::OlCoach, coach)),
+        ];
+        if !synch_mode {
+            let shared = Arc::clone(&shared);
+            tasks.push(tokio::spawn(async move {
+                let mut interval = tokio::time::interval(simulator_step);
+                interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+                loop {
+                    interval.tick().await;
+                    let outgoing = shared.world().step();
+                    shared.dispatch(outgoing);
+                }
+            }));
+        }
+
+        info!(
+            "[FakeServer] Listening, players on {player_addr}, trainer on {trainer_addr}, coach on {coach_addr}"
+        );
+        Ok(Self { shared, player_addr, trainer_addr, coach_addr, tasks })
+    }
+
+    pub fn player_addr(&self) -> SocketAddr {
+        self.player_addr
+    }
+
+    pub fn trainer_addr(&self) -> SocketAddr {
+        self.trainer_addr
+    }
+
+    pub fn coach_addr(&self) -> SocketAddr {
+        self.coach_addr
+    }
+
+    pub fn time(&self) -> u16 {
+        self.shared.world().time()
+    }
+
+    pub fn play_mode(&self) -> PlayMode {
+        self.shared.world().play_mode()
+    }
+
+    /// Goals of the left and the right team.
+    pub fn score(&self) -> (u8, u8) {
+        self.shared.world().score()
+    }
+
+    pub async fn shutdown(mut self) {
+        for task in self.tasks.drain(..) {
+            task.abort();
+            task.await.ok();
+        }
+        self.shared.sockets.clear();
+        info!("[FakeServer] Shut down at cycle {}", self.time());
+    }
+}
+
+impl Drop for FakeServer {
+    fn drop(&mut self) {
+        for task in self.tasks.iter() {
+            task.abort();
+        }
+    }
+}
+
+/// Accept clients on a server port, sessions end with the listener.
+async fn listen(shared: Arc<Shared>, kind: Kind, socket: UdpSocket) {
+    let mut sessions = JoinSet::new();
+    let mut buf = [0u8; BUFFER_SIZE];
+
+    loop {
+        let (len, peer) = match socket.recv_from(&mut buf).await {
+            Ok(ret) => ret,
+            Err(e) => {
+                warn!("[FakeServer] {kind:?} port failed to receive: {e}");
+                continue
+            },
+        };
+        while sessions.try_join_next().is_some() {}
+
+        let msg = String::from_utf8_lossy(&buf[..len]);
+        let msg = msg.trim_end_matches(['\0', '\n', '\r']);
+        trace!("[FakeServer] {kind:?} port <- {peer}: {msg}");
+
+        let connected = shared.world().connect(kind, msg);
+        let (id, replies) = match connected {
+            Ok(ret) => ret,
+            Err(reply) => {
+                debug!("[FakeServer] Rejected {peer} on {kind:?} port: {reply}");
+                socket.send_to(format!("{reply}\0").as_bytes(), peer).await.ok();
+                continue
+            },
+        };
+
+        let session = match open_session(&socket, peer).await {
+            Ok(session) => Arc::new(session),
+            Err(e) => {
+                warn!("[FakeServer] Failed to open a session for {peer}: {e}");
+                shared.world().disconnect(id);
+                continue
+            },
+        };
+        for reply in replies {
+            session.send(format!("{reply}\0").as_bytes()).await.ok();
+        }
+        // registered after the replies, so nothing overtakes the init reply
+        shared.sockets.insert(id, Arc::clone(&session));
+        sessions.spawn(serve(Arc::clone(&shared), id, session));
+    }
+}
+
+async fn open_session(socket: &UdpSocket, peer: SocketAddr) -> std::io::Result<UdpSocket> {
+    let host = socket.local_addr()?;
+    let session = UdpSocket::bind(SocketAddr::new(host.ip(), 0)).await?;
+    session.connect(peer).await?;
+    Ok(session)
+}
+
+async fn serve(shared: Arc<Shared>, id: ClientId, socket: Arc<UdpSocket>) {
+    let mut buf = [0u8; BUFFER_SIZE];
+
+    loop {
+        let len = match socket.recv(&mut buf).await {
+            Ok(len) => len,
+            Err(e) => {
+                // the client closed its socket, which surfaces as a refused connection
+                debug!("[FakeServer] Client {id} unreachable: {e}");
+                break
+            },
+        };
+
+        let msg = String::from_utf8_lossy(&buf[..len]);
+        let msg = msg.trim_end_matches(['\0', '\n', '\r']);
+        trace!("[FakeServer] <- {id}: {msg}");
+
+        let (outgoing, connected) = {
+            let mut world = shared.world();
+            let outgoing = world.handle(id, msg);
+            (outgoing, world.is_connected(id))
+        };
+        shared.dispatch(outgoing);
+        if !connected {
+            break
+        }
+    }
+
+    shared.disconnect(id);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::sync::mpsc;
+
+    use common::client::{self, Client};
+    use common::command::player::CommandInit;
+    use process::Player;
+
+    use super::*;
+
+    const TIMEOUT: Duration = Duration::from_secs(2);
+
+    async fn recv_until(rx: &mut mpsc::Receiver<client::RxData>, head: &str) -> String {
+        let recv = async {
+            while let Some(msg) = rx.recv().await {
+                if msg.starts_with(head) {
+                    return msg.trim_end_matches('\0').to_string();
+                }
+            }
+            panic!("channel closed while waiting for {head}");
+        };
+        tokio::time::timeout(TIMEOUT, recv).await.expect(head)
+    }
+
+    #[tokio::test]
+    async fn test_player_and_trainer_end_to_end() {
+        let server = FakeServer::spawn(Config::ephemeral()).await.unwrap();
+
+        let mut builder = Player::builder();
+        builder.rich_client.with_peer(server.player_addr());
+        builder.enable_resolver().init_on_build(CommandInit {
+            team_name: "Fake".to_string(),
+            is_goalie: true,
+            ..Default::default()
+        });
+        let player = builder.build().await.unwrap();
+        let (player_tx, mut player_rx) = mpsc::channel(32);
+        player.subscribe(player_tx);
+
+        let mut params = player.watch_server_params();
+        tokio::time::timeout(TIMEOUT, params.wait_for(|p| p.is_complete())).await.unwrap().unwrap();
+        assert_eq!(player.server_params().player_types.len(), 18);
+
+        let trainer = Client::new(client::Config {
+            kind: client::Kind::Trainer,
+            peer: server.trainer_addr(),
+            ..Default::default()
+        });
+        let (trainer_tx, mut trainer_rx) = mpsc::channel(32);
+        trainer.subscribe(trainer_tx);
+        trainer.connect().await.unwrap();
+        trainer.send_data("(init 5)".into()).await.unwrap();
+        assert_eq!(recv_until(&mut trainer_rx, "(init").await, "(init ok)");
+
+        trainer.send_data("(start)".into()).await.unwrap();
+        assert_eq!(recv_until(&mut trainer_rx, "(ok").await, "(ok start)");
+        assert_eq!(recv_until(&mut player_rx, "(hear").await, "(hear 0 referee kick_off_l)");
+        assert_eq!(server.play_mode(), PlayMode::PM_KickOff_Left);
+
+        trainer.send_data("(team_names)".into()).await.unwrap();
+        assert_eq!(recv_until(&mut trainer_rx, "(ok").await, "(ok team_names (team l Fake))");
+
+        server.shutdown().await;
+    }
+}
@@ -0,0 +1,679 @@
+//! The simulated match, answering and announcing what rcssserver would.
+//!
+//! Nothing moves but the ball, which rolls and decays once kicked by a trainer `move`,
+//! and the referee only knows about kick-offs, goals, halves and the end of the match.
+
+use std::collections::BTreeMap;
+
+use log::{debug, info};
+
+use common::client::Kind;
+use common::types::{BallPosition, EarMode, EyeMode, PlayMode, Side};
+use common::utils::sexp::Sexp;
+
+use super::Config;
+
+pub type ClientId = u64;
+
+/// A message for one client, sent from the socket it was redirected to.
+pub type Outgoing = (ClientId, String);
+
+const PITCH_HALF_LENGTH: f32 = 52.5;
+const PITCH_HALF_WIDTH: f32 = 34.0;
+const BALL_DECAY: f32 = 0.94;
+const TEAM_SIZE: u8 = 11;
+/// Cycles the referee waits after a goal before the kick-off.
+const AFTER_GOAL_WAIT: u16 = 50;
+
+#[derive(Clone, Copy, Debug, Default)]
+struct Body {
+    x: f32,
+    y: f32,
+    vx: f32,
+    vy: f32,
+    dir: f32,
+}
+
+#[derive(Debug)]
+struct Client {
+    kind: Kind,
+    ear: bool,
+    eye: bool,
+    done: bool,
+}
+
+#[derive(Debug)]
+struct Slot {
+    client: Option<ClientId>,
+    goalie: bool,
+    body: Body,
+}
+
+#[derive(Debug, Default)]
+struct Team {
+    name: Option<String>,
+    players: BTreeMap<u8, Slot>,
+    coach: Option<ClientId>,
+    score: u8,
+}
+
+#[derive(Debug)]
+pub struct World {
+    config: Config,
+    time: u16,
+    play_mode: PlayMode,
+    /// When the current play mode was entered.
+    mode_since: u16,
+    ball: Body,
+    teams: [Team; 2],
+    clients: BTreeMap<ClientId, Client>,
+    next_id: ClientId,
+}
+
+impl World {
+    pub fn new(config: Config) -> Self {
+        Self {
+            config,
+            time: 0,
+            play_mode: PlayMode::PM_BeforeKickOff,
+            mode_since: 0,
+            ball: Body::default(),
+            teams: Default::default(),
+            clients: BTreeMap::new(),
+            next_id: 1,
+        }
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    pub fn time(&self) -> u16 {
+        self.time
+    }
+
+    pub fn play_mode(&self) -> PlayMode {
+        self.play_mode
+    }
+
+    /// Goals of the left and the right team.
+    pub fn score(&self) -> (u8, u8) {
+        (self.teams[0].score, self.teams[1].score)
+    }
+
+    pub fn is_connected(&self, id: ClientId) -> bool {
+        self.clients.contains_key(&id)
+    }
+
+    /// Handle the first message of a client on one of the server ports.
+    ///
+    /// `Ok` registers the client, its replies are sent from a socket of its own, which is
+    /// how rcssserver redirects every client to a dedicated port. `Err` is the reply to send
+    /// back from the server port.
+    pub fn connect(&mut self, kind: Kind, msg: &str) -> Result<(ClientId, Vec<String>), String> {
+        let Ok(sexp) = Sexp::parse(msg) else {
+            return Err(error("illegal_command_form"));
+        };
+        let Some((head, args)) = sexp.list().and_then(|l| l.split_first()) else {
+            return Err(error("illegal_command_form"));
+        };
+
+        let id = self.next_id;
+        let (side, reply) = match (kind, head.atom()) {
+            (Kind::Player, Some("init")) => {
+                let (side, unum) = self.join(id, args)?;
+                (side, format!("(init {} {unum} {})", side.encode(), self.play_mode.encode()))
+            },
+            (Kind::Player, Some("reconnect")) => {
+                let (side, unum) = self.rejoin(id, args)?;
+                (side, format!("(reconnect {} {unum} {})", side.encode(), self.play_mode.encode()))
+            },
+            (Kind::Trainer, Some("init")) => (Side::NEUTRAL, "(init ok)".to_string()),
+            (Kind::OlCoach, Some("init")) => {
+                let side = self.assign_coach(id, args)?;
+                (side, format!("(init {} ok)", side.encode()))
+            },
+            _ => return Err(error("illegal_command_form")),
+        };
+
+        self.next_id += 1;
+        self.clients.insert(id, Client { kind, ear: false, eye: false, done: false });
+        info!("[World] Client {id} joined as {kind:?} ({}), {reply}", side.encode());
+
+        let mut replies = vec![reply];
+        replies.extend(self.config.param_messages());
+        Ok((id, replies))
+    }
+
+    /// Forget a client, a player keeps its place on the field until it reconnects.
+    pub fn disconnect(&mut self, id: ClientId) -> Vec<Outgoing> {
+        let Some(client) = self.clients.remove(&id) else {
+            return vec![];
+        };
+        info!("[World] Client {id} ({:?}) left", client.kind);
+
+        for team in self.teams.iter_mut() {
+            if team.coach == Some(id) {
+                team.coach = None;
+            }
+            for slot in team.players.values_mut().filter(|s| s.client == Some(id)) {
+                slot.client = None;
+            }
+        }
+        self.step_if_synched()
+    }
+
+    /// Handle a message from a connected client, possibly holding several commands.
+    pub fn handle(&mut self, id: ClientId, msg: &str) -> Vec<Outgoing> {
+        let mut out = vec![];
+        let Ok(commands) = Sexp::parse_many(msg) else {
+            out.push((id, error("illegal_command_form")));
+            return out;
+        };
+
+        for command in &commands {
+            let Some(kind) = self.clients.get(&id).map(|c| c.kind) else {
+                break;
+            };
+            let Some((head, args)) = command.list().and_then(|l| l.split_first()) else {
+                out.push((id, error("illegal_command_form")));
+                continue;
+            };
+
+            let head = head.atom().unwrap_or_default();
+            if head == "done" {
+                if let Some(client) = self.clients.get_mut(&id) {
+                    client.done = true;
+                }
+                out.extend(self.step_if_synched());
+                continue;
+            }
+
+            let reply = match kind {
+                Kind::Player => self.player_command(id, head, args, &mut out),
+                Kind::Trainer => self.trainer_command(id, head, args, &mut out),
+                Kind::OlCoach => self.coach_command(id, head, args),
+            };
+            if let Some(reply) = reply {
+                out.push((id, reply));
+            }
+        }
+        out
+    }
+
+    /// Advance one cycle: the ball rolls, the referee rules and every eye sees the field.
+    ///
+    /// In synch mode this also releases everyone with a `(think)`.
+    pub fn step(&mut self) -> Vec<Outgoing> {
+        let mut out = vec![];
+
+        if !matches!(self.play_mode, PlayMode::PM_BeforeKickOff | PlayMode::PM_TimeOver) {
+            self.time = self.time.saturating_add(1);
+        }
+
+        if self.play_mode == PlayMode::PM_PlayOn {
+            self.ball.x += self.ball.vx;
+            self.ball.y += self.ball.vy;
+            self.ball.vx *= BALL_DECAY;
+            self.ball.vy *= BALL_DECAY;
+
+            match self.ball_position() {
+                BallPosition::GoalL => out.extend(self.goal(Side::RIGHT)),
+                BallPosition::GoalR => out.extend(self.goal(Side::LEFT)),
+                _ => {},
+            }
+        }
+
+        let after_goal = match self.play_mode {
+            PlayMode::PM_AfterGoal_Left => Some(PlayMode::PM_KickOff_Right),
+            PlayMode::PM_AfterGoal_Right => Some(PlayMode::PM_KickOff_Left),
+            _ => None,
+        };
+        if let Some(kick_off) = after_goal
+            && self.time.saturating_sub(self.mode_since) >= AFTER_GOAL_WAIT
+        {
+            self.ball = Body::default();
+            out.extend(self.change_mode(kick_off));
+        }
+
+        if let Some(half) = self.config.half_cycles()
+            && self.play_mode != PlayMode::PM_BeforeKickOff
+            && self.play_mode != PlayMode::PM_TimeOver
+            && self.time > 0
+            && self.time.is_multiple_of(half)
+        {
+            if self.time / half >= self.config.nr_normal_halfs {
+                out.extend(self.change_mode(PlayMode::PM_TimeOver));
+            } else {
+                self.ball = Body::default();
+                out.extend(self.referee("half_time"));
+                self.play_mode = PlayMode::PM_BeforeKickOff;
+                self.mode_since = self.time;
+            }
+        }
+
+        let see_global = format!("(see_global {} {})", self.time, self.objects());
+        for (id, client) in self.clients.iter_mut() {
+            if client.eye {
+                out.push((*id, see_global.clone()));
+            }
+            if self.config.synch_mode {
+                client.done = false;
+                out.push((*id, "(think)".to_string()));
+            }
+        }
+        out
+    }
+
+    fn step_if_synched(&mut self) -> Vec<Outgoing> {
+        let all_done = !self.clients.is_empty() && self.clients.values().all(|c| c.done);
+        if self.config.synch_mode && all_done {
+            return self.step();
+        }
+        vec![]
+    }
+
+    fn join(&mut self, id: ClientId, args: &[Sexp]) -> Result<(Side, u8), String> {
+        let name = args.first()
+            .and_then(Sexp::atom)
+            .filter(|name| is_team_name(name))
+            .ok_or_else(|| error("illegal_teamname"))?;
+        let goalie = args.iter().skip(1).any(|a| a.head() == Some("goalie"));
+
+        let no_more = || error("no_more_team_or_player_or_goalie");
+        let index = self.team_index(name)
+            .or_else(|| self.teams.iter().position(|t| t.name.is_none()))
+            .ok_or_else(no_more)?;
+
+        let team = &mut self.teams[index];
+        if goalie && team.players.values().any(|s| s.goalie) {
+            return Err(no_more());
+        }
+        let unum = (1..=TEAM_SIZE).find(|u| !team.players.contains_key(u)).ok_or_else(no_more)?;
+
+        team.name.get_or_insert_with(|| name.to_string());
+        let side = side_of(index);
+        // waiting next to the pitch, like rcssserver does before the first move
+        let sign = if side == Side::LEFT { -1.0 } else { 1.0 };
+        let body = Body { x: sign * 3.0 * unum as f32, y: -PITCH_HALF_WIDTH - 3.0, ..Default::default() };
+        team.players.insert(unum, Slot { client: Some(id), goalie, body });
+        Ok((side, unum))
+    }
+
+    fn rejoin(&mut self, id: ClientId, args: &[Sexp]) -> Result<(Side, u8), String> {
+        let (Some(name), Some(unum)) = (args.first().and_then(Sexp::atom), args.get(1).and_then(Sexp::parse_atom)) else {
+            return Err(error("illegal_command_form"));
+        };
+        let index = self.team_index(name).ok_or_else(|| error("reconnect"))?;
+        match self.teams[index].players.get_mut(&unum) {
+            Some(slot) if slot.client.is_none() => {
+                slot.client = Some(id);
+                Ok((side_of(index), unum))
+            },
+            _ => Err(error("reconnect")),
+        }
+    }
+
+    fn assign_coach(&mut self, id: ClientId, args: &[Sexp]) -> Result<Side, String> {
+        let no_such = || error("no_such_team_or_already_have_coach");
+        let name = args.first().and_then(Sexp::atom).ok_or_else(|| error("illegal_command_form"))?;
+        let index = self.team_index(name).ok_or_else(no_such)?;
+
+        let team = &mut self.teams[index];
+        if team.coach.is_some() {
+            return Err(no_such());
+        }
+        team.coach = Some(id);
+        Ok(side_of(index))
+    }
+
+    fn player_command(&mut self, id: ClientId, head: &str, args: &[Sexp], out: &mut Vec<Outgoing>) -> Option<String> {
+        match head {
+            "bye" => out.extend(self.disconnect(id)),
+            "move" => {
+                let movable = matches!(
+                    self.play_mode,
+                    PlayMode::PM_BeforeKickOff | PlayMode::PM_AfterGoal_Left | PlayMode::PM_AfterGoal_Right
+                );
+                if let (true, Some(x), Some(y)) = (movable, args.first(), args.get(1))
+                    && let (Some(x), Some(y)) = (x.parse_atom(), y.parse_atom())
+                    && let Some(slot) = self.slot_of(id)
+                {
+                    slot.body.x = x;
+                    slot.body.y = y;
+                }
+            },
+            // nothing else is simulated
+            _ => debug!("[World] Player {id} sent {head}"),
+        }
+        None
+    }
+
+    fn trainer_command(&mut self, id: ClientId, head: &str, args: &[Sexp], out: &mut Vec<Outgoing>) -> Option<String> {
+        let reply = match head {
+            "init" => "(init ok)".to_string(),
+            "check_ball" => format!("(ok check_ball {} {})", self.time, self.ball_position().encode()),
+            "look" => format!("(ok look {} {})", self.time, self.objects()),
+            "team_names" => format!("(ok team_names{})", self.team_names()),
+            "recover" => "(ok recover)".to_string(),
+            "start" => {
+                if self.play_mode == PlayMode::PM_BeforeKickOff {
+                    out.extend(self.change_mode(self.kick_off()));
+                }
+                "(ok start)".to_string()
+            },
+            "change_mode" => match args.first().and_then(Sexp::atom) {
+                None => error("illegal_command_form"),
+                Some(mode) => match PlayMode::decode(mode) {
+                    Some(play_mode) => {
+                        out.extend(self.change_mode(play_mode));
+                        "(ok change_mode)".to_string()
+                    },
+                    None => error("illegal_mode"),
+                },
+            },
+            "ear" => match args.first().map(|a| a.parse_atom::<EarMode>()) {
+                None => error("illegal_command_form"),
+                Some(None) => error("illegal_mode"),
+                Some(Some(mode)) => {
+                    if let Some(client) = self.clients.get_mut(&id) {
+                        client.ear = matches!(mode, EarMode::On);
+                    }
+                    format!("(ok ear {})", mode.encode())
+                },
+            },
+            "eye" => self.eye(id, args),
+            "move" => self.trainer_move(args),
+            _ => error("unknown_command"),
+        };
+        Some(reply)
+    }
+
+    fn coach_command(&mut self, id: ClientId, head: &str, args: &[Sexp]) -> Option<String> {
+        let reply = match head {
+            "eye" => self.eye(id, args),
+            "look" => format!("(ok look {} {})", self.time, self.objects()),
+            "team_names" => format!("(ok team_names{})", self.team_names()),
+            "score" => {
+                let (left, right) = self.score();
+                format!("(score {} {left} {right})", self.time)
+            },
+            "say" => "(ok say)".to_string(),
+            "change_player_type" => match (args.first().and_then(Sexp::atom), args.get(1).and_then(Sexp::atom)) {
+                (Some(unum), Some(player_type)) => format!("(ok change_player_type {unum} {player_type})"),
+                _ => error("illegal_command_form"),
+            },
+            "team_graphic" => match args.first().and_then(Sexp::list) {
+                Some([x, y, ..]) => format!("(ok team_graphic {x} {y})"),
+                _ => error("illegal_command_form"),
+            },
+            _ => error("unknown_command"),
+        };
+        Some(reply)
+    }
+
+    fn eye(&mut self, id: ClientId, args: &[Sexp]) -> String {
+        match args.first().map(|a| a.parse_atom::<EyeMode>()) {
+            None => error("illegal_command_form"),
+            Some(None) => error("illegal_mode"),
+            Some(Some(mode)) => {
+                if let Some(client) = self.clients.get_mut(&id) {
+                    client.eye = matches!(mode, EyeMode::On);
+                }
+                format!("(ok eye {})", mode.encode())
+            },
+        }
+    }
+
+    /// `(move (ball) X Y [DIR [VX VY]])` or `(move (player TEAM UNUM) X Y [DIR [VX VY]])`.
+    fn trainer_move(&mut self, args: &[Sexp]) -> String {
+        let Some((object, values)) = args.split_first() else {
+            return error("illegal_command_form");
+        };
+        let Some(values) = values.iter().map(Sexp::parse_atom::<f32>).collect::<Option<Vec<_>>>() else {
+            return error("illegal_command_form");
+        };
+        let (x, y, dir, vel) = match values[..] {
+            [x, y] => (x, y, None, None),
+            [x, y, dir] => (x, y, Some(dir), None),
+            [x, y, dir, vx, vy] => (x, y, Some(dir), Some((vx, vy))),
+            _ => return error("illegal_command_form"),
+        };
+
+        let body = match object.list() {
+            Some([kind]) if kind.is_atom("ball") || kind.is_atom("b") => &mut self.ball,
+            Some([kind, team, unum, ..]) if kind.is_atom("player") || kind.is_atom("p") => {
+                let index = team.atom().and_then(|t| self.team_index(t));
+                let slot = index.zip(unum.parse_atom::<u8>())
+                    .and_then(|(index, unum)| self.teams[index].players.get_mut(&unum));
+                match slot {
+                    Some(slot) => &mut slot.body,
+                    None => return error("illegal_object_form"),
+                }
+            },
+            _ => return error("illegal_object_form"),
+        };
+
+        body.x = x;
+        body.y = y;
+        if let Some(dir) = dir {
+            body.dir = dir;
+        }
+        let (vx, vy) = vel.unwrap_or_default();
+        body.vx = vx;
+        body.vy = vy;
+        "(ok move)".to_string()
+    }
+
+    fn change_mode(&mut self, play_mode: PlayMode) -> Vec<Outgoing> {
+        if self.play_mode == play_mode {
+            return vec![];
+        }
+        self.play_mode = play_mode;
+        self.mode_since = self.time;
+        self.referee(play_mode.encode())
+    }
+
+    fn goal(&mut self, scorer: Side) -> Vec<Outgoing> {
+        let index = team_index(scorer);
+        self.teams[index].score += 1;
+        let message = format!("goal_{}_{}", scorer.encode(), self.teams[index].score);
+        info!("[World] {message} at {}", self.time);
+
+        self.ball.vx = 0.0;
+        self.ball.vy = 0.0;
+        self.play_mode = if scorer == Side::LEFT { PlayMode::PM_AfterGoal_Left } else { PlayMode::PM_AfterGoal_Right };
+        self.mode_since = self.time;
+        self.referee(&message)
+    }
+
+    /// The second half, like every even one, is kicked off by the right team.
+    fn kick_off(&self) -> PlayMode {
+        let half = self.config.half_cycles().map_or(0, |half| self.time / half);
+        if half.is_multiple_of(2) { PlayMode::PM_KickOff_Left } else { PlayMode::PM_KickOff_Right }
+    }
+
+    /// Players and online coaches always hear the referee, the trainer only with its ear on.
+    fn referee(&self, message: &str) -> Vec<Outgoing> {
+        let hear = format!("(hear {} referee {message})", self.time);
+        self.clients.iter()
+            .filter(|(_, c)| c.kind != Kind::Trainer || c.ear)
+            .map(|(id, _)| (*id, hear.clone()))
+            .collect()
+    }
+
+    fn ball_position(&self) -> BallPosition {
+        let in_goal = self.ball.y.abs() < self.config.goal_width / 2.0;
+        if self.ball.x < -PITCH_HALF_LENGTH {
+            if in_goal { BallPosition::GoalL } else { BallPosition::OutOfField }
+        } else if self.ball.x > PITCH_HALF_LENGTH {
+            if in_goal { BallPosition::GoalR } else { BallPosition::OutOfField }
+        } else if self.ball.y.abs() > PITCH_HALF_WIDTH {
+            BallPosition::OutOfField
+        } else {
+            BallPosition::InField
+        }
+    }
+
+    /// The objects of `look` and `see_global`, see [`common::types::WorldState`].
+    fn objects(&self) -> String {
+        let ball = &self.ball;
+        let mut ret = format!(
+            "((g l) {} 0) ((g r) {} 0) ((b) {} {} {} {})",
+            -PITCH_HALF_LENGTH, PITCH_HALF_LENGTH, ball.x, ball.y, ball.vx, ball.vy,
+        );
+        for team in self.teams.iter() {
+            let Some(name) = &team.name else { continue };
+            for (unum, slot) in team.players.iter() {
+                let goalie = if slot.goalie { " goalie" } else { "" };
+                let b = &slot.body;
+                ret += &format!(" ((p \"{name}\" {unum}{goalie}) {} {} {} {} {} 0)", b.x, b.y, b.vx, b.vy, b.dir);
+            }
+        }
+        ret
+    }
+
+    fn team_names(&self) -> String {
+        self.teams.iter()
+            .enumerate()
+            .filter_map(|(index, team)| Some(format!(" (team {} {})", side_of(index).encode(), team.name.as_ref()?)))
+            .collect()
+    }
+
+    fn team_index(&self, name: &str) -> Option<usize> {
+        self.teams.iter().position(|t| t.name.as_deref() == Some(name))
+    }
+
+    fn slot_of(&mut self, id: ClientId) -> Option<&mut Slot> {
+        self.teams.iter_mut()
+            .flat_map(|t| t.players.values_mut())
+            .find(|s| s.client == Some(id))
+    }
+}
+
+fn error(kind: &str) -> String {
+    format!("(error {kind})")
+}
+
+fn team_index(side: Side) -> usize {
+    if side == Side::RIGHT { 1 } else { 0 }
+}
+
+fn side_of(index: usize) -> Side {
+    if index == 0 { Side::LEFT } else { Side::RIGHT }
+}
+
+fn is_team_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn world(synch_mode: bool) -> World {
+        let mut config = Config::default();
+        config.synch_mode = synch_mode;
+        World::new(config)
+    }
+
+    fn messages(out: &[Outgoing], id: ClientId) -> Vec<&str> {
+        out.iter().filter(|(to, _)| *to == id).map(|(_, msg)| msg.as_str()).collect()
+    }
+
+    #[test]
+    fn test_players_join_teams() {
+        let mut world = world(false);
+
+        let (_, replies) = world.connect(Kind::Player, "(init Left (version 19) (goalie))").unwrap();
+        assert_eq!(replies[0], "(init l 1 before_kick_off)");
+        assert!(replies[1].starts_with("(server_param "));
+        assert_eq!(world.connect(Kind::Player, "(init Left (version 19))").unwrap().1[0], "(init l 2 before_kick_off)");
+        assert_eq!(world.connect(Kind::Player, "(init Right)").unwrap().1[0], "(init r 1 before_kick_off)");
+
+        assert_eq!(world.connect(Kind::Player, "(init Third)").unwrap_err(), "(error no_more_team_or_player_or_goalie)");
+        assert_eq!(world.connect(Kind::Player, "(init Left (goalie))").unwrap_err(), "(error no_more_team_or_player_or_goalie)");
+        assert_eq!(world.connect(Kind::OlCoach, "(init Right (version 19))").unwrap().1[0], "(init r ok)");
+        assert!(world.connect(Kind::OlCoach, "(init Right)").is_err());
+
+        let (trainer, _) = world.connect(Kind::Trainer, "(init 5)").unwrap();
+        let out = world.handle(trainer, "(team_names)");
+        assert_eq!(messages(&out, trainer), ["(ok team_names (team l Left) (team r Right))"]);
+    }
+
+    #[test]
+    fn test_trainer_commands() {
+        let mut world = world(false);
+        let (player, _) = world.connect(Kind::Player, "(init Left)").unwrap();
+        let (trainer, replies) = world.connect(Kind::Trainer, "(init 5)").unwrap();
+        assert_eq!(replies[0], "(init ok)");
+
+        let out = world.handle(trainer, "(change_mode play_on)");
+        assert_eq!(messages(&out, trainer), ["(ok change_mode)"]);
+        assert_eq!(messages(&out, player), ["(hear 0 referee play_on)"]);
+
+        let out = world.handle(trainer, "(ear on)(change_mode no_such_mode)");
+        assert_eq!(messages(&out, trainer), ["(ok ear on)", "(error illegal_mode)"]);
+
+        let out = world.handle(trainer, "(move (ball) 10 -5)(check_ball)(look)");
+        assert_eq!(messages(&out, trainer)[..2], ["(ok move)", "(ok check_ball 0 in_field)"]);
+        let look = Sexp::parse(messages(&out, trainer)[2]).unwrap();
+        let state = common::types::WorldState::parse(&look.list().unwrap()[2..]).unwrap();
+        assert_eq!(state.ball.unwrap().x, 10.0);
+        assert_eq!(state.players.len(), 1);
+
+        let out = world.handle(trainer, "(move (player Right 1) 0 0)(fly)");
+        assert_eq!(messages(&out, trainer), ["(error illegal_object_form)", "(error unknown_command)"]);
+    }
+
+    #[test]
+    fn test_goal_and_kick_off() {
+        let mut world = world(false);
+        let (trainer, _) = world.connect(Kind::Trainer, "(init)").unwrap();
+        world.handle(trainer, "(ear on)(start)(change_mode play_on)(move (ball) 50 0 0 3 0)");
+
+        let heard: Vec<_> = (0..AFTER_GOAL_WAIT + 5)
+            .flat_map(|_| world.step())
+            .map(|(_, msg)| msg)
+            .collect();
+        assert_eq!(heard, ["(hear 1 referee goal_l_1)", "(hear 51 referee kick_off_r)"]);
+        assert_eq!(world.score(), (1, 0));
+        assert_eq!(world.play_mode(), PlayMode::PM_KickOff_Right);
+    }
+
+    #[test]
+    fn test_synch_mode_waits_for_everyone() {
+        let mut world = world(true);
+        let (trainer, _) = world.connect(Kind::Trainer, "(init)").unwrap();
+        let (player, _) = world.connect(Kind::Player, "(init Left)").unwrap();
+        world.handle(trainer, "(start)");
+
+        assert!(world.handle(trainer, "(done)").is_empty());
+        let out = world.handle(player, "(done)");
+        assert_eq!(messages(&out, trainer), ["(think)"]);
+        assert_eq!(world.time(), 1);
+
+        // a client leaving no longer holds the others back
+        world.handle(trainer, "(done)");
+        let out = world.disconnect(player);
+        assert_eq!(messages(&out, trainer), ["(think)"]);
+        assert_eq!(world.time(), 2);
+    }
+
+    #[test]
+    fn test_time_over() {
+        let mut config = Config::default();
+        config.half_time = 1;
+        let mut world = World::new(config);
+        let (trainer, _) = world.connect(Kind::Trainer, "(init)").unwrap();
+        world.handle(trainer, "(ear on)(start)");
+
+        let heard: Vec<_> = (0..30).flat_map(|_| world.step()).map(|(_, msg)| msg).collect();
+        assert_eq!(heard, ["(hear 10 referee half_time)"]);
+        world.handle(trainer, "(start)");
+        assert_eq!(world.play_mode(), PlayMode::PM_KickOff_Right);
+
+        let heard: Vec<_> = (0..30).flat_map(|_| world.step()).map(|(_, msg)| msg).collect();
+        assert_eq!(heard, ["(hear 20 referee time_over)"]);
+    }
+}
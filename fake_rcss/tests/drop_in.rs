@@ -0,0 +1,32 @@
+//! The `fake-rcssserver` binary spawned the way rcssserver is.
+
+use std::net::UdpSocket;
+use std::time::Duration;
+
+use common::command::trainer::{ChangeMode, CheckBall};
+use common::types::PlayMode;
+use process::{CoachedProcessSpawner, RcssBinary, RcssVersion};
+
+fn free_port() -> u16 {
+    UdpSocket::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port()
+}
+
+#[tokio::test]
+async fn test_coached_process_on_fake_binary() {
+    let binary = RcssBinary::new(env!("CARGO_BIN_EXE_fake-rcssserver")).with_wrapper(vec![]);
+    let mut spawner = CoachedProcessSpawner::from_binary(binary).await.unwrap();
+    assert_eq!(spawner.process.version(), RcssVersion::new(19, 0, 0));
+
+    spawner.with_ports(free_port(), free_port(), free_port()).with_sync_mode(true);
+    let mut process = spawner.spawn().await.unwrap();
+
+    let coach = process.coach();
+    let check = coach.call(CheckBall).await.unwrap().unwrap();
+    assert_eq!(check.time, 0);
+
+    coach.call(ChangeMode { play_mode: PlayMode::PM_PlayOn }).await.unwrap().unwrap();
+    let outcome = coach.step(3, Duration::from_secs(2)).await.unwrap();
+    assert_eq!((outcome.stepped, outcome.time), (3, 3));
+
+    process.shutdown().await.unwrap();
+}
@@ -1,10 +1,13 @@
 use std::sync::{Arc, OnceLock};
 use std::time::Duration;
-use log::info;
+use chrono::Utc;
+use log::{debug, info, warn};
 
+use serde::Deserialize;
 use tokio::sync::{watch, RwLock};
+use tokio::task::JoinHandle;
 
-use common::types::Side;
+use common::types::{MatchResult, Side};
 
 use crate::team::{self, Team, TeamStatus};
 use crate::metadata::MetaData;
@@ -94,6 +97,29 @@ impl MatchComposer {
     }
 }
 
+/// How long to wait for the server when asking for the match result.
+const RESULT_TIMEOUT: Duration = Duration::from_secs(2);
+/// How many times to ask for the result once the match is over.
+const RESULT_ATTEMPTS: usize = 5;
+const RESULT_RETRY_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The part of the server's `/metrics/status` reply the composer reads.
+#[derive(Deserialize)]
+struct ServerStatusResponse {
+    payload: ServerStatusPayload,
+}
+
+#[derive(Deserialize)]
+struct ServerStatusPayload {
+    service: ServiceStatus,
+}
+
+#[derive(Deserialize)]
+struct ServiceStatus {
+    #[serde(default)]
+    match_result: Option<MatchResult>,
+}
+
 pub struct Match {
     pub rcsss: RcssServerConfig,
     pub config: Arc<MetaData>,
@@ -102,6 +128,9 @@ pub struct Match {
     pub status: watch::Receiver<GameStatusInfo>,
     
     status_tx: watch::Sender<GameStatusInfo>,
+    result_tx: watch::Sender<Option<MatchResult>>,
+    http: reqwest::Client,
+    watch_task: Option<JoinHandle<()>>,
 }
 
 impl Match {
@@ -112,6 +141,11 @@ impl Match {
         team_r: Team,
     ) -> Self {
         let (status_tx, status) = watch::channel(GameStatusInfo::Idle);
+        let (result_tx, _) = watch::channel(None);
+        let http = reqwest::Client::builder()
+            .timeout(RESULT_TIMEOUT)
+            .build()
+            .expect("failed to build reqwest client");
         
         Self {
             rcsss,
@@ -120,6 +154,9 @@ impl Match {
             team_r,
             status,
             status_tx,
+            result_tx,
+            http,
+            watch_task: None,
         }
     }
 
@@ -134,6 +171,7 @@ impl Match {
         tokio::time::sleep(team_delay).await;
         self.team_r.spawn(registry, player_delay).await?;
         info!("Team R spawned successfully, {:?}", self.team_r.info());
+        self.start_watch();
         Ok(())
     }
 
@@ -152,11 +190,57 @@ impl Match {
         tokio::try_join!(spawn_l, spawn_r)?;
         info!("Team L spawned successfully, {:?}", self.team_l.info());
         info!("Team R spawned successfully, {:?}", self.team_r.info());
+        self.start_watch();
 
         Ok(())
     }
 
+    /// Mark the match running, then finished once both teams are done, and fetch
+    /// the result from the server in the background so `/status` never waits on it.
+    fn start_watch(&mut self) {
+        let started_at = Utc::now();
+        self.status_tx.send_replace(GameStatusInfo::Running { started_at });
+
+        let mut watch_l = self.team_l.status_watch();
+        let mut watch_r = self.team_r.status_watch();
+        let status_tx = self.status_tx.clone();
+        let result_tx = self.result_tx.clone();
+        let http = self.http.clone();
+        let url = format!("http://{}/metrics/status", self.rcsss.control);
+
+        self.watch_task = Some(tokio::spawn(async move {
+            tokio::join!(
+                async { let _ = watch_l.wait_for(|s| s.is_finished()).await; },
+                async { let _ = watch_r.wait_for(|s| s.is_finished()).await; },
+            );
+            status_tx.send_replace(GameStatusInfo::Finished { started_at, finished_at: Utc::now() });
+
+            for attempt in 1..=RESULT_ATTEMPTS {
+                if let Some(result) = fetch_result(&http, &url).await {
+                    info!("[Match] Result received: {result:?}");
+                    result_tx.send_replace(Some(result));
+                    return;
+                }
+                if attempt < RESULT_ATTEMPTS {
+                    tokio::time::sleep(RESULT_RETRY_INTERVAL).await;
+                }
+            }
+            warn!("[Match] No result from {url} after {RESULT_ATTEMPTS} attempts");
+        }));
+    }
+
     pub async fn shutdown(&mut self) -> Result<()> {
+        if let Some(task) = self.watch_task.take() {
+            task.abort();
+        }
+        if let GameStatusInfo::Running { started_at } = self.status_now() {
+            self.status_tx.send_replace(GameStatusInfo::Terminated {
+                started_at,
+                finished_at: Utc::now(),
+                reason: "shutdown".to_string(),
+            });
+        }
+
         self.team_l.shutdown().await;
         self.team_r.shutdown().await;
 
@@ -201,6 +285,7 @@ impl Match {
             status: self.status_now(),
             team_l: self.team_l.info(),
             team_r: self.team_r.info(),
+            result: self.result_now(),
         }
     }
     
@@ -211,6 +296,35 @@ impl Match {
     pub fn status_watch(&self) -> watch::Receiver<GameStatusInfo> {
         self.status.clone()
     }
+
+    pub fn result_now(&self) -> Option<MatchResult> {
+        self.result_tx.borrow().clone()
+    }
+}
+
+impl Drop for Match {
+    fn drop(&mut self) {
+        if let Some(task) = self.watch_task.take() {
+            task.abort();
+        }
+    }
+}
+
+/// Ask the server how the match ended.
+async fn fetch_result(http: &reqwest::Client, url: &str) -> Option<MatchResult> {
+    let res = async {
+        http.get(url).send().await?
+            .error_for_status()?
+            .json::<ServerStatusResponse>().await
+    }.await;
+
+    match res {
+        Ok(status) => status.payload.service.match_result,
+        Err(e) => {
+            debug!("[Match] Failed to fetch the result from {url}: {e}");
+            None
+        },
+    }
 }
 
 
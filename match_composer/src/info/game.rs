@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
+use common::types::MatchResult;
 
 use crate::declaration::HostPort;
 use super::team::TeamInfo;
@@ -10,6 +11,9 @@ pub struct GameInfo {
     pub status: GameStatusInfo,
     pub team_l: TeamInfo,
     pub team_r: TeamInfo,
+    /// As reported by the server once the match is over.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<MatchResult>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
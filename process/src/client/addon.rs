@@ -30,3 +30,17 @@ pub trait CallerAddon<CMD: CommandAny>: Addon {
     where
         Self: Sized;
 }
+
+/// Both calls commands and sees every message received by the client, e.g. to
+/// switch on a server push and consume what it sends.
+pub trait ListenerAddon<CMD: CommandAny>: Addon {
+    type Handle: Sync + Send + 'static;
+    fn handle(&self) -> Self::Handle;
+
+    fn from_listener(
+        caller: super::resolver::Sender<CMD, TxData, RxData>,
+        data_rx: mpsc::Receiver<RxData>,
+    ) -> Self
+    where
+        Self: Sized;
+}
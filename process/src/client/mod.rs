@@ -1,10 +1,12 @@
 mod addon;
 mod error;
+mod params;
 mod resolver;
 mod rich_client;
 
-pub use addon::{Addon, CallerAddon, RawAddon};
+pub use addon::{Addon, CallerAddon, ListenerAddon, RawAddon};
 pub use error::{Error, Result};
+pub use params::ServerParams;
 pub use resolver::{CallResolver, Sender as CallSender, WeakSender as WeakCallSender};
 pub use rich_client::RichClient;
 pub use rich_client::RichClientBuilder;
@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use log::trace;
+use tokio::sync::{mpsc, watch};
+use tokio::task::JoinHandle;
+
+use common::client::RxData;
+use common::utils::sexp::Sexp;
+
+use super::addon::Addon;
+use crate::process::{PlayerConfig, PlayerType, ServerConfig};
+
+/// Parameters announced by rcssserver right after `init`, in its own field names.
+#[derive(Clone, Debug, Default)]
+pub struct ServerParams {
+    pub server: Option<ServerConfig>,
+    pub player: Option<PlayerConfig>,
+    pub player_types: Vec<PlayerType>,
+}
+
+impl ServerParams {
+    pub fn player_type(&self, id: i32) -> Option<&PlayerType> {
+        self.player_types.iter().find(|t| t.id == Some(id))
+    }
+
+    /// All of `server_param`, `player_param` and every announced `player_type` were received.
+    pub fn is_complete(&self) -> bool {
+        let Some(player) = &self.player else {
+            return false;
+        };
+        let expected = player.player_types.unwrap_or_default().max(0) as usize;
+        self.server.is_some() && self.player_types.len() >= expected
+    }
+
+    /// Fold a raw message in, returns `false` if it is not a param message.
+    pub fn ingest(&mut self, msg: &str) -> bool {
+        const HEADS: [&str; 3] = ["(server_param ", "(player_param ", "(player_type "];
+        if !HEADS.iter().any(|h| msg.starts_with(h)) {
+            return false;
+        }
+        let Ok(sexp) = Sexp::parse(msg) else {
+            return false;
+        };
+        let Some((head, params)) = sexp.list().and_then(|l| l.split_first()) else {
+            return false;
+        };
+
+        match head.atom() {
+            Some("server_param") => self.server = Some(ServerConfig::from_params(params)),
+            Some("player_param") => self.player = Some(PlayerConfig::from_params(params)),
+            Some("player_type") => {
+                let player_type = PlayerType::from_params(params);
+                self.player_types.retain(|t| t.id != player_type.id);
+                self.player_types.push(player_type);
+                self.player_types.sort_by_key(|t| t.id);
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParamsAddon {
+    task: JoinHandle<()>,
+}
+
+impl ParamsAddon {
+    pub(crate) fn new(mut rx: mpsc::Receiver<RxData>, params: Arc<watch::Sender<ServerParams>>) -> Self {
+        let task = tokio::spawn(async move {
+            while let Some(msg) = rx.recv().await {
+                if params.send_if_modified(|p| p.ingest(&msg)) {
+                    trace!("[ParamsAddon] Updated server params: {}", msg.split(' ').next().unwrap_or_default());
+                }
+            }
+        });
+
+        Self { task }
+    }
+}
+
+impl Addon for ParamsAddon {
+    fn close(&self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ingest() {
+        let mut params = ServerParams::default();
+        assert!(!params.ingest("(see 0 ((b) 1 2))"));
+
+        assert!(params.ingest("(server_param (goal_width 14.02) (synch_mode 1) (game_log_dir \"./log\") (unknown 3))"));
+        assert!(params.ingest("(player_param (player_types 2) (pt_max 1))"));
+        assert!(params.ingest("(player_type (id 1) (player_speed_max 1.05) (kick_rand 0.1))"));
+        assert!(!params.is_complete());
+        assert!(params.ingest("(player_type (id 0) (player_speed_max 1))"));
+        assert!(params.is_complete());
+
+        let server = params.server.as_ref().unwrap();
+        assert_eq!(server.goal_width, Some(14.02));
+        assert_eq!(server.synch_mode, Some(true));
+        assert_eq!(server.game_log_dir.as_deref(), Some("./log"));
+        assert_eq!(params.player_types[0].id, Some(0));
+        assert_eq!(params.player_type(1).unwrap().kick_rand, Some(0.1));
+    }
+}
@@ -2,6 +2,7 @@ use std::any::Any;
 use std::collections::VecDeque;
 use std::fmt::Debug;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Duration;
 
 use arcstr::ArcStr;
@@ -11,9 +12,11 @@ use tokio::sync::{mpsc, oneshot};
 use tokio::time::error::Elapsed;
 
 use common::client::{RxData, TxData, TxSignal};
+use common::command::coach::CoachCommand;
 use common::command::player::PlayerCommand;
 use common::command::trainer::TrainerCommand;
 use common::command::{Command, CommandAny};
+use common::utils::sexp::Sexp;
 
 use super::addon::{Addon, RawAddon};
 
@@ -39,12 +42,7 @@ impl CallResolver<PlayerCommand, RxData> {
 
 impl Receiver<PlayerCommand, RxData> {
     fn new(mut receiver: mpsc::Receiver<RxData>) -> Self {
-        let tasks: Arc<
-            DashMap<
-                PlayerCommand,
-                VecDeque<oneshot::Sender<Result<Box<dyn Any + Send>, Box<dyn Any + Send>>>>,
-            >,
-        > = Arc::new(DashMap::new());
+        let tasks: Arc<Pending<PlayerCommand>> = Arc::new(DashMap::new());
 
         let tasks_ = Arc::clone(&tasks);
         let recv_task = tokio::spawn(async move {
@@ -57,24 +55,15 @@ impl Receiver<PlayerCommand, RxData> {
                 }
 
 
-                let mut msg = msg[1..msg.len() - 1].split(' ');
-                let (kind, ret) = match msg.next() {
-                    Some("error") => {
-                        let mut ret = None;
-
-                        let rest: Vec<_> = msg.collect();
-
-                        let map_keys = tasks_.iter().map(|entry| *entry.key());
-
-                        for sig_kind in map_keys {
-                            if let Some(err) = sig_kind.parse_ret_err(&rest) {
-                                ret = Some((sig_kind, Err(err)));
-                                break;
-                            }
-                        }
+                let Ok(Sexp::List(msg)) = Sexp::parse(msg) else {
+                    debug!("[CallResolver] Ignore malformed msg: {raw_msg:?}");
+                    continue;
+                };
 
-                        match ret {
-                            Some(error) => error,
+                let (kind, ret) = match msg.split_first() {
+                    Some((Sexp::Atom("error"), rest)) => {
+                        match oldest_error(&tasks_, rest) {
+                            Some((sig_kind, err)) => (sig_kind, Err(err)),
                             None => {
                                 debug!(
                                     "[CallResolver] Ignore \"error\" for unknown Sig: {raw_msg:?}"
@@ -83,18 +72,20 @@ impl Receiver<PlayerCommand, RxData> {
                             }
                         }
                     },
-                    Some(maybe_ok) => {
-                        let kind_str = if maybe_ok != "ok" {
-                            Some(maybe_ok)
+                    Some((Sexp::Atom(maybe_ok), rest)) => {
+                        let (kind_str, rest) = if *maybe_ok != "ok" {
+                            (Some(*maybe_ok), rest)
                         } else {
-                            msg.next()
+                            match rest.split_first() {
+                                Some((kind, rest)) => (kind.atom(), rest),
+                                None => (None, rest),
+                            }
                         };
 
                         if let Some(kind_str) = kind_str
                             && let Some(sig_kind) = PlayerCommand::decode(kind_str)
                         {
-                            let rest: Vec<_> = msg.collect();
-                            let ret = sig_kind.parse_ret_ok(&rest);
+                            let ret = sig_kind.parse_ret_ok(rest);
                             match ret {
                                 Some(ok) => (sig_kind, Ok(ok)),
                                 None => {
@@ -116,8 +107,7 @@ impl Receiver<PlayerCommand, RxData> {
                     }
                 };
 
-                if let Some(mut queue) = tasks_.get_mut(&kind)
-                    && let Some(tx) = queue.value_mut().pop_front()
+                if let Some(tx) = pop_pending(&tasks_, &kind)
                     && tx.send(ret).is_err()
                 {
                     debug!(
@@ -131,6 +121,7 @@ impl Receiver<PlayerCommand, RxData> {
         Self {
             recv_task,
             queue: tasks,
+            next_seq: AtomicU64::new(0),
             _phantom: Default::default(),
         }
     }
@@ -169,12 +160,7 @@ impl CallResolver<TrainerCommand, RxData> {
 
 impl Receiver<TrainerCommand, RxData> {
     fn new(mut receiver: mpsc::Receiver<RxData>) -> Self {
-        let tasks: Arc<
-            DashMap<
-                TrainerCommand,
-                VecDeque<oneshot::Sender<Result<Box<dyn Any + Send>, Box<dyn Any + Send>>>>,
-            >,
-        > = Arc::new(DashMap::new());
+        let tasks: Arc<Pending<TrainerCommand>> = Arc::new(DashMap::new());
 
         let tasks_ = Arc::clone(&tasks);
         let recv_task = tokio::spawn(async move {
@@ -188,14 +174,17 @@ impl Receiver<TrainerCommand, RxData> {
 
                 let msg = if msg == "(init ok)" { "(ok init)" } else { msg };
 
-                let mut msg = msg[1..msg.len() - 1].split(' ');
-                let (kind, ret) = match msg.next() {
-                    Some("ok") => {
-                        if let Some(kind_str) = msg.next()
-                            && let Some(sig_kind) = TrainerCommand::decode(kind_str)
+                let Ok(Sexp::List(msg)) = Sexp::parse(msg) else {
+                    debug!("[CallResolver] Ignore malformed msg: {raw_msg:?}");
+                    continue;
+                };
+
+                let (kind, ret) = match msg.split_first() {
+                    Some((Sexp::Atom("ok"), rest)) => {
+                        if let Some((kind_str, rest)) = rest.split_first()
+                            && let Some(sig_kind) = kind_str.atom().and_then(TrainerCommand::decode)
                         {
-                            let rest: Vec<_> = msg.collect();
-                            let ret = sig_kind.parse_ret_ok(&rest);
+                            let ret = sig_kind.parse_ret_ok(rest);
                             match ret {
                                 Some(ok) => (sig_kind, Ok(ok)),
                                 None => {
@@ -211,22 +200,124 @@ impl Receiver<TrainerCommand, RxData> {
                             continue;
                         }
                     }
-                    Some("error") => {
-                        let mut ret = None;
+                    Some((Sexp::Atom("error"), rest)) => {
+                        match oldest_error(&tasks_, rest) {
+                            Some((sig_kind, err)) => (sig_kind, Err(err)),
+                            None => {
+                                debug!(
+                                    "[CallResolver] Ignore \"error\" for unknown Sig: {raw_msg:?}"
+                                );
+                                continue;
+                            }
+                        }
+                    }
+                    _ => {
+                        debug!("[CallResolver] Ignore unknown msg: {raw_msg:?}");
+                        continue;
+                    }
+                };
 
-                        let rest: Vec<_> = msg.collect();
+                if let Some(tx) = pop_pending(&tasks_, &kind)
+                    && tx.send(ret).is_err()
+                {
+                    debug!(
+                        "[CallResolver] Failed to send return to caller for [{}]",
+                        kind.encode()
+                    );
+                }
+            }
+        });
 
-                        let map_keys = tasks_.iter().map(|entry| *entry.key());
+        Self {
+            recv_task,
+            queue: tasks,
+            next_seq: AtomicU64::new(0),
+            _phantom: Default::default(),
+        }
+    }
+}
 
-                        for sig_kind in map_keys {
-                            if let Some(err) = sig_kind.parse_ret_err(&rest) {
-                                ret = Some((sig_kind, Err(err)));
-                                break;
+impl RawAddon for CallResolver<TrainerCommand, RxData> {
+    fn from_raw(
+        _: mpsc::Sender<TxSignal>,
+        _: mpsc::Sender<TxData>,
+        data_rx: mpsc::Receiver<RxData>,
+    ) -> Self
+    where
+        Self: Sized,
+    {
+        Self::from_rx(data_rx)
+    }
+}
+
+impl CallResolver<CoachCommand, RxData> {
+    pub fn from_rx(receiver: mpsc::Receiver<RxData>) -> Self {
+        let rx = Arc::new(Receiver::<CoachCommand, RxData>::new(receiver));
+        Self {
+            rx,
+            rx_ingest: None,
+        }
+    }
+    pub fn new(buffer: usize) -> Self {
+        let (tx, rx) = mpsc::channel(buffer);
+        let rx = Arc::new(Receiver::<CoachCommand, RxData>::new(rx));
+        Self {
+            rx,
+            rx_ingest: Some(tx),
+        }
+    }
+}
+
+impl Receiver<CoachCommand, RxData> {
+    fn new(mut receiver: mpsc::Receiver<RxData>) -> Self {
+        let tasks: Arc<Pending<CoachCommand>> = Arc::new(DashMap::new());
+
+        let tasks_ = Arc::clone(&tasks);
+        let recv_task = tokio::spawn(async move {
+            while let Some(raw_msg) = receiver.recv().await {
+                let msg = raw_msg.trim().trim_end_matches(['\0', '\n', '\r']);
+                if msg.is_empty() || !msg.starts_with('(') || !msg.ends_with(')') {
+                    debug!("{:?}", msg.chars().take(msg.len() - 1));
+                    debug!("ignoring peer ret, not matching '(.+)': '{msg}'.");
+                    continue;
+                }
+
+                let Ok(Sexp::List(mut msg)) = Sexp::parse(msg) else {
+                    debug!("[CallResolver] Ignore malformed msg: {raw_msg:?}");
+                    continue;
+                };
+
+                // `(init SIDE ok)` => `(ok init SIDE)`, `(score ...)` => `(ok score ...)`
+                if let [Sexp::Atom("init"), side, Sexp::Atom("ok")] = &msg[..] {
+                    msg = vec![Sexp::Atom("ok"), Sexp::Atom("init"), side.clone()];
+                } else if msg.first().is_some_and(|h| h.is_atom("score")) {
+                    msg.insert(0, Sexp::Atom("ok"));
+                }
+
+                let (kind, ret) = match msg.split_first() {
+                    Some((Sexp::Atom("ok"), rest)) => {
+                        if let Some((kind_str, rest)) = rest.split_first()
+                            && let Some(sig_kind) = kind_str.atom().and_then(CoachCommand::decode)
+                        {
+                            let ret = sig_kind.parse_ret_ok(rest);
+                            match ret {
+                                Some(ok) => (sig_kind, Ok(ok)),
+                                None => {
+                                    debug!(
+                                        "[CallResolver] Ignore \"ok\" for [{}]: {raw_msg:?}",
+                                        sig_kind.encode()
+                                    );
+                                    continue;
+                                }
                             }
+                        } else {
+                            debug!("[CallResolver] Ignore \"ok\" for unknown Sig: {raw_msg:?}");
+                            continue;
                         }
-
-                        match ret {
-                            Some(error) => error,
+                    }
+                    Some((Sexp::Atom("error" | "warning"), rest)) => {
+                        match oldest_error(&tasks_, rest) {
+                            Some((sig_kind, err)) => (sig_kind, Err(err)),
                             None => {
                                 debug!(
                                     "[CallResolver] Ignore \"error\" for unknown Sig: {raw_msg:?}"
@@ -241,8 +332,7 @@ impl Receiver<TrainerCommand, RxData> {
                     }
                 };
 
-                if let Some(mut queue) = tasks_.get_mut(&kind)
-                    && let Some(tx) = queue.value_mut().pop_front()
+                if let Some(tx) = pop_pending(&tasks_, &kind)
                     && tx.send(ret).is_err()
                 {
                     debug!(
@@ -256,12 +346,13 @@ impl Receiver<TrainerCommand, RxData> {
         Self {
             recv_task,
             queue: tasks,
+            next_seq: AtomicU64::new(0),
             _phantom: Default::default(),
         }
     }
 }
 
-impl RawAddon for CallResolver<TrainerCommand, RxData> {
+impl RawAddon for CallResolver<CoachCommand, RxData> {
     fn from_raw(
         _: mpsc::Sender<TxSignal>,
         _: mpsc::Sender<TxData>,
@@ -274,6 +365,68 @@ impl RawAddon for CallResolver<TrainerCommand, RxData> {
     }
 }
 
+type ReplySender = oneshot::Sender<Result<Box<dyn Any + Send>, Box<dyn Any + Send>>>;
+
+/// Calls waiting for a reply, by command kind, in the order they were sent.
+type Pending<CMD> = DashMap<CMD, VecDeque<PendingCall>>;
+
+#[derive(Debug)]
+struct PendingCall {
+    /// Order of the call across all kinds.
+    seq: u64,
+    /// Sent as is for another client, takes any error and its reason is sent back as text.
+    relayed: bool,
+    tx: ReplySender,
+}
+
+/// Take the oldest call waiting on `kind`, dropping the queue once it is empty.
+fn pop_pending<CMD: CommandAny>(tasks: &Pending<CMD>, kind: &CMD) -> Option<ReplySender> {
+    let call = tasks.get_mut(kind)?.pop_front();
+    tasks.remove_if(kind, |_, queue| queue.is_empty());
+    call.map(|call| call.tx)
+}
+
+/// `(error ...)` does not name the command, e.g. many player commands fail with
+/// `illegal_command_form`, so it goes to the oldest pending call which accepts it.
+fn oldest_error<CMD: CommandAny>(tasks: &Pending<CMD>, tokens: &[Sexp]) -> Option<(CMD, Box<dyn Any + Send>)> {
+    let mut pending = tasks.iter()
+        .filter_map(|entry| {
+            let call = entry.value().front()?;
+            Some((call.seq, entry.key().clone(), call.relayed))
+        })
+        .collect::<Vec<_>>();
+    pending.sort_unstable_by_key(|(seq, _, _)| *seq);
+    pending.into_iter()
+        .find_map(|(_, kind, relayed)| match relayed {
+            true => {
+                let reason = tokens.iter().map(Sexp::to_string).collect::<Vec<_>>().join(" ");
+                Some((kind, Box::new(ArcStr::from(reason)) as Box<dyn Any + Send>))
+            },
+            false => kind.parse_ret_err(tokens).map(|err| (kind, err)),
+        })
+}
+
+/// Removes its call from the queue if the caller stops waiting, e.g. on timeout.
+struct PendingGuard<'a, CMD, RX>
+where
+    CMD: CommandAny,
+    RX: Debug + Send + Sync + 'static,
+{
+    resolver: &'a Receiver<CMD, RX>,
+    command: CMD,
+    seq: u64,
+}
+
+impl<CMD, RX> Drop for PendingGuard<'_, CMD, RX>
+where
+    CMD: CommandAny,
+    RX: Debug + Send + Sync + 'static,
+{
+    fn drop(&mut self) {
+        self.resolver.remove_queue(&self.command, self.seq);
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct CallResolver<CMD, RX>
 where
@@ -341,9 +494,8 @@ where
 {
     recv_task: tokio::task::JoinHandle<()>,
 
-    queue: Arc<
-        DashMap<CMD, VecDeque<oneshot::Sender<Result<Box<dyn Any + Send>, Box<dyn Any + Send>>>>>,
-    >,
+    queue: Arc<Pending<CMD>>,
+    next_seq: AtomicU64,
 
     _phantom: std::marker::PhantomData<RX>,
 }
@@ -353,12 +505,18 @@ where
     CMD: CommandAny,
     RX: Debug + Send + Sync + 'static,
 {
-    fn add_queue(
-        &self,
-        command: CMD,
-        tx: oneshot::Sender<Result<Box<dyn Any + Send>, Box<dyn Any + Send>>>,
-    ) {
-        self.queue.entry(command).or_default().push_back(tx)
+    fn add_queue(&self, command: CMD, relayed: bool, tx: ReplySender) -> PendingGuard<'_, CMD, RX> {
+        let seq = self.next_seq.fetch_add(1, Ordering::Relaxed);
+        self.queue.entry(command.clone()).or_default().push_back(PendingCall { seq, relayed, tx });
+        PendingGuard { resolver: self, command, seq }
+    }
+
+    /// Forget a call which is no longer waited for, so that it does not take a later reply.
+    fn remove_queue(&self, command: &CMD, seq: u64) {
+        if let Some(mut queue) = self.queue.get_mut(command) {
+            queue.retain(|call| call.seq != seq);
+        }
+        self.queue.remove_if(command, |_, queue| queue.is_empty());
     }
 
     pub fn close(&self) {
@@ -388,13 +546,12 @@ where
     }
     
     async fn send<T: Command<Kind = CMD>>(&self, sig: T) -> super::Result<Result<T::Ok, T::Error>> {
-        let sig_kind = sig.kind();
-        let sender = &self.tx;
-        sender.send(sig.encode().into()).await
+        // queued first, the reply may come back before `send` returns
+        let (tx, rx) = oneshot::channel();
+        let _pending = self.resolver.add_queue(sig.kind(), false, tx);
+        self.tx.send(sig.encode().into()).await
             .map_err(|_| super::Error::CommandSendFailed)?;
 
-        let (tx, rx) = oneshot::channel();
-        self.resolver.add_queue(sig_kind, tx);
         match rx.await.map_err(|_| super::Error::CommandReceiveFailed)? {
             Ok(ok) => {
                 let ok = *ok.downcast::<T::Ok>()
@@ -409,6 +566,10 @@ where
         }
     }
 
+    /// Send `sig` and wait for its reply, up to [`TIMEOUT`].
+    ///
+    /// rcssserver answers most player commands, e.g. `dash`, `turn` or `kick`, only when they
+    /// fail, so a call on those ends at the timeout; send them with `RichClient::send_cmd`.
     pub async fn call<T: Command<Kind = CMD>>(
         &self,
         sig: T,
@@ -420,6 +581,31 @@ where
         Ok(res)
     }
 
+    /// Send `data`, a `kind` command composed by another client, and wait for its reply,
+    /// up to [`TIMEOUT`].
+    ///
+    /// The call holds its place among ours, so its reply cannot resolve one of them.
+    /// An error reply comes back as its reason, e.g. `illegal_object_form`.
+    pub async fn call_raw(&self, kind: CMD, data: ArcStr) -> super::Result<Result<(), ArcStr>> {
+        let send = async {
+            let (tx, rx) = oneshot::channel();
+            let _pending = self.resolver.add_queue(kind.clone(), true, tx);
+            self.tx.send(data.into()).await
+                .map_err(|_| super::Error::CommandSendFailed)?;
+
+            match rx.await.map_err(|_| super::Error::CommandReceiveFailed)? {
+                Ok(_) => Ok(Ok(())),
+                Err(reason) => {
+                    let reason = *reason.downcast::<ArcStr>()
+                        .map_err(|_| super::Error::CommandResponseTypeMismatch)?;
+                    Ok(Err(reason))
+                }
+            }
+        };
+        tokio::time::timeout(TIMEOUT, send)
+            .await.map_err(|_| super::Error::CallElapsed { kind: kind.encode() }).flatten()
+    }
+
     pub fn downgrade(&self) -> WeakSender<CMD, TX, RX> {
         let tx = self.tx.downgrade();
         WeakSender::new(tx, Arc::clone(&self.resolver))
@@ -448,13 +634,12 @@ where
     }
 
     pub async fn send<T: Command<Kind = CMD>>(&self, sig: T) -> Result<Result<T::Ok, T::Error>, super::Error> {
-        let sig_kind = sig.kind();
         let sender = self.tx.upgrade().ok_or(super::Error::SenderClosed)?;
+        let (tx, rx) = oneshot::channel();
+        let _pending = self.resolver.add_queue(sig.kind(), false, tx);
         sender.send(sig.encode().into()).await
             .map_err(|_| super::Error::CommandSendFailed)?;
 
-        let (tx, rx) = oneshot::channel();
-        self.resolver.add_queue(sig_kind, tx);
         match rx.await.map_err(|_| super::Error::CommandReceiveFailed)? {
             Ok(ok) => {
                 let ok = *ok.downcast::<T::Ok>()
@@ -474,3 +659,86 @@ where
         Some(Sender::new(tx, self.resolver))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common::command::player::{CommandDash, CommandKick, CommandTurn};
+    use common::command::player::dash::CommandDashError;
+    use common::command::player::turn::CommandTurnError;
+    use common::command::trainer::ChangeMode;
+    use common::types::PlayMode;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_error_goes_to_oldest_call() {
+        let resolver = CallResolver::<PlayerCommand, RxData>::new(8);
+        let ingest = resolver.ingest_tx().unwrap();
+        let (data_tx, mut data_rx) = mpsc::channel::<TxData>(8);
+        let sender = resolver.sender(data_tx);
+
+        let turn = tokio::spawn({
+            let sender = sender.clone();
+            async move { sender.call(CommandTurn { moment: 500.0 }).await }
+        });
+        data_rx.recv().await.unwrap();
+        let dash = tokio::spawn({
+            let sender = sender.clone();
+            async move { sender.call(CommandDash { power: 500.0, dir: None }).await }
+        });
+        data_rx.recv().await.unwrap();
+
+        ingest.send("(error illegal_command_form)".into()).await.unwrap();
+        assert!(matches!(turn.await.unwrap(), Ok(Err(CommandTurnError::IllegalCommandForm))));
+        ingest.send("(error illegal_command_form)".into()).await.unwrap();
+        assert!(matches!(dash.await.unwrap(), Ok(Err(CommandDashError::IllegalCommandForm))));
+        assert!(resolver.rx.queue.is_empty());
+
+        // a call given up on leaves nothing behind to take later errors
+        let kick = sender.call(CommandKick { power: 10.0, dir: 0.0 });
+        assert!(tokio::time::timeout(Duration::from_millis(10), kick).await.is_err());
+        assert!(resolver.rx.queue.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_relayed_replies_stay_apart() {
+        let resolver = CallResolver::<TrainerCommand, RxData>::new(8);
+        let ingest = resolver.ingest_tx().unwrap();
+        let (data_tx, mut data_rx) = mpsc::channel::<TxData>(8);
+        let sender = resolver.sender(data_tx);
+
+        let relayed = tokio::spawn({
+            let sender = sender.clone();
+            async move { sender.call_raw(TrainerCommand::ChangeMode, "(change_mode nonsense)".into()).await }
+        });
+        assert_eq!(data_rx.recv().await.unwrap(), "(change_mode nonsense)");
+        let ours = tokio::spawn({
+            let sender = sender.clone();
+            async move { sender.call(ChangeMode { play_mode: PlayMode::PM_PlayOn }).await }
+        });
+        data_rx.recv().await.unwrap();
+
+        // the relayed command's error is its own, the service's call keeps waiting
+        ingest.send("(error illegal_mode)".into()).await.unwrap();
+        assert_eq!(relayed.await.unwrap().unwrap(), Err(ArcStr::from("illegal_mode")));
+        assert!(!ours.is_finished());
+        ingest.send("(ok change_mode)".into()).await.unwrap();
+        assert!(matches!(ours.await.unwrap(), Ok(Ok(()))));
+
+        // and a relayed command sent first takes the first reply of its kind
+        let relayed = tokio::spawn({
+            let sender = sender.clone();
+            async move { sender.call_raw(TrainerCommand::ChangeMode, "(change_mode play_on)".into()).await }
+        });
+        data_rx.recv().await.unwrap();
+        let ours = tokio::spawn({
+            let sender = sender.clone();
+            async move { sender.call(ChangeMode { play_mode: PlayMode::PM_PlayOn }).await }
+        });
+        data_rx.recv().await.unwrap();
+        ingest.send("(ok change_mode)".into()).await.unwrap();
+        assert_eq!(relayed.await.unwrap().unwrap(), Ok(()));
+        ingest.send("(ok change_mode)".into()).await.unwrap();
+        assert!(matches!(ours.await.unwrap(), Ok(Ok(()))));
+        assert!(resolver.rx.queue.is_empty());
+    }
+}
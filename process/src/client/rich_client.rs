@@ -1,13 +1,16 @@
 use std::net::SocketAddr;
+use std::sync::Arc;
 
+use arcstr::ArcStr;
 use dashmap::DashMap;
 use log::trace;
-use tokio::sync::{OnceCell, mpsc};
+use tokio::sync::{OnceCell, mpsc, watch};
 use tokio::time::error::Elapsed;
 use uuid::Uuid;
 
-use super::addon::{Addon, CallerAddon, RawAddon};
-use super::{CallResolver, CallSender, Error, Result};
+use super::addon::{Addon, CallerAddon, ListenerAddon, RawAddon};
+use super::params::ParamsAddon;
+use super::{CallResolver, CallSender, Error, Result, ServerParams};
 use common::client;
 use common::client::{RxData, TxData};
 use common::command::{Command, CommandAny, CommandResult};
@@ -16,6 +19,7 @@ pub type RichClientCaller<CMD> = CallSender<CMD, TxData, RxData>;
 
 pub const DEFAULT_LOCAL_PLAYER_PORT: u16 = 6000;
 pub const DEFAULT_LOCAL_TRAINER_PORT: u16 = 6001;
+pub const DEFAULT_LOCAL_COACH_PORT: u16 = 6002;
 
 #[derive(Clone, Debug)]
 pub struct RichClientBuilder {
@@ -49,6 +53,16 @@ impl RichClientBuilder {
         Self { conn_builder }
     }
 
+    pub fn coach() -> Self {
+        let mut conn_builder = client::Builder::new();
+        conn_builder
+            .with_kind(client::Kind::OlCoach)
+            .with_name("Default Coach".to_string())
+            .with_local_peer(DEFAULT_LOCAL_COACH_PORT);
+
+        Self { conn_builder }
+    }
+
     pub fn with_kind(&mut self, kind: client::Kind) -> &mut Self {
         self.conn_builder.with_kind(kind);
         self
@@ -92,6 +106,7 @@ where
     pub(crate) conn: client::Client,
     pub(crate) resolver_tx: OnceCell<CallSender<CMD, TxData, RxData>>,
     pub(crate) addons: DashMap<&'static str, Box<dyn Addon>>,
+    pub(crate) params: Arc<watch::Sender<ServerParams>>,
 }
 
 impl<CMD, const BUF_SIZE: usize> RichClient<CMD, BUF_SIZE>
@@ -117,6 +132,7 @@ where
             conn,
             resolver_tx: OnceCell::new(),
             addons: DashMap::new(),
+            params: Arc::new(watch::Sender::new(ServerParams::default())),
         }
     }
 
@@ -149,7 +165,26 @@ where
         handle
     }
 
+    #[must_use]
+    pub fn add_listener_addon<A: ListenerAddon<CMD>>(&self, name: &'static str) -> A::Handle {
+        trace!("[RichClient] Adding listener addon '{name}'");
+        let (tx, rx) = mpsc::channel(BUF_SIZE);
+        let id = self.conn.subscribe(tx);
+        let addon = A::from_listener(self.caller(), rx);
+        let handle = addon.handle();
+
+        self.addons.insert(name, Box::new(addon));
+        trace!("[RichClient] Addon '{name}' added, id = {id}");
+
+        handle
+    }
+
     pub(crate) async fn conn_connect(&self) -> Result<()> {
+        if !self.addons.contains_key("params") {
+            let (tx, rx) = mpsc::channel(BUF_SIZE);
+            self.conn.subscribe(tx);
+            self.addons.insert("params", Box::new(ParamsAddon::new(rx, Arc::clone(&self.params))));
+        }
         self.conn.connect().await
             .map_err(|e| Error::ClientClosed { source: e })?;
         Ok(())
@@ -162,6 +197,8 @@ where
             .clone()
     }
 
+    /// Send `cmd` and wait for its reply, `dash`, `kick` and the like only get one when they
+    /// fail and go through [`send_cmd`](Self::send_cmd) instead.
     pub async fn call<T: Command<Kind = CMD>>(
         &self,
         cmd: T,
@@ -173,6 +210,15 @@ where
             .await
     }
 
+    /// Parameters received from the server since connecting, see [`ServerParams::is_complete`].
+    pub fn server_params(&self) -> watch::Ref<'_, ServerParams> {
+        self.params.borrow()
+    }
+
+    pub fn watch_server_params(&self) -> watch::Receiver<ServerParams> {
+        self.params.subscribe()
+    }
+
     pub fn subscribe(&self, ingest_tx: mpsc::Sender<RxData>) -> Uuid {
         self.conn.subscribe(ingest_tx)
     }
@@ -181,14 +227,25 @@ where
         self.conn.unsubscribe(id)
     }
 
-    async fn send_cmd(&self, ctrl: impl Command) -> Result<()> {
+    /// Send a command without waiting for its reply, e.g. for `dash`/`kick` which never answer.
+    pub async fn send_cmd<T: Command<Kind = CMD>>(&self, cmd: T) -> Result<()> {
         self.conn
-            .send_data(ctrl.encode())
+            .send_data(cmd.encode())
             .await
             .map_err(|e| Error::ClientClosed { source: e })?;
         Ok(())
     }
 
+    /// Send a `kind` message as is and wait for its reply, e.g. relayed from a client the
+    /// server does not know about, see [`CallSender::call_raw`].
+    pub async fn call_raw(&self, kind: CMD, data: TxData) -> Result<std::result::Result<(), ArcStr>> {
+        self.resolver_tx
+            .get()
+            .expect("CallResolver not initialized - call init_resolver() first")
+            .call_raw(kind, data)
+            .await
+    }
+
     pub async fn shutdown(&mut self) -> Result<()> {
         self.conn
             .close()
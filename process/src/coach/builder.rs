@@ -0,0 +1,39 @@
+use crate::client::RichClientBuilder;
+use super::OnlineCoach;
+use std::ops::{Deref, DerefMut};
+
+#[derive(Clone, Debug)]
+pub struct OnlineCoachBuilder {
+    pub builder: RichClientBuilder,
+}
+
+impl Default for OnlineCoachBuilder {
+    fn default() -> Self {
+        let builder = RichClientBuilder::coach();
+        Self { builder }
+    }
+}
+
+impl OnlineCoachBuilder {
+    pub fn build(&self) -> OnlineCoach {
+        OnlineCoach::from_client_config(self.builder.conn_builder.build())
+    }
+
+    pub fn build_into(self) -> OnlineCoach {
+        OnlineCoach::from_client_config(self.builder.conn_builder.build_into())
+    }
+}
+
+impl Deref for OnlineCoachBuilder {
+    type Target = RichClientBuilder;
+
+    fn deref(&self) -> &Self::Target {
+        &self.builder
+    }
+}
+
+impl DerefMut for OnlineCoachBuilder {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.builder
+    }
+}
@@ -0,0 +1,5 @@
+mod builder;
+mod online_coach;
+
+pub use builder::OnlineCoachBuilder as Builder;
+pub use online_coach::OnlineCoach;
@@ -0,0 +1,118 @@
+use super::Builder;
+use crate::client::{CallResolver, CallSender, Result, RichClient};
+use common::client::{RxData, TxData};
+use common::command::coach::{self, CoachCommand};
+use common::command::CommandAny;
+use common::client;
+use log::{debug, error, trace};
+use arcstr::ArcStr;
+use std::ops::{Deref, DerefMut};
+
+#[derive(Debug)]
+pub struct OnlineCoach {
+    client: RichClient<CoachCommand>,
+}
+
+impl OnlineCoach {
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
+    pub fn client(&self) -> &RichClient<CoachCommand> {
+        &self.client
+    }
+
+    pub fn from_client_config(config: client::Config) -> Self {
+        assert_eq!(
+            config.kind,
+            client::Kind::OlCoach,
+            "ClientKind::OlCoach expected"
+        );
+        let client = RichClient::from_client_config(config);
+
+        Self { client }
+    }
+
+    pub(super) fn init_resolver(&self) -> Result<()> {
+        trace!("[OnlineCoach] Initializing CallResolver addon.");
+        let resolver = CallResolver::<CoachCommand, RxData>::new(32);
+        self.resolver_tx
+            .set(resolver.sender(self.conn.data_sender()))
+            .map_err(|_| crate::client::Error::ResolverNotSingleton)?;
+        let id = self.subscribe(
+            resolver.ingest_tx()
+                .ok_or(crate::client::Error::ResolverNotSingleton)?
+        );
+        trace!("[OnlineCoach] CallResolver addon initialized, id = {id}");
+        self.addons.insert("call_resolver", Box::new(resolver));
+
+        Ok(())
+    }
+
+    pub async fn connect(&self) -> Result<()> {
+        trace!(
+            "[OnlineCoach] Connecting to host {:?} via peer {:?}",
+            self.config().host,
+            self.config().peer
+        );
+        self.conn_connect().await?;
+        debug!("[OnlineCoach] Connected.");
+        self.init_resolver()?;
+        debug!("[OnlineCoach] CallResolver initialized.");
+        Ok(())
+    }
+
+    /// Connect and bind to `team_name`, which must already have a player connected.
+    pub async fn connect_and_init(&self, team_name: String) -> Result<coach::init::CommandInitOk> {
+        self.connect().await?;
+
+        let init = coach::Init { team_name, ..Default::default() };
+        match self.call(init).await? {
+            Ok(ok) => {
+                trace!("[OnlineCoach] Init command succeeded returned with {ok:?}.");
+                Ok(ok)
+            },
+            Err(e) => {
+                error!("[OnlineCoach] Init command returned with error: {}", e);
+                Err(crate::client::Error::RcssErrorCall {
+                    kind: CoachCommand::Init.encode(),
+                    msg: ArcStr::from(e.to_string())
+                })
+            },
+        }
+    }
+
+    /// Substitute `unum` with the heterogeneous `player_type`.
+    pub async fn change_player_type(&self, unum: u8, player_type: u8) -> Result<()> {
+        let cmd = coach::ChangePlayerType { unum, player_type };
+        match self.call(cmd).await? {
+            Ok(_) => Ok(()),
+            Err(e) => Err(crate::client::Error::RcssErrorCall {
+                kind: CoachCommand::ChangePlayerType.encode(),
+                msg: ArcStr::from(e.to_string())
+            }),
+        }
+    }
+
+    pub fn command_sender(&self) -> CallSender<CoachCommand, TxData, RxData> {
+        self.caller()
+    }
+
+    pub async fn shutdown(&mut self) -> Result<()> {
+        self.client.shutdown().await
+    }
+}
+
+impl Deref for OnlineCoach {
+    type Target = RichClient<CoachCommand>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.client
+    }
+}
+
+impl DerefMut for OnlineCoach {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.client
+    }
+}
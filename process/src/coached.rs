@@ -7,11 +7,9 @@ use common::command::trainer::TrainerCommand;
 
 use crate::{Error, Result};
 use crate::client::CommandCaller;
-use crate::process::{self, ServerProcess, ServerProcessSpawner};
+use crate::process::{self, RcssBinary, ServerProcess, ServerProcessSpawner};
 use crate::trainer::{self, OfflineCoach};
 
-use crate::RCSS_PROCESS_NAME;
-
 #[derive(Clone, Debug)]
 pub struct CoachedProcessSpawner<const OUT: usize = 32, const ERR: usize = 32> {
     pub coach: trainer::Builder,
@@ -19,11 +17,15 @@ pub struct CoachedProcessSpawner<const OUT: usize = 32, const ERR: usize = 32> {
 }
 
 impl CoachedProcessSpawner {
-    pub async fn new() -> Self {
-        CoachedProcessSpawner {
+    pub async fn new() -> Result<Self> {
+        Self::from_binary(RcssBinary::default()).await
+    }
+
+    pub async fn from_binary(binary: RcssBinary) -> Result<Self> {
+        Ok(CoachedProcessSpawner {
             coach: OfflineCoach::builder(),
-            process: ServerProcess::spawner(RCSS_PROCESS_NAME).await,
-        }
+            process: ServerProcess::spawner(binary).await.map_err(Error::Binary)?,
+        })
     }
 
     pub fn with_ports(&mut self, port: u16, coach_port: u16, olcoach_port: u16) -> &mut Self {
@@ -41,7 +43,12 @@ impl CoachedProcessSpawner {
         self
     }
     
-    pub fn with_log_dir(&mut self, log_dir: &'static str) -> &mut Self {
+    pub fn with_referee(&mut self, enabled: bool) -> &mut Self {
+        self.process_config_mut().with_referee(enabled);
+        self
+    }
+
+    pub fn with_log_dir(&mut self, log_dir: impl Into<String>) -> &mut Self {
         self.process_config_mut().with_log_dir(log_dir);
         self
     }
@@ -103,7 +110,7 @@ pub struct CoachedProcess {
 }
 
 impl CoachedProcess {
-    pub async fn spawner() -> CoachedProcessSpawner {
+    pub async fn spawner() -> Result<CoachedProcessSpawner> {
         CoachedProcessSpawner::new().await
     }
 
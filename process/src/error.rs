@@ -2,6 +2,8 @@
 pub enum Error {
     #[error("[Coach] Failed to connect, {0:?}")]
     ConnectCoach(crate::client::Error),
+    #[error("[Process] rcssserver unavailable, {0}")]
+    Binary(crate::process::Error),
     #[error("[Process] Failed to spawn, {0}")]
     SpawnProcess(crate::process::Error),
     #[error("[Coach] Failed to shutdown, {0}")]
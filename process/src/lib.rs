@@ -1,4 +1,5 @@
 mod client;
+mod coach;
 mod coached;
 mod process;
 mod test;
@@ -7,19 +8,28 @@ mod player;
 mod error;
 
 pub mod addon {
-    pub use super::client::{Addon, CallerAddon, RawAddon};
+    pub use super::client::{Addon, CallerAddon, ListenerAddon, RawAddon};
+}
+
+pub mod config {
+    pub use crate::process::{
+        ConfigError, ConfigFormat, ConfigValue, CsvSaverConfig, ParamValue, PlayerConfig, PlayerType, ServerConfig,
+    };
 }
 
 pub mod resolver {
     pub use crate::client::{CallResolver, CallSender, WeakCallSender};
 }
 
-pub use client::CommandCaller;
+pub use client::{CommandCaller, ServerParams};
 pub use coached::{CoachedProcess, CoachedProcessSpawner};
 pub use process::Config as ProcessConfig;
+pub use process::{RcssBinary, Version as RcssVersion};
 pub use common::process::ProcessStatus;
 pub use error::{Result, Error};
 
 pub use player::{Player};
+pub use coach::OnlineCoach;
+pub use trainer::{StepOutcome, PROTOCOL_VERSION as TRAINER_PROTOCOL_VERSION};
 
 pub const RCSS_PROCESS_NAME: &str = "rcssserver";
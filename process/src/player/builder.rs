@@ -1,4 +1,3 @@
-use log::debug;
 use common::command::player::CommandInit;
 use crate::client::RichClientBuilder;
 use super::Player;
@@ -50,6 +49,7 @@ impl PlayerBuilder {
         let client = self.rich_client.build_into();
         let player = Player {
             client,
+            enable_resolver: self.enable_resolver,
         };
         if !self.enable_resolver {
             if self.init_on_build.is_some() {
@@ -57,10 +57,8 @@ impl PlayerBuilder {
             }
             return Ok(player)
         }
-        
-        player.init_resolver().map_err(|err| err.to_string())?;
-        debug!("[Player] CallResolver initialized.");
-        
+
+        // otherwise the caller connects, which sets up the resolver
         if let Some(init_msg) = self.init_on_build {
             player.connect().await.map_err(|err| err.to_string())?;
             let res = player.call(init_msg).await.map_err(|err| err.to_string())?;
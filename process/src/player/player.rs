@@ -7,6 +7,8 @@ use super::PlayerBuilder;
 
 pub struct Player {
     pub client: RichClient<PlayerCommand>,
+    /// The resolver sends through the connection, so it is set up by `connect`.
+    pub(super) enable_resolver: bool,
 }
 
 impl Deref for Player {
@@ -52,6 +54,10 @@ impl Player {
         );
         self.conn_connect().await?;
         debug!("[Player] Connected.");
+        if self.enable_resolver {
+            self.init_resolver()?;
+            debug!("[Player] CallResolver initialized.");
+        }
         Ok(())
     }
 }
\ No newline at end of file
@@ -0,0 +1,237 @@
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::str::FromStr;
+use std::time::Duration;
+
+use log::{debug, trace};
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::process::Command;
+
+use super::config::{Config, PlayerConfig, ServerConfig};
+use super::error::{Error, Result};
+
+/// How long `--version` may take, older releases print the banner and keep running.
+pub const VERSION_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    pub major: u16,
+    pub minor: u16,
+    pub patch: u16,
+}
+
+impl Version {
+    /// The oldest release whose parameters [`ServerConfig`] and the trainer rely on.
+    pub const MIN_SUPPORTED: Version = Version::new(16, 0, 0);
+
+    pub const fn new(major: u16, minor: u16, patch: u16) -> Self {
+        Self { major, minor, patch }
+    }
+
+    /// Find the version in a `--version` or banner line, e.g. `rcssserver-18.1.3`.
+    pub fn find_in(line: &str) -> Option<Self> {
+        if !line.to_ascii_lowercase().contains("rcssserver") {
+            return None;
+        }
+        line.split(|c: char| !c.is_ascii_digit() && c != '.')
+            .find_map(|s| s.parse().ok())
+    }
+}
+
+impl FromStr for Version {
+    type Err = ();
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let mut parts = s.trim_matches('.').split('.').map(|p| p.parse::<u16>());
+        let major = parts.next().ok_or(())?.map_err(|_| ())?;
+        let minor = parts.next().ok_or(())?.map_err(|_| ())?;
+        let patch = parts.next().transpose().map_err(|_| ())?.unwrap_or(0);
+        if parts.next().is_some() {
+            return Err(());
+        }
+        Ok(Self::new(major, minor, patch))
+    }
+}
+
+impl Display for Version {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Parameters newer than [`Version::MIN_SUPPORTED`], with the release that introduced them.
+/// Older releases abort on unknown parameters or ignore them, depending on the build.
+const PARAMS_SINCE: &[(&str, &str, Version)] = &[
+    (ServerConfig::NAMESPACE, "dist_noise_rate", Version::new(18, 0, 0)),
+    (ServerConfig::NAMESPACE, "focus_dist_noise_rate", Version::new(18, 0, 0)),
+    (ServerConfig::NAMESPACE, "land_dist_noise_rate", Version::new(18, 0, 0)),
+    (ServerConfig::NAMESPACE, "land_focus_dist_noise_rate", Version::new(18, 0, 0)),
+];
+
+/// The rcssserver executable and the wrapper it is launched through.
+#[derive(Clone, Debug)]
+pub struct RcssBinary {
+    /// A name looked up in `PATH`, or a path to the executable.
+    pub program: PathBuf,
+    /// Prepended to the command line, e.g. `stdbuf -oL -eL` to get line-buffered logs.
+    pub wrapper: Vec<String>,
+}
+
+impl Default for RcssBinary {
+    fn default() -> Self {
+        Self {
+            program: PathBuf::from(crate::RCSS_PROCESS_NAME),
+            wrapper: ["stdbuf", "-oL", "-eL"].map(String::from).to_vec(),
+        }
+    }
+}
+
+impl RcssBinary {
+    pub fn new(program: impl Into<PathBuf>) -> Self {
+        Self { program: program.into(), ..Self::default() }
+    }
+
+    pub fn with_wrapper(mut self, wrapper: Vec<String>) -> Self {
+        self.wrapper = wrapper;
+        self
+    }
+
+    /// Locate the executable and the wrapper, so a missing one fails before the first spawn.
+    pub fn resolve(&self) -> Result<PathBuf> {
+        if let Some(wrapper) = self.wrapper.first() {
+            find_executable(Path::new(wrapper))?;
+        }
+        find_executable(&self.program)
+    }
+
+    /// Run `PROGRAM --version` and read the version from its output.
+    pub async fn probe_version(program: &Path) -> Result<Version> {
+        let mut child = Command::new(program)
+            .arg("--version")
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()?;
+
+        let mut stdout = BufReader::new(child.stdout.take().unwrap()).lines();
+        let mut stderr = BufReader::new(child.stderr.take().unwrap()).lines();
+        let mut output = vec![];
+
+        let probe = async {
+            loop {
+                let line = tokio::select! {
+                    Ok(Some(line)) = stdout.next_line() => line,
+                    Ok(Some(line)) = stderr.next_line() => line,
+                    else => return None,
+                };
+                trace!("RcssBinary::probe_version: '{line}'");
+                if let Some(version) = Version::find_in(&line) {
+                    return Some(version);
+                }
+                output.push(line);
+            }
+        };
+        let version = tokio::time::timeout(VERSION_PROBE_TIMEOUT, probe).await.ok().flatten();
+        child.kill().await.ok();
+
+        let version = version.ok_or_else(|| Error::VersionUndetected {
+            program: program.display().to_string(),
+            output: output.join("\n"),
+        })?;
+        debug!("RcssBinary::probe_version: {} is rcssserver {version}", program.display());
+
+        if version < Version::MIN_SUPPORTED {
+            return Err(Error::UnsupportedVersion { found: version, min: Version::MIN_SUPPORTED });
+        }
+        Ok(version)
+    }
+
+    pub(crate) fn command(&self, program: &Path) -> Command {
+        match self.wrapper.split_first() {
+            Some((wrapper, args)) => {
+                let mut cmd = Command::new(wrapper);
+                cmd.args(args).arg(program);
+                cmd
+            },
+            None => Command::new(program),
+        }
+    }
+}
+
+/// Reject the set parameters the given rcssserver release does not know about.
+pub fn check_compatibility(config: &Config, version: Version) -> Result<()> {
+    for (namespace, name, since) in PARAMS_SINCE {
+        let set = match *namespace {
+            ServerConfig::NAMESPACE => config.server.get_param(name).is_some(),
+            PlayerConfig::NAMESPACE => config.player.get_param(name).is_some(),
+            _ => false,
+        };
+        if set && version < *since {
+            return Err(Error::UnsupportedParam {
+                name: format!("{namespace}::{name}"),
+                since: *since,
+                found: version,
+            });
+        }
+    }
+    Ok(())
+}
+
+fn find_executable(program: &Path) -> Result<PathBuf> {
+    let not_found = || Error::BinaryNotFound { program: program.display().to_string() };
+
+    if program.components().count() > 1 {
+        return program.is_file().then(|| program.to_path_buf()).ok_or_else(not_found);
+    }
+
+    let paths = std::env::var_os("PATH").ok_or_else(not_found)?;
+    std::env::split_paths(&paths)
+        .map(|dir| dir.join(program))
+        .find(|candidate| candidate.is_file())
+        .ok_or_else(not_found)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_version() {
+        assert_eq!(Version::find_in("rcssserver-19.0.0"), Some(Version::new(19, 0, 0)));
+        assert_eq!(Version::find_in("rcssserver Version 18.1"), Some(Version::new(18, 1, 0)));
+        assert_eq!(Version::find_in("Copyright 2000 - 2024 RoboCup Soccer Simulator"), None);
+        assert!(Version::new(17, 0, 1) < Version::new(18, 0, 0));
+    }
+
+    #[test]
+    fn test_check_compatibility() {
+        let mut config = Config::default_trainer_on();
+        assert!(check_compatibility(&config, Version::new(17, 0, 0)).is_ok());
+
+        config.server_then(|c| { c.dist_noise_rate(0.0125); });
+        assert!(check_compatibility(&config, Version::new(18, 0, 0)).is_ok());
+        assert!(matches!(
+            check_compatibility(&config, Version::new(17, 0, 1)),
+            Err(Error::UnsupportedParam { since: Version { major: 18, .. }, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_and_probe() {
+        let missing = RcssBinary::new("/nonexistent/rcssserver");
+        assert!(matches!(missing.resolve(), Err(Error::BinaryNotFound { .. })));
+
+        let script = std::env::temp_dir().join(format!("rcssserver-probe-{}", std::process::id()));
+        std::fs::write(&script, "#!/bin/sh\necho 'rcssserver-18.1.3'\n").unwrap();
+        std::process::Command::new("chmod").arg("+x").arg(&script).status().unwrap();
+
+        let binary = RcssBinary::new(&script).with_wrapper(vec![]);
+        let program = binary.resolve().unwrap();
+        let version = RcssBinary::probe_version(&program).await.unwrap();
+        assert_eq!(version, Version::new(18, 1, 3));
+
+        std::fs::remove_file(&script).ok();
+    }
+}
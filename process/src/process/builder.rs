@@ -1,5 +1,6 @@
-use log::{error, trace};
+use log::info;
 use std::io;
+use std::path::{Path, PathBuf};
 use std::process::Stdio;
 use tokio::process::Command;
 
@@ -7,61 +8,51 @@ use super::*;
 
 #[derive(Clone, Debug)]
 pub struct ServerProcessSpawner {
-    pgm_name: &'static str,
+    binary: RcssBinary,
+    program: PathBuf,
+    version: Version,
     pub config: Config,
 }
 
 impl ServerProcessSpawner {
-    pub(super) async fn new(pgm_name: &'static str) -> Self {
-        Self::validate(pgm_name).await;
-        Self {
-            pgm_name,
+    pub(super) async fn new(binary: RcssBinary) -> Result<Self> {
+        let program = binary.resolve()?;
+        let version = RcssBinary::probe_version(&program).await?;
+        info!("RcssServer: using {} (rcssserver {version})", program.display());
+
+        Ok(Self {
+            binary,
+            program,
+            version,
             config: Config::default_trainer_on(),
-        }
+        })
     }
 
-    async fn validate(pgm_name: &'static str) {
-        // let is_exist = {
-        //     let mut validate_cmd = Command::new("whereis");
-        //     validate_cmd.arg(pgm_name);
-        //     let out = validate_cmd.output().await
-        //         .expect("error calling whereis when creating RcssServer Process");
-        //
-        //     let out = String::from_utf8_lossy(&out.stdout);
-        //     trace!("RcssServer::validate: whereis returned: {out}");
-        //     !out.ends_with(":")
-        // };
+    pub fn version(&self) -> Version {
+        self.version
+    }
 
-        let is_exist = {
-            let mut validate_cmd = Command::new("which");
-            validate_cmd.arg(pgm_name);
-            match validate_cmd.output().await {
-                Ok(out) => {
-                    let out = String::from_utf8_lossy(&out.stdout);
-                    trace!("RcssServer::validate: `which` returned: '{out}'.");
-                    !out.trim().is_empty()
-                }
-                Err(e) => {
-                    error!("RcssServer::validate: failed to execute `which` command: {}", e);
-                    false
-                }
-            }
-        };
+    pub fn program(&self) -> &Path {
+        &self.program
+    }
 
-        if !is_exist {
-            error!("RcssServer::validate panic: {} is not installed", pgm_name);
-            panic!("{} is not installed", pgm_name);
-        }
+    /// What a spawn runs: the wrapper, the resolved program and its arguments.
+    pub fn command_line(&self) -> Vec<String> {
+        let mut ret = self.binary.wrapper.clone();
+        ret.push(self.program.display().to_string());
+        ret.append(&mut self.config.to_args());
+        ret
     }
 
     fn build_start_cmd(&self) -> Command {
-        let mut cmd = Command::new("stdbuf");
-        cmd.arg("-oL").arg("-eL").arg(self.pgm_name);
+        let mut cmd = self.binary.command(&self.program);
         cmd.args(self.config.to_args());
         cmd
     }
 
     pub async fn spawn(&self) -> Result<ServerProcess> {
+        binary::check_compatibility(&self.config, self.version)?;
+
         let mut cmd = self.build_start_cmd();
         cmd.stdin(Stdio::piped());
         cmd.stdout(Stdio::piped());
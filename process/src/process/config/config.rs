@@ -1,4 +1,4 @@
-use super::{CsvSaverConfig, PlayerConfig, ServerConfig};
+use super::{ConfigError, CsvSaverConfig, PlayerConfig, ServerConfig};
 
 pub const LOG_DIR: &str = "./log";
 
@@ -32,6 +32,43 @@ impl Config {
         args
     }
 
+    /// No parameter set at all, the starting point of a layer.
+    pub fn empty() -> Self {
+        Self {
+            server: ServerConfig::default(),
+            player: PlayerConfig::default(),
+            csv_saver: CsvSaverConfig::default(),
+        }
+    }
+
+    /// Overlay the parameters set in `other`, e.g. defaults < profile < overrides.
+    pub fn merge(&mut self, other: &Config) -> &mut Self {
+        self.server.merge(&other.server);
+        self.player.merge(&other.player);
+        self.csv_saver.merge(&other.csv_saver);
+        self
+    }
+
+    /// Set a parameter by its rcssserver namespace and name, e.g. `("server", "stamina_max", "8000")`.
+    pub fn set_param(&mut self, namespace: &str, name: &str, value: &str) -> Result<(), ConfigError> {
+        let (set, known) = match namespace {
+            ServerConfig::NAMESPACE => (self.server.set_param(name, value), ServerConfig::PARAM_NAMES),
+            PlayerConfig::NAMESPACE => (self.player.set_param(name, value), PlayerConfig::PARAM_NAMES),
+            CsvSaverConfig::NAMESPACE => (self.csv_saver.set_param(name, value), CsvSaverConfig::PARAM_NAMES),
+            _ => (false, &[] as &[&str]),
+        };
+
+        match (set, known.contains(&name)) {
+            (true, _) => Ok(()),
+            (false, true) => Err(ConfigError::InvalidValue {
+                namespace: namespace.to_string(), name: name.to_string(), value: value.to_string(),
+            }),
+            (false, false) => Err(ConfigError::UnknownParam {
+                namespace: namespace.to_string(), name: name.to_string(),
+            }),
+        }
+    }
+
     pub fn default_trainer_on() -> Self {
         let mut ret = Self::default();
         ret.server_then(|cfg| {
@@ -54,16 +91,32 @@ impl Config {
         })
     }
 
-    pub fn with_log_dir(&mut self, log_dir: &'static str) -> &mut Self {
+    /// With the trainer connected, `coach_w_referee` is what keeps the automatic referee running;
+    /// disabling it also turns off the fault rules so play is never stopped by the server.
+    pub fn with_referee(&mut self, enabled: bool) -> &mut Self {
+        self.server_then(|c| {
+            c.coach_w_referee(enabled);
+            if !enabled {
+                c.auto_mode(false)
+                    .free_kick_faults(false)
+                    .forbid_kick_off_offside(false)
+                    .use_offside(false)
+                    .back_passes(false);
+            }
+        })
+    }
+
+    pub fn with_log_dir(&mut self, log_dir: impl Into<String>) -> &mut Self {
         self.server_then(|c| {
-            c.game_log_dir(log_dir);
+            c.game_log_dir(log_dir.into());
         })
     }
 
-    pub fn with_all_log_dir(&mut self, log_dir: &'static str) -> &mut Self {
+    pub fn with_all_log_dir(&mut self, log_dir: impl Into<String>) -> &mut Self {
+        let log_dir = log_dir.into();
         self.server_then(|c| {
-            c.game_log_dir(log_dir);
-            c.text_log_dir(log_dir);
+            c.game_log_dir(log_dir.clone());
+            c.text_log_dir(log_dir.clone());
             c.keepaway_log_dir(log_dir);
         })
     }
@@ -100,3 +153,22 @@ impl Config {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_referee_disabled_args() {
+        let mut config = Config::default_trainer_on();
+        config.with_referee(false);
+
+        let args = config.to_args();
+        assert!(args.contains(&"server::coach_w_referee=false".to_string()));
+        assert!(args.contains(&"server::free_kick_faults=false".to_string()));
+        assert!(args.contains(&"server::use_offside=false".to_string()));
+
+        config.with_referee(true);
+        assert_eq!(config.server.coach_w_referee, Some(true));
+    }
+}
@@ -0,0 +1,25 @@
+use std::path::PathBuf;
+
+#[derive(thiserror::Error, Debug)]
+pub enum ConfigError {
+    #[error("Failed to access config file {path}: {source}")]
+    Io { path: PathBuf, #[source] source: std::io::Error },
+
+    #[error("Unsupported config file {0}, expected a .conf, .toml, .yaml or .yml file")]
+    UnsupportedFormat(PathBuf),
+
+    #[error("Malformed .conf line {line}: {message}")]
+    Syntax { line: usize, message: String },
+
+    #[error("Malformed profile: {0}")]
+    Profile(String),
+
+    #[error("Unknown parameter {namespace}::{name}")]
+    UnknownParam { namespace: String, name: String },
+
+    #[error("Invalid value for {namespace}::{name}: '{value}'")]
+    InvalidValue { namespace: String, name: String, value: String },
+
+    #[error("{namespace}::{name} = {value} is out of range, expected {expected}")]
+    OutOfRange { namespace: &'static str, name: &'static str, value: f64, expected: String },
+}
@@ -0,0 +1,178 @@
+//! Native rcssserver `.conf` files and TOML/YAML profiles.
+//!
+//! A `.conf` file holds one `namespace::name = value` per line, e.g. `server::stamina_max = 8000`.
+//! A profile holds one table per namespace:
+//!
+//! ```toml
+//! [server]
+//! stamina_max = 8000
+//!
+//! [player]
+//! player_types = 18
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use super::{Config, ConfigError, CsvSaverConfig, ParamValue, PlayerConfig, ServerConfig};
+
+type Profile = BTreeMap<String, BTreeMap<String, ParamValue>>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Conf,
+    Toml,
+    Yaml,
+}
+
+impl ConfigFormat {
+    pub fn from_path(path: &Path) -> Result<Self, ConfigError> {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("conf") => Ok(ConfigFormat::Conf),
+            Some("toml") => Ok(ConfigFormat::Toml),
+            Some("yaml" | "yml") => Ok(ConfigFormat::Yaml),
+            _ => Err(ConfigError::UnsupportedFormat(path.to_path_buf())),
+        }
+    }
+}
+
+impl Config {
+    /// A config holding only what the file sets, meant to be merged over a base.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let format = ConfigFormat::from_path(path)?;
+        let content = std::fs::read_to_string(path).map_err(|source| ConfigError::Io {
+            path: path.to_path_buf(), source,
+        })?;
+        Self::parse(&content, format)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ConfigError> {
+        let path = path.as_ref();
+        let content = self.format(ConfigFormat::from_path(path)?)?;
+        std::fs::write(path, content).map_err(|source| ConfigError::Io {
+            path: PathBuf::from(path), source,
+        })
+    }
+
+    pub fn parse(content: &str, format: ConfigFormat) -> Result<Self, ConfigError> {
+        let mut ret = Self::empty();
+        match format {
+            ConfigFormat::Conf => {
+                for (i, line) in content.lines().enumerate() {
+                    let line = line.trim();
+                    if line.is_empty() || line.starts_with('#') {
+                        continue;
+                    }
+
+                    let syntax = |message: &str| ConfigError::Syntax { line: i + 1, message: message.to_string() };
+                    let (key, value) = line.split_once('=').ok_or_else(|| syntax("expected `namespace::name = value`"))?;
+                    let (namespace, name) = key.trim().split_once("::").ok_or_else(|| syntax("expected a `namespace::` prefix"))?;
+                    ret.set_param(namespace, name, value.trim())?;
+                }
+            },
+            ConfigFormat::Toml | ConfigFormat::Yaml => {
+                let profile: Profile = match format {
+                    ConfigFormat::Toml => toml::from_str(content).map_err(|e| ConfigError::Profile(e.to_string()))?,
+                    _ => serde_yaml::from_str(content).map_err(|e| ConfigError::Profile(e.to_string()))?,
+                };
+                for (namespace, params) in profile {
+                    for (name, value) in params {
+                        ret.set_param(&namespace, &name, &value.to_string())?;
+                    }
+                }
+            },
+        }
+        Ok(ret)
+    }
+
+    pub fn format(&self, format: ConfigFormat) -> Result<String, ConfigError> {
+        let sections = [
+            (ServerConfig::NAMESPACE, self.server.params()),
+            (PlayerConfig::NAMESPACE, self.player.params()),
+            (CsvSaverConfig::NAMESPACE, self.csv_saver.params()),
+        ];
+
+        match format {
+            ConfigFormat::Conf => {
+                let mut ret = String::new();
+                for (namespace, params) in sections {
+                    for (name, value) in params {
+                        let value = match value {
+                            ParamValue::Text(s) => format!("'{s}'"),
+                            value => value.to_string(),
+                        };
+                        ret.push_str(&format!("{namespace}::{name} = {value}\n"));
+                    }
+                }
+                Ok(ret)
+            },
+            ConfigFormat::Toml | ConfigFormat::Yaml => {
+                let profile: Profile = sections.into_iter()
+                    .filter(|(_, params)| !params.is_empty())
+                    .map(|(namespace, params)| {
+                        (namespace.to_string(), params.into_iter().map(|(n, v)| (n.to_string(), v)).collect())
+                    })
+                    .collect();
+                match format {
+                    ConfigFormat::Toml => toml::to_string(&profile).map_err(|e| ConfigError::Profile(e.to_string())),
+                    _ => serde_yaml::to_string(&profile).map_err(|e| ConfigError::Profile(e.to_string())),
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_conf() {
+        let conf = "# tuned\nserver::stamina_max = 8000\n\nserver::game_log_dir = './games'\nplayer::player_types = 18\n";
+        let config = Config::parse(conf, ConfigFormat::Conf).unwrap();
+
+        assert_eq!(config.server.stamina_max, Some(8000.0));
+        assert_eq!(config.server.game_log_dir.as_deref(), Some("./games"));
+        assert_eq!(config.player.player_types, Some(18));
+        assert!(config.server.port.is_none());
+
+        assert!(matches!(
+            Config::parse("server::no_such_param = 1", ConfigFormat::Conf),
+            Err(ConfigError::UnknownParam { .. })
+        ));
+        assert!(matches!(
+            Config::parse("server::stamina_max = lots", ConfigFormat::Conf),
+            Err(ConfigError::InvalidValue { .. })
+        ));
+        assert!(matches!(
+            Config::parse("stamina_max 8000", ConfigFormat::Conf),
+            Err(ConfigError::Syntax { line: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_profile_roundtrip() {
+        let mut config = Config::empty();
+        config.server_then(|c| {
+            c.stamina_max(8000.0).use_offside(false).game_log_dir("./games".to_string());
+        });
+        config.player_then(|c| { c.player_types(18); });
+
+        for format in [ConfigFormat::Conf, ConfigFormat::Toml, ConfigFormat::Yaml] {
+            let content = config.format(format).unwrap();
+            let parsed = Config::parse(&content, format).unwrap();
+            assert_eq!(parsed.to_args(), config.to_args(), "{format:?}:\n{content}");
+        }
+    }
+
+    #[test]
+    fn test_parse_yaml_profile() {
+        let yaml = "server:\n  stamina_max: 8000\n  player_rand: 0.2\nplayer:\n  player_types: 18\n";
+        let config = Config::parse(yaml, ConfigFormat::Yaml).unwrap();
+
+        assert_eq!(config.server.stamina_max, Some(8000.0));
+        assert_eq!(config.server.player_rand, Some(0.2));
+        assert_eq!(config.player.player_types, Some(18));
+    }
+}
@@ -1,13 +1,103 @@
 mod config;
+mod error;
+mod file;
+mod validate;
 pub mod csv_saver;
 pub mod player;
+pub mod player_type;
 pub mod server;
 
 pub use csv_saver::CsvSaverConfig;
 pub use player::PlayerConfig;
+pub use player_type::PlayerType;
 pub use server::ServerConfig;
 
 pub use config::Config;
+pub use error::ConfigError;
+pub use file::ConfigFormat;
+
+use std::fmt::Display;
+use serde::{Deserialize, Serialize};
+
+/// A value of an rcssserver parameter, as written in `server_param` messages and `.conf` files.
+pub trait ConfigValue: Sized {
+    fn parse_value(s: &str) -> Option<Self>;
+    fn to_param_value(&self) -> ParamValue;
+}
+
+/// A parameter value detached from its field type, as read from and written to profiles.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum ParamValue {
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Text(String),
+}
+
+impl ParamValue {
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            ParamValue::Int(i) => Some(*i as f64),
+            ParamValue::Float(f) => Some(*f),
+            _ => None,
+        }
+    }
+}
+
+impl Display for ParamValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParamValue::Bool(b) => write!(f, "{b}"),
+            ParamValue::Int(i) => write!(f, "{i}"),
+            ParamValue::Float(v) => write!(f, "{v}"),
+            ParamValue::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+macro_rules! impl_config_value_from_str {
+    ($($ty:ty => $variant:ident),+) => {
+        $(
+            impl ConfigValue for $ty {
+                fn parse_value(s: &str) -> Option<Self> {
+                    s.trim().parse().ok()
+                }
+
+                fn to_param_value(&self) -> ParamValue {
+                    // through the shortest decimal form, so 0.3f32 stays 0.3
+                    ParamValue::$variant(self.to_string().parse().unwrap())
+                }
+            }
+        )+
+    };
+}
+
+impl_config_value_from_str!(i32 => Int, u16 => Int, f32 => Float, f64 => Float);
+
+impl ConfigValue for bool {
+    fn parse_value(s: &str) -> Option<Self> {
+        match s.trim() {
+            "1" | "true" | "on" => Some(true),
+            "0" | "false" | "off" => Some(false),
+            _ => None,
+        }
+    }
+
+    fn to_param_value(&self) -> ParamValue {
+        ParamValue::Bool(*self)
+    }
+}
+
+impl ConfigValue for String {
+    fn parse_value(s: &str) -> Option<Self> {
+        Some(s.trim().trim_matches(['"', '\'']).to_string())
+    }
+
+    fn to_param_value(&self) -> ParamValue {
+        ParamValue::Text(self.clone())
+    }
+}
 
 #[macro_export]
 macro_rules! create_config {
@@ -29,6 +119,73 @@ macro_rules! create_config {
         }
 
         impl $ident {
+            pub const NAMESPACE: &'static str = $namespace;
+            pub const PARAM_NAMES: &'static [&'static str] = &[$(stringify!($field)),*];
+
+            /// Get a field by its rcssserver parameter name, `None` if unset or unknown.
+            pub fn get_param(&self, name: &str) -> Option<$crate::process::config::ParamValue> {
+                use $crate::process::config::ConfigValue as _;
+                match name {
+                    $(
+                        stringify!($field) => self.$field.as_ref().map(|v| v.to_param_value()),
+                    )*
+                    _ => None,
+                }
+            }
+
+            /// The fields that are set, in declaration order.
+            pub fn params(&self) -> Vec<(&'static str, $crate::process::config::ParamValue)> {
+                use $crate::process::config::ConfigValue as _;
+                let mut ret = vec![];
+                $(
+                    if let Some(value) = &self.$field {
+                        ret.push((stringify!($field), value.to_param_value()));
+                    }
+                )*
+                ret
+            }
+
+            /// Overlay the fields set in `other`.
+            pub fn merge(&mut self, other: &Self) -> &mut Self {
+                $(
+                    if let Some(value) = &other.$field {
+                        self.$field = Some(value.clone());
+                    }
+                )*
+                self
+            }
+
+            /// Set a field by its rcssserver parameter name, returns `false` if the name is
+            /// unknown or the value does not parse.
+            pub fn set_param(&mut self, name: &str, value: &str) -> bool {
+                use $crate::process::config::ConfigValue as _;
+                match name {
+                    $(
+                        stringify!($field) => match <$value>::parse_value(value) {
+                            Some(value) => {
+                                self.$field = Some(value);
+                                true
+                            }
+                            None => false,
+                        },
+                    )*
+                    _ => false,
+                }
+            }
+
+            /// Parse the `(NAME VALUE)` pairs of a `server_param`/`player_param` message body.
+            pub fn from_params(params: &[::common::utils::sexp::Sexp]) -> Self {
+                let mut ret = Self::default();
+                for param in params {
+                    if let Some([name, value]) = param.list()
+                        && let (Some(name), Some(value)) = (name.atom(), value.atom())
+                    {
+                        ret.set_param(name, value);
+                    }
+                }
+                ret
+            }
+
             pub fn to_args(&self) -> Vec<String> {
                 let mut args = vec![];
                 $(
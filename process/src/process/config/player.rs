@@ -1,7 +1,7 @@
 use crate::create_config;
 
 create_config!(PlayerConfig, "player", {
-    version: &'static str,
+    version: String,
     player_types: i32,
     pt_max: i32,
     random_seed: i32,
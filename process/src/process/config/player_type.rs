@@ -0,0 +1,34 @@
+//! https://github.com/rcsoccersim/rcssserver/blob/master/src/heteroplayer.cpp
+//!
+//! Not a command line namespace, only parsed from the `player_type` messages.
+
+use crate::create_config;
+
+create_config!(PlayerType, "player_type", {
+    id: i32,
+    player_speed_max: f64,
+    stamina_inc_max: f64,
+    player_decay: f64,
+    inertia_moment: f64,
+    dash_power_rate: f64,
+    player_size: f64,
+    kickable_margin: f64,
+    kick_rand: f64,
+    extra_stamina: f64,
+    effort_max: f64,
+    effort_min: f64,
+    kick_power_rate: f64,
+    foul_detect_probability: f64,
+    catchable_area_l_stretch: f64,
+    unum_far_length: f64,
+    unum_too_far_length: f64,
+    team_far_length: f64,
+    team_too_far_length: f64,
+    player_max_observation_length: f64,
+    ball_vel_far_length: f64,
+    ball_vel_too_far_length: f64,
+    ball_max_observation_length: f64,
+    flag_chg_far_length: f64,
+    flag_chg_too_far_length: f64,
+    flag_max_observation_length: f64,
+});
@@ -3,7 +3,7 @@
 use crate::create_config;
 
 create_config! (ServerConfig, "server", {
-    version: &'static str,
+    version: String,
     catch_ban_cycle: i32,
     clang_advice_win: i32,
     clang_define_win: i32,
@@ -193,19 +193,19 @@ create_config! (ServerConfig, "server", {
     wind_dir: f64,
     wind_force: f64,
     wind_rand: f64,
-    coach_msg_file: &'static str,
-    fixed_teamname_l: &'static str,
-    fixed_teamname_r: &'static str,
-    game_log_dir: &'static str,
-    game_log_fixed_name: &'static str,
-    keepaway_log_dir: &'static str,
-    keepaway_log_fixed_name: &'static str,
-    landmark_file: &'static str,
-    log_date_format: &'static str,
-    team_l_start: &'static str,
-    team_r_start: &'static str,
-    text_log_dir: &'static str,
-    text_log_fixed_name: &'static str,
+    coach_msg_file: String,
+    fixed_teamname_l: String,
+    fixed_teamname_r: String,
+    game_log_dir: String,
+    game_log_fixed_name: String,
+    keepaway_log_dir: String,
+    keepaway_log_fixed_name: String,
+    landmark_file: String,
+    log_date_format: String,
+    team_l_start: String,
+    team_r_start: String,
+    text_log_dir: String,
+    text_log_fixed_name: String,
 });
 
 // impl Default for ServerConfig {
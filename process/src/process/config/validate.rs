@@ -0,0 +1,113 @@
+use super::{Config, ConfigError, CsvSaverConfig, ParamValue, PlayerConfig, ServerConfig};
+
+/// Inclusive bounds of a numeric parameter, checked only when the parameter is set.
+struct Range {
+    namespace: &'static str,
+    name: &'static str,
+    min: Option<f64>,
+    max: Option<f64>,
+}
+
+const fn at_least(namespace: &'static str, name: &'static str, min: f64) -> Range {
+    Range { namespace, name, min: Some(min), max: None }
+}
+
+const fn between(namespace: &'static str, name: &'static str, min: f64, max: f64) -> Range {
+    Range { namespace, name, min: Some(min), max: Some(max) }
+}
+
+const SERVER: &str = ServerConfig::NAMESPACE;
+const PLAYER: &str = PlayerConfig::NAMESPACE;
+
+const RANGES: &[Range] = &[
+    at_least(SERVER, "simulator_step", 1.0),
+    at_least(SERVER, "send_step", 1.0),
+    at_least(SERVER, "sense_body_step", 1.0),
+    at_least(SERVER, "drop_ball_time", 0.0),
+    at_least(SERVER, "game_over_wait", 0.0),
+    at_least(SERVER, "stamina_max", 1.0),
+    at_least(SERVER, "stamina_inc_max", 0.0),
+    at_least(SERVER, "ball_speed_max", 0.0),
+    at_least(SERVER, "player_speed_max", 0.0),
+    at_least(SERVER, "kickable_margin", 0.0),
+    at_least(SERVER, "inertia_moment", 0.0),
+    between(SERVER, "ball_decay", 0.0, 1.0),
+    between(SERVER, "player_decay", 0.0, 1.0),
+    between(SERVER, "ball_rand", 0.0, 1.0),
+    between(SERVER, "player_rand", 0.0, 1.0),
+    between(SERVER, "kick_rand", 0.0, 1.0),
+    between(SERVER, "wind_rand", 0.0, 1.0),
+    between(SERVER, "tackle_rand_factor", 0.0, 1.0),
+    between(SERVER, "catch_probability", 0.0, 1.0),
+    between(SERVER, "foul_detect_probability", 0.0, 1.0),
+    between(SERVER, "effort_min", 0.0, 1.0),
+    between(SERVER, "recover_min", 0.0, 1.0),
+    between(SERVER, "visible_angle", 0.0, 360.0),
+    at_least(PLAYER, "player_types", 1.0),
+    at_least(PLAYER, "pt_max", 0.0),
+    at_least(PLAYER, "subs_max", 0.0),
+];
+
+impl Config {
+    /// Check the set parameters against the ranges rcssserver can run with.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        for range in RANGES {
+            let Some(value) = self.get_param(range.namespace, range.name).as_ref().and_then(ParamValue::as_f64) else {
+                continue
+            };
+
+            let below = range.min.is_some_and(|min| value < min);
+            let above = range.max.is_some_and(|max| value > max);
+            if below || above {
+                let expected = match (range.min, range.max) {
+                    (Some(min), Some(max)) => format!("between {min} and {max}"),
+                    (Some(min), None) => format!("at least {min}"),
+                    (None, Some(max)) => format!("at most {max}"),
+                    (None, None) => unreachable!(),
+                };
+                return Err(ConfigError::OutOfRange {
+                    namespace: range.namespace, name: range.name, value, expected,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn get_param(&self, namespace: &str, name: &str) -> Option<ParamValue> {
+        match namespace {
+            ServerConfig::NAMESPACE => self.server.get_param(name),
+            PlayerConfig::NAMESPACE => self.player.get_param(name),
+            CsvSaverConfig::NAMESPACE => self.csv_saver.get_param(name),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ranges_name_known_params() {
+        for range in RANGES {
+            let known = match range.namespace {
+                ServerConfig::NAMESPACE => ServerConfig::PARAM_NAMES,
+                PlayerConfig::NAMESPACE => PlayerConfig::PARAM_NAMES,
+                _ => &[],
+            };
+            assert!(known.contains(&range.name), "{}::{}", range.namespace, range.name);
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut config = Config::default_trainer_on();
+        config.server_then(|c| { c.player_rand(0.2).stamina_max(8000.0); });
+        assert!(config.validate().is_ok());
+
+        config.server_then(|c| { c.player_rand(1.5); });
+        let err = config.validate().unwrap_err();
+        assert!(matches!(err, ConfigError::OutOfRange { name: "player_rand", .. }));
+        assert_eq!(err.to_string(), "server::player_rand = 1.5 is out of range, expected between 0 and 1");
+    }
+}
@@ -1,5 +1,7 @@
 use common::process::ProcessError;
 
+use super::binary::Version;
+
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
     #[error("Failed to start server: system process limit is reached. source: {0}")]
@@ -10,6 +12,18 @@ pub enum Error {
 
     #[error(transparent)]
     Process(#[from] ProcessError),
+
+    #[error("rcssserver executable '{program}' not found")]
+    BinaryNotFound { program: String },
+
+    #[error("Failed to detect the rcssserver version of '{program}', output: {output:?}")]
+    VersionUndetected { program: String, output: String },
+
+    #[error("rcssserver {found} is not supported, at least {min} is required")]
+    UnsupportedVersion { found: Version, min: Version },
+
+    #[error("{name} requires rcssserver {since}, found {found}")]
+    UnsupportedParam { name: String, since: Version, found: Version },
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
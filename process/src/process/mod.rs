@@ -1,4 +1,5 @@
 mod builder;
+pub mod binary;
 pub mod config;
 pub mod error;
 pub mod process;
@@ -6,6 +7,7 @@ pub mod process;
 pub use config::*;
 pub use error::*;
 
+pub use binary::{RcssBinary, Version};
 pub use builder::ServerProcessSpawner;
 pub use process::ServerProcess;
 pub use common::process::ProcessError;
@@ -5,6 +5,7 @@ use tokio::process::Child;
 use tokio::sync::{broadcast, watch};
 use common::process::{Process, ProcessError, ProcessStatus as Status, ProcessStatusKind};
 
+use super::binary::RcssBinary;
 use super::builder::ServerProcessSpawner;
 use super::error::{Error, Result};
 
@@ -19,8 +20,8 @@ pub struct ServerProcess {
 impl ServerProcess {
     pub const TERM_TIMEOUT_S: Duration = Duration::from_secs(5);
 
-    pub async fn spawner(pgm_name: &'static str) -> ServerProcessSpawner {
-        ServerProcessSpawner::new(pgm_name).await
+    pub async fn spawner(binary: RcssBinary) -> Result<ServerProcessSpawner> {
+        ServerProcessSpawner::new(binary).await
     }
 
     pub(crate) async fn try_from(child: Child) -> Result<ServerProcess> {
@@ -7,7 +7,7 @@ async fn processes_spawn_and_shutdown_1k() {
 
     let mut tasks = vec![];
 
-    let mut builder = ServerProcess::spawner("rcssserver").await;
+    let mut builder = ServerProcess::spawner(Default::default()).await.unwrap();
 
     for mut ports in (6000..=9000).chunks(3).into_iter() {
         if let Some((server, coach, trainer)) = ports.next_tuple() {
@@ -3,11 +3,23 @@ use crate::client::{CallResolver, CallSender, Result, RichClient};
 use common::client::{RxData, TxData};
 use common::command::trainer::TrainerCommand;
 use common::{client, command};
-use log::{debug, error, trace};
+use log::{debug, error, trace, warn};
 use arcstr::ArcStr;
 use std::ops::{Deref, DerefMut};
+use std::time::Duration;
+use tokio::sync::mpsc;
 use common::command::{CommandAny};
 
+/// Trainer protocol the service speaks to rcssserver.
+pub const PROTOCOL_VERSION: u8 = 5;
+
+/// Outcome of [`OfflineCoach::step`], `stepped < cycles` means the deadline passed.
+#[derive(Clone, Copy, Debug)]
+pub struct StepOutcome {
+    pub stepped: u16,
+    pub time: u16,
+}
+
 #[derive(Debug)]
 pub struct OfflineCoach {
     client: RichClient<TrainerCommand>,
@@ -65,7 +77,7 @@ impl OfflineCoach {
     pub async fn connect_and_init(&self) -> Result<()> {
         self.connect().await?;
 
-        match self.call(command::trainer::Init { version: Some(5) }).await? {
+        match self.call(command::trainer::Init { version: Some(PROTOCOL_VERSION) }).await? {
             Ok(ok) => {
                 trace!("[OfflineCoach] Init command succeeded returned with {ok:?}.");
                 Ok(())
@@ -80,6 +92,52 @@ impl OfflineCoach {
         }
     }
     
+    /// Drive a synch mode server `cycles` cycles forward.
+    ///
+    /// Each cycle the trainer sends `(done)` and waits for the next `(think)`, which
+    /// rcssserver only sends once every connected client is done. Stops early once
+    /// `timeout` has passed, then returns the cycle reported by `check_ball`.
+    pub async fn step(&self, cycles: u16, timeout: Duration) -> Result<StepOutcome> {
+        let (tx, mut rx) = mpsc::channel(32);
+        let id = self.subscribe(tx);
+        let deadline = tokio::time::Instant::now() + timeout;
+
+        let mut stepped = 0;
+        let res = async {
+            while stepped < cycles {
+                self.send_cmd(command::trainer::Done).await?;
+                let think = tokio::time::timeout_at(deadline, async {
+                    while let Some(msg) = rx.recv().await {
+                        if msg.starts_with("(think)") {
+                            return true;
+                        }
+                    }
+                    false
+                }).await;
+
+                match think {
+                    Ok(true) => stepped += 1,
+                    Ok(false) => return Err(crate::client::Error::SenderClosed),
+                    Err(_) => {
+                        warn!("[OfflineCoach] Step deadline passed after {stepped}/{cycles} cycles.");
+                        break;
+                    },
+                }
+            }
+            Ok(())
+        }.await;
+        self.unsubscribe(id);
+        res?;
+
+        let time = match self.call(command::trainer::CheckBall).await? {
+            Ok(ok) => ok.time,
+            Err(e) => match e {},
+        };
+        trace!("[OfflineCoach] Stepped {stepped} cycles, now at {time}.");
+
+        Ok(StepOutcome { stepped, time })
+    }
+
     pub fn command_sender(&self) -> CallSender<TrainerCommand, TxData, RxData> {
         self.caller()
     }
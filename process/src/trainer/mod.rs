@@ -2,5 +2,5 @@ mod builder;
 mod coach;
 
 pub use builder::OfflineCoachBuilder as Builder;
-pub use coach::OfflineCoach;
+pub use coach::{OfflineCoach, StepOutcome, PROTOCOL_VERSION};
 pub use coach::OfflineCoach as Trainer;
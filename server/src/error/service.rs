@@ -12,7 +12,9 @@ impl<'a> ServiceError<'a> {
             Error::Timeout { op: _ } => StatusCode::REQUEST_TIMEOUT,
             Error::ProcessFailedToShutdown(_) => StatusCode::INTERNAL_SERVER_ERROR,
             Error::ProcessSpawnFailed(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            Error::ProcessConfigInvalid(_) => StatusCode::BAD_REQUEST,
             Error::TrainerCommandFailed(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            Error::NotSyncMode => StatusCode::CONFLICT,
             Error::StatusChannelClosed => StatusCode::INTERNAL_SERVER_ERROR,
             #[cfg(feature = "agones")]
             Error::AgonesSdkFailToConnect(_) => unreachable!(),
@@ -52,12 +54,18 @@ impl<'a> From<ServiceError<'a>> for Response {
                     "Failed to spawn process due to internal error."
                 )
             },
+            Error::ProcessConfigInvalid(_) => {
+                Response::error("ProcessConfigInvalid", &value.0.to_string())
+            },
             Error::TrainerCommandFailed(_) => {
                 Response::error(
                     "TrainerCommandFailed",
                     "Failed to send command to trainer process due to internal error."
                 )
             },
+            Error::NotSyncMode => {
+                Response::error("NotSyncMode", &value.0.to_string())
+            },
             Error::StatusChannelClosed => {
                 Response::error(
                     "StatusChannelClosed",
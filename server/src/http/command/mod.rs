@@ -1,4 +1,5 @@
 mod trainer;
+mod trainer_ws;
 
 use axum::Router;
 use serde::Serialize;
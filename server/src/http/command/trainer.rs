@@ -24,23 +24,29 @@ impl<C: Command<Kind = TrainerCommand>> Serialize for PostResponse<C> {
     }
 }
 
+/// Send `command` through the service's trainer, shared with the WebSocket.
+pub(super) async fn execute<C: Command<Kind = TrainerCommand>>(s: &AppState, command: C) -> Response {
+    let result = match s.service.send_trainer_command(command).await {
+        Ok(result) => result,
+        Err(e) => return Error::from(e).into(),
+    };
+
+    let result: CommandResponse<C> = result.into();
+    let resp = PostResponse(result);
+    debug!("{:?}", serde_json::to_string(&resp));
+    Response::success(resp)
+}
+
 pub async fn post<C: Command<Kind = TrainerCommand>>(
     State(s): State<AppState>,
     Json(req): Json<PostRequest<C>>,
 ) -> Json<Response> {
-    let result = s.service.send_trainer_command(req.args).await;
-    if let Err(e) = result {
-        return Json(Error::from(e).into());
-    }
-
-    let result: CommandResponse<C> = result.expect("WTF").into();
-    let resp = PostResponse(result);
-    debug!("{:?}", serde_json::to_string(&resp));
-    Json(Response::success(resp))
+    Json(execute(&s, req.args).await)
 }
 
 pub fn route(path: &str) -> Router<AppState> {
     let inner = Router::new()
+        .route("/", routing::get(super::trainer_ws::upgrade))
         .route("/change_mode", routing::post(post::<ChangeMode>))
         .route("/check_ball", routing::post(post::<CheckBall>))
         .route("/ear", routing::post(post::<Ear>))
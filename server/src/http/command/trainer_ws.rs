@@ -0,0 +1,243 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use axum::extract::ws::{Message, WebSocket};
+use axum::extract::{State, WebSocketUpgrade};
+use axum::response::Response as AxumResponse;
+use futures::StreamExt;
+use log::{error, info, trace, warn};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::error::TrySendError;
+use tokio::task::JoinHandle;
+
+use common::client::RxData;
+use common::command::Command;
+use common::command::CommandAny;
+use common::command::trainer::*;
+
+use crate::proxy::ws::ws_into_mpsc_tx;
+use crate::state::AppStateStatus;
+
+use super::trainer::{PostRequest, execute};
+use super::{AppState, Response};
+
+/// A command, its args flattened in like the body of `POST /trainer/<command>`.
+#[derive(Deserialize, Debug)]
+struct Request {
+    /// Echoed back with the result, so results can come back out of order.
+    #[serde(default)]
+    id: Value,
+    command: String,
+    #[serde(flatten)]
+    args: Map<String, Value>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Event<'a> {
+    /// Anything rcssserver sent the trainer, referee messages and `see_global` included.
+    Message { data: &'a str },
+    /// Messages dropped since the last one, the client did not keep up.
+    Lagged { dropped: u64 },
+    /// The same response `POST /trainer/<command>` would have returned.
+    Result { id: Value, command: String, response: Response },
+    /// A request which could not be read.
+    Error { error: &'static str, desc: String },
+}
+
+impl Event<'_> {
+    fn into_message(self) -> Message {
+        Message::Text(serde_json::to_string(&self).expect("events are plain values").into())
+    }
+}
+
+async fn call<C>(s: &AppState, args: Map<String, Value>) -> Response
+where C: Command<Kind = TrainerCommand> + DeserializeOwned {
+    match serde_json::from_value::<PostRequest<C>>(Value::Object(args)) {
+        Ok(req) => execute(s, req.args).await,
+        Err(e) => Response::error("InvalidArgs", &e.to_string()),
+    }
+}
+
+/// The commands `POST /trainer/<command>` takes.
+async fn dispatch(s: &AppState, command: &str, args: Map<String, Value>) -> Response {
+    match TrainerCommand::decode(command) {
+        Some(TrainerCommand::ChangeMode) => call::<ChangeMode>(s, args).await,
+        Some(TrainerCommand::CheckBall) => call::<CheckBall>(s, args).await,
+        Some(TrainerCommand::Ear) => call::<Ear>(s, args).await,
+        Some(TrainerCommand::Eye) => call::<Eye>(s, args).await,
+        Some(TrainerCommand::Init) => call::<Init>(s, args).await,
+        Some(TrainerCommand::Look) => call::<Look>(s, args).await,
+        Some(TrainerCommand::Move) => call::<Move>(s, args).await,
+        Some(TrainerCommand::Recover) => call::<Recover>(s, args).await,
+        Some(TrainerCommand::Start) => call::<Start>(s, args).await,
+        Some(TrainerCommand::TeamNames) => call::<TeamNames>(s, args).await,
+        Some(TrainerCommand::Done) | None => {
+            Response::error("UnknownCommand", &format!("No trainer command '{command}'."))
+        },
+    }
+}
+
+/// Messages for the client which have not gone out yet, more are dropped.
+const TRAINER_BUFFER: usize = 64;
+
+/// Pass everything from the service's trainer on to `tx`, dropping what does not fit.
+///
+/// The trainer waits on each of its subscribers, so one client falling behind would
+/// hold up the service's own calls, the match state and the stopping conditions.
+fn forward_lossy(
+    mut rx: mpsc::Receiver<RxData>,
+    tx: mpsc::Sender<RxData>,
+    dropped: Arc<AtomicU64>,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        while let Some(msg) = rx.recv().await {
+            match tx.try_send(msg) {
+                Ok(()) => {},
+                Err(TrySendError::Full(_)) => { dropped.fetch_add(1, Ordering::Relaxed); },
+                Err(TrySendError::Closed(_)) => break,
+            }
+        }
+    })
+}
+
+pub async fn upgrade(State(s): State<AppState>, ws: WebSocketUpgrade) -> AxumResponse {
+    ws.on_upgrade(move |socket| async move { handle_upgrade(socket, s).await })
+}
+
+async fn handle_upgrade(socket: WebSocket, state: AppState) {
+    let (socket_tx, mut socket_rx, mut socket_task) = ws_into_mpsc_tx::<32>(socket);
+    let (trainer_tx, mut trainer_rx) = mpsc::channel(TRAINER_BUFFER);
+    let (subscribe_tx, subscribe_rx) = mpsc::channel(TRAINER_BUFFER);
+    let dropped = Arc::new(AtomicU64::new(0));
+    let forward_task = forward_lossy(subscribe_rx, trainer_tx, dropped.clone());
+    let mut generation = state.service.generation_watch();
+    generation.mark_changed();
+    info!("[Trainer WS] Client connected.");
+
+    let mut state_status = state.status_rx.clone();
+    loop {
+        tokio::select! {
+            _ = state_status.changed() => {
+                let status = *state_status.borrow();
+                match status {
+                    AppStateStatus::ShuttingDown|AppStateStatus::Stopped => {
+                        info!("[Trainer WS] Server is shutting down, closing WebSocket...");
+                        socket_tx.send(Message::Close(None)).await.ok();
+                    }
+                    _ => continue
+                }
+            },
+
+            // a restart replaced the service's trainer, follow the new one
+            Ok(()) = generation.changed() => {
+                generation.borrow_and_update();
+                if let Err(e) = state.service.subscribe_trainer(subscribe_tx.clone()).await {
+                    warn!("[Trainer WS] Failed to follow the service's trainer: {e}");
+                }
+            },
+
+            socket_close = &mut socket_task => {
+                match socket_close {
+                    Ok(Ok(())) => trace!("[Trainer WS] WebSocket closed normally."),
+                    Ok(Err(e)) => warn!("[Trainer WS] WebSocket closed with error: {e}"),
+                    Err(e) => warn!("[Trainer WS] WebSocket task failed to join: {e}"),
+                }
+                break;
+            },
+
+            Some(msg) = socket_rx.next() => {
+                let text = match msg {
+                    Ok(Message::Text(text)) => text,
+                    Ok(Message::Ping(ping)) => {
+                        socket_tx.send(Message::Pong(ping)).await.ok();
+                        continue;
+                    },
+                    Ok(Message::Close(_)) => break,
+                    Ok(_) => continue,
+                    Err(e) => {
+                        error!("[Trainer WS] Failed to receive message: {e}");
+                        break;
+                    },
+                };
+
+                let req = match serde_json::from_str::<Request>(&text) {
+                    Ok(req) => req,
+                    Err(e) => {
+                        let event = Event::Error { error: "InvalidRequest", desc: e.to_string() };
+                        socket_tx.send(event.into_message()).await.ok();
+                        continue;
+                    },
+                };
+
+                // commands wait on rcssserver, the stream must not
+                let state = state.clone();
+                let socket_tx = socket_tx.clone();
+                tokio::spawn(async move {
+                    let response = dispatch(&state, &req.command, req.args).await;
+                    let event = Event::Result { id: req.id, command: req.command, response };
+                    socket_tx.send(event.into_message()).await.ok();
+                });
+            },
+
+            Some(msg) = trainer_rx.recv() => {
+                let lagged = dropped.swap(0, Ordering::Relaxed);
+                if lagged > 0 {
+                    warn!("[Trainer WS] Client fell behind, {lagged} messages dropped.");
+                    socket_tx.send(Event::Lagged { dropped: lagged }.into_message()).await.ok();
+                }
+                if let Err(e) = socket_tx.send(Event::Message { data: &msg }.into_message()).await {
+                    error!("[Trainer WS] Failed to send message: {e}");
+                    break;
+                }
+            },
+        }
+    }
+    forward_task.abort();
+    info!("[Trainer WS] Client disconnected.");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_and_events() {
+        let req: Request = serde_json::from_str(
+            r#"{"id": 7, "command": "move", "target": "ball", "x": 1.0, "y": -2.0}"#
+        ).unwrap();
+        assert_eq!((req.id.clone(), req.command.as_str()), (Value::from(7), "move"));
+        let PostRequest { args: Move { x, y, .. } } = serde_json::from_value(Value::Object(req.args)).unwrap();
+        assert_eq!((x, y), (1.0, -2.0));
+
+        let req: Request = serde_json::from_str(r#"{"command": "team_names"}"#).unwrap();
+        assert!(req.id.is_null());
+        serde_json::from_value::<PostRequest<TeamNames>>(Value::Object(req.args)).unwrap();
+
+        let event = serde_json::to_value(Event::Message { data: "(hear 0 referee kick_off_l)" }).unwrap();
+        assert_eq!(event, serde_json::json!({ "type": "message", "data": "(hear 0 referee kick_off_l)" }));
+    }
+
+    #[tokio::test]
+    async fn test_forward_never_waits_on_the_client() {
+        let (subscribe_tx, subscribe_rx) = mpsc::channel(4);
+        let (client_tx, mut client_rx) = mpsc::channel(2);
+        let dropped = Arc::new(AtomicU64::new(0));
+        let task = forward_lossy(subscribe_rx, client_tx, dropped.clone());
+
+        // nobody reads the client side, the trainer's sends still go through
+        for i in 0..10 {
+            let send = subscribe_tx.send(format!("(msg {i})").into());
+            tokio::time::timeout(std::time::Duration::from_millis(100), send).await.unwrap().unwrap();
+        }
+        drop(subscribe_tx);
+        task.await.unwrap();
+
+        assert_eq!(client_rx.recv().await.unwrap(), "(msg 0)");
+        assert_eq!(client_rx.recv().await.unwrap(), "(msg 1)");
+        assert_eq!(dropped.load(Ordering::Relaxed), 8);
+    }
+}
@@ -1,6 +1,7 @@
 #[cfg(feature = "standalone")]
 mod restart;
 mod shutdown;
+mod step;
 
 use super::{AppState, Response};
 use axum::Router;
@@ -12,7 +13,8 @@ pub fn route(path: &str) -> Router<AppState> {
     let inner = inner.merge(restart::route("/restart"));
     
     let inner = inner
-        .merge(shutdown::route("/shutdown"));
+        .merge(shutdown::route("/shutdown"))
+        .merge(step::route("/step"));
 
     if path == "/" {
         inner
@@ -0,0 +1,56 @@
+use std::time::Duration;
+
+use super::{AppState, Response};
+use crate::error::Error;
+use axum::extract::State;
+use axum::{Json, Router, routing};
+use serde::{Deserialize, Serialize};
+
+fn default_cycles() -> u16 {
+    1
+}
+
+fn default_timeout_ms() -> u64 {
+    1000
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PostRequest {
+    #[serde(default = "default_cycles")]
+    pub cycles: u16,
+    #[serde(default = "default_timeout_ms")]
+    pub timeout_ms: u64,
+}
+
+impl Default for PostRequest {
+    fn default() -> Self {
+        Self { cycles: default_cycles(), timeout_ms: default_timeout_ms() }
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PostResponse {
+    pub stepped: u16,
+    pub time: u16,
+    pub timed_out: bool,
+}
+
+async fn post(State(state): State<AppState>, req: Option<Json<PostRequest>>) -> Response {
+    let req = req.map(|Json(r)| r).unwrap_or_default();
+
+    let res = state.service.step(req.cycles, Duration::from_millis(req.timeout_ms)).await;
+    match res {
+        Ok(outcome) => Response::success(PostResponse {
+            stepped: outcome.stepped,
+            time: outcome.time,
+            timed_out: outcome.stepped < req.cycles,
+        }),
+        Err(e) => Error::from(e).into(),
+    }
+}
+
+pub fn route(path: &str) -> Router<AppState> {
+    Router::new().route(path, routing::post(post))
+}
@@ -1,19 +1,217 @@
+use std::net::{IpAddr, Ipv6Addr};
+
 use super::{AppState, Response};
 use axum::Router;
 use axum::extract::{Query, State};
-use serde::Deserialize;
+use axum::http::{HeaderMap, header};
+use common::command::trainer::{Look, TeamNames};
+use common::types::Side;
+use serde::{Deserialize, Serialize};
+use service::metrics::ServiceStatusInfo;
 use uuid::Uuid;
 
+/// Uniform numbers a team can take.
+const UNUMS: std::ops::RangeInclusive<u8> = 1..=11;
+
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct GetRequest {
+    /// Used in the WebSocket endpoint, a fresh one is made up if not given.
+    pub client_id: Option<Uuid>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct GetResponse {
     pub client_id: Uuid,
+    /// Public host to connect to, from the Agones status when running under Agones.
+    pub host: String,
+    pub endpoints: Endpoints,
+    pub teams: Teams,
+    pub protocol: ProtocolInfo,
+    pub status: ServiceStatusInfo,
 }
 
-async fn get(State(state): State<AppState>, Query(request): Query<GetRequest>) -> Response {
-    todo!()
+#[derive(Serialize, Debug)]
+pub struct Endpoints {
+    /// Base URL of this API.
+    pub http: String,
+    pub player: ClientEndpoint,
+    pub trainer: ClientEndpoint,
+    pub coach: ClientEndpoint,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ClientEndpoint {
+    /// Port of the UDP proxy on `host`.
+    pub udp: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ws: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct Teams {
+    pub left: TeamSlots,
+    pub right: TeamSlots,
+}
+
+#[derive(Serialize, Debug)]
+pub struct TeamSlots {
+    /// The declared team, otherwise the one which connected on this side.
+    pub name: Option<String>,
+    pub declared: bool,
+    /// `None` if rcssserver could not be asked.
+    pub free_unums: Option<Vec<u8>>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ProtocolInfo {
+    pub rcssserver: String,
+    /// rcssserver speaks client protocols up to its major version.
+    pub player: u16,
+    pub coach: u16,
+    /// The trainer proxy relays over the service's trainer, in its protocol.
+    pub trainer: u8,
+}
+
+/// Public host and ports, by the port names of the fleet template.
+struct PublicAddrs {
+    host: String,
+    http: u16,
+    player: u16,
+    trainer: u16,
+    coach: u16,
+}
+
+impl PublicAddrs {
+    fn of(state: &AppState, headers: &HeaderMap) -> Self {
+        let listen = state.listen;
+        let host = headers.get(header::HOST)
+            .and_then(|h| h.to_str().ok())
+            .and_then(host_of)
+            .unwrap_or_else(|| match listen.http.ip() {
+                ip if ip.is_unspecified() => IpAddr::from([127, 0, 0, 1]).to_string(),
+                ip => ip.to_string(),
+            });
+
+        Self {
+            host,
+            http: listen.http.port(),
+            player: listen.player.port(),
+            trainer: listen.trainer.port(),
+            coach: listen.coach.port(),
+        }
+    }
+
+    #[cfg(feature = "agones")]
+    async fn resolve(state: &AppState, headers: &HeaderMap) -> Self {
+        let mut ret = Self::of(state, headers);
+        if let Some(gs) = state.service.gameserver_address().await {
+            let port = |name: &str, default: u16| gs.ports.get(name).copied().unwrap_or(default);
+            ret.http = port("default", ret.http);
+            ret.player = port("player", ret.player);
+            ret.trainer = port("trainer", ret.trainer);
+            ret.coach = port("coach", ret.coach);
+            ret.host = gs.address;
+        }
+        ret
+    }
+
+    #[cfg(not(feature = "agones"))]
+    async fn resolve(state: &AppState, headers: &HeaderMap) -> Self {
+        Self::of(state, headers)
+    }
+
+    fn endpoints(&self, client_id: Uuid) -> Endpoints {
+        let authority = match self.host.parse::<Ipv6Addr>() {
+            Ok(_) => format!("[{}]:{}", self.host, self.http),
+            Err(_) => format!("{}:{}", self.host, self.http),
+        };
+        Endpoints {
+            http: format!("http://{authority}"),
+            player: ClientEndpoint { udp: self.player, ws: Some(format!("ws://{authority}/player/{client_id}")) },
+            trainer: ClientEndpoint { udp: self.trainer, ws: Some(format!("ws://{authority}/trainer")) },
+            coach: ClientEndpoint { udp: self.coach, ws: None },
+        }
+    }
+}
+
+/// Host of a `Host` header, without the port.
+fn host_of(value: &str) -> Option<String> {
+    let host = match value.strip_prefix('[') {
+        Some(v6) => v6.split_once(']')?.0,
+        None => value.split(':').next()?,
+    };
+    (!host.is_empty()).then(|| host.to_string())
+}
+
+async fn teams(state: &AppState) -> Teams {
+    let (declared_l, declared_r) = state.service.declared_teams();
+    let live = state.service.send_trainer_command(TeamNames).await.ok().and_then(Result::ok);
+    let world = state.service.send_trainer_command(Look).await.ok().and_then(Result::ok);
+    let (live_l, live_r) = live.map(|t| (t.left, t.right)).unwrap_or_default();
+
+    let world = world.map(|mut w| {
+        w.resolve_sides(live_l.as_deref(), live_r.as_deref());
+        w
+    });
+    let slots = |side: Side, declared: Option<&str>, live: Option<String>| TeamSlots {
+        name: declared.map(str::to_string).or(live),
+        declared: declared.is_some(),
+        free_unums: world.as_ref()
+            .map(|w| UNUMS.filter(|unum| w.player(side, *unum).is_none()).collect()),
+    };
+
+    Teams {
+        left: slots(Side::LEFT, declared_l, live_l),
+        right: slots(Side::RIGHT, declared_r, live_r),
+    }
+}
+
+async fn get(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(request): Query<GetRequest>,
+) -> Response {
+    let client_id = request.client_id.unwrap_or_else(Uuid::now_v7);
+    let addrs = PublicAddrs::resolve(&state, &headers).await;
+    let version = state.service.rcss_version();
+
+    Response::success(GetResponse {
+        client_id,
+        endpoints: addrs.endpoints(client_id),
+        host: addrs.host,
+        teams: teams(&state).await,
+        protocol: ProtocolInfo {
+            rcssserver: version.to_string(),
+            player: version.major,
+            coach: version.major,
+            trainer: service::TRAINER_PROTOCOL_VERSION,
+        },
+        status: state.service.status_info().await,
+    })
 }
 
 pub fn route(path: &str) -> Router<AppState> {
     Router::new().route(path, axum::routing::get(get))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_public_endpoints() {
+        assert_eq!(host_of("example.org:6666").as_deref(), Some("example.org"));
+        assert_eq!(host_of("10.0.0.7").as_deref(), Some("10.0.0.7"));
+        assert_eq!(host_of("[::1]:6666").as_deref(), Some("::1"));
+        assert_eq!(host_of(":6666"), None);
+
+        let id = Uuid::nil();
+        let addrs = PublicAddrs { host: "::1".to_string(), http: 7000, player: 7001, trainer: 7002, coach: 7003 };
+        let endpoints = addrs.endpoints(id);
+        assert_eq!(endpoints.http, "http://[::1]:7000");
+        assert_eq!(endpoints.player.ws.as_deref(), Some(format!("ws://[::1]:7000/player/{id}").as_str()));
+        assert_eq!(endpoints.trainer.ws.as_deref(), Some("ws://[::1]:7000/trainer"));
+        assert_eq!((endpoints.trainer.udp, endpoints.coach.udp), (7002, 7003));
+    }
+}
@@ -2,16 +2,21 @@ use axum::extract::State;
 use axum::{Router, routing};
 use serde::Serialize;
 
+use service::metrics::ConfigInfo;
 
 use super::{AppState, Response};
 
 #[derive(Serialize, Debug)]
 pub struct GetResponse {
-    
+    pub service: ConfigInfo,
+    pub server: serde_json::Value,
 }
 
 async fn get(State(state): State<AppState>) -> Response {
-    todo!()
+    Response::success(GetResponse {
+        service: state.service.config_info().await,
+        server: state.args.as_ref().clone(),
+    })
 }
 
 pub fn route(path: &str) -> Router<AppState> {
@@ -0,0 +1,48 @@
+use axum::extract::{Path, Query, State};
+use axum::{Router, routing};
+use serde::{Deserialize, Serialize};
+
+use service::MatchRecord;
+
+use super::{AppState, Response};
+
+fn default_limit() -> usize {
+    50
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ListRequest {
+    #[serde(default)]
+    pub offset: usize,
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ListResponse {
+    pub total: usize,
+    /// Newest first.
+    pub matches: Vec<MatchRecord>,
+}
+
+async fn list(State(state): State<AppState>, Query(req): Query<ListRequest>) -> Response {
+    let history = state.service.history();
+    Response::success(ListResponse {
+        total: history.len(),
+        matches: history.list(req.offset, req.limit),
+    })
+}
+
+async fn get(State(state): State<AppState>, Path(generation): Path<u64>) -> Response {
+    match state.service.history().get(generation) {
+        Some(record) => Response::success(record),
+        None => Response::error("MatchNotFound", &format!("No match of generation {generation} in the history.")),
+    }
+}
+
+pub fn route(path: &str) -> Router<AppState> {
+    Router::new()
+        .route(path, routing::get(list))
+        .route(&format!("{path}/{{generation}}"), routing::get(get))
+}
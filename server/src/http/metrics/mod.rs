@@ -1,6 +1,7 @@
 mod status;
 mod conn;
 mod config;
+mod history;
 
 use super::{AppState, Response};
 use axum::Router;
@@ -12,7 +13,8 @@ pub fn route(path: &str) -> Router<AppState> {
         .merge(status::route("/health"))
         .merge(status::route("/status"))
         .merge(config::route("/config"))
-        .merge(conn::route("/conn"));
+        .merge(conn::route("/conn"))
+        .merge(history::route("/history"));
 
     if path == "/" {
         inner
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use axum::extract::State;
 use axum::{Router, routing};
 use serde::Serialize;
@@ -10,6 +12,7 @@ use super::{AppState, Response};
 pub struct GetResponse {
     pub service: ServiceStatusInfo,
     pub conn_count: usize,
+    pub blocked_actions: BTreeMap<String, u64>,
     #[cfg(feature = "agones")]
     pub agones: service::metrics::AgonesRuntimeInfo,
 }
@@ -21,6 +24,7 @@ async fn get(State(state): State<AppState>) -> Response {
     Response::success(GetResponse {
         service,
         conn_count: conn.len(),
+        blocked_actions: state.blocked.snapshot(),
         #[cfg(feature = "agones")]
         agones: state.service.agones_runtime_info().await,
     })
@@ -11,6 +11,7 @@ use std::pin::Pin;
 use std::sync::Arc;
 use axum::Router;
 use clap::Parser;
+use serde::Serialize;
 use log::{debug, error, info, warn};
 use tokio::net::{TcpListener, ToSocketAddrs};
 use tokio::sync::oneshot;
@@ -21,8 +22,11 @@ use service::Service;
 use common::axum::response;
 use common::utils::logging::{LoggingArgs, init_stdout_logger, init_dual_logger};
 
+use common::client::Kind as ClientKind;
+
+use crate::proxy::trainer::TrainerProxy;
 use crate::proxy::udp::UdpProxy;
-use crate::state::AppState;
+use crate::state::{AppState, ListenAddrs};
 
 pub const PEER_IP: IpAddr = IpAddr::V4(std::net::Ipv4Addr::LOCALHOST);
 
@@ -59,7 +63,7 @@ fn init_logging(
     Ok(ret)
 }
 
-#[derive(Parser, Debug)]
+#[derive(Parser, Serialize, Debug)]
 #[clap(author = "EnricLiu")]
 struct Args {
     #[clap(long, default_value = "0.0.0.0", env = "SERVER_HOST", help = "Server IP to bind")]
@@ -81,6 +85,7 @@ struct Args {
     log_args: LoggingArgs,
 
     #[clap(flatten)]
+    #[serde(skip)] // reported by the service
     service_args: service::Args,
 }
 
@@ -109,32 +114,56 @@ fn route(state: AppState) -> Router {
         .route_layer(TraceLayer::new_for_http())
 }
 
+fn spawn_udp_proxy(state: AppState, addr: SocketAddr, kind: ClientKind) {
+    tokio::spawn(async move {
+        match UdpProxy::new(state, addr, kind).await {
+            Ok(proxy) => {
+                 info!("[UDP Proxy({kind:?})] Started on {}", addr);
+                 proxy.run().await;
+            },
+            Err(e) => {
+                error!("[UDP Proxy({kind:?})] Failed to start on {}: {}", addr, e);
+            }
+        }
+    });
+}
+
 pub async fn listen(
     addr: impl ToSocketAddrs,
     player_prox_udp_addr: impl Into<SocketAddr>,
+    trainer_prox_udp_addr: impl Into<SocketAddr>,
+    coach_prox_udp_addr: impl Into<SocketAddr>,
+    server_args: serde_json::Value,
     service: Service,
     shutdown: Option<impl Future<Output=()> + Send + 'static>
 ) -> JoinHandle<Result<(), String>> {
+    let listener = TcpListener::bind(addr).await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let listen_addrs = ListenAddrs {
+        http: addr,
+        player: player_prox_udp_addr.into(),
+        trainer: trainer_prox_udp_addr.into(),
+        coach: coach_prox_udp_addr.into(),
+    };
+
     let (shutdown_tx, shutdown_rx) = oneshot::channel();
-    let state = AppState::new(service, Some(shutdown_rx));
+    let state = AppState::new(service, listen_addrs, server_args, Some(shutdown_rx));
 
     state.service.spawn().await.expect("FATAL: Service failed to start");
 
-    let listener = TcpListener::bind(addr).await.unwrap();
-    let addr = listener.local_addr().unwrap();
+    spawn_udp_proxy(state.clone(), listen_addrs.player, ClientKind::Player);
+    spawn_udp_proxy(state.clone(), listen_addrs.coach, ClientKind::OlCoach);
 
     let _state = state.clone();
-
-    let player_prox_udp_addr = player_prox_udp_addr.into();
     tokio::spawn(async move {
-        let addr = player_prox_udp_addr;
-        match UdpProxy::new(_state, addr).await {
+        let addr = listen_addrs.trainer;
+        match TrainerProxy::new(_state, addr).await {
             Ok(proxy) => {
-                 info!("[UDP Proxy(Player)] Started on {}", addr);
+                 info!("[Trainer Proxy] Started on {}", addr);
                  proxy.run().await;
             },
             Err(e) => {
-                error!("[UDP Proxy(Player)] Failed to start on {}: {}", addr, e);
+                error!("[Trainer Proxy] Failed to start on {}: {}", addr, e);
             }
         }
     });
@@ -171,9 +200,12 @@ pub async fn listen(
 #[tokio::main]
 async fn main() {
     let args = Args::parse();
+    let server_args = serde_json::to_value(&args).expect("server args are plain values");
 
     let listen_addr = args.listen_addr();
     let player_udp_listen_addr = args.player_udp_listen_addr();
+    let trainer_udp_listen_addr = args.trainer_udp_listen_addr();
+    let coach_udp_listen_addr = args.coach_udp_listen_addr();
 
     let log_root = init_logging("info", &args.log_args, args.stdio_log_path).unwrap()
         .unwrap_or(env::current_dir().unwrap());
@@ -187,6 +219,14 @@ async fn main() {
     };
 
     let shutdown_signal = Some(service.shutdown_signal());
-    let app = listen(listen_addr, player_udp_listen_addr, service, shutdown_signal).await;
+    let app = listen(
+        listen_addr,
+        player_udp_listen_addr,
+        trainer_udp_listen_addr,
+        coach_udp_listen_addr,
+        server_args,
+        service,
+        shutdown_signal,
+    ).await;
     app.await.unwrap().unwrap();
 }
@@ -0,0 +1,286 @@
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use dashmap::DashMap;
+use log::info;
+
+use common::command::CommandAny;
+use common::command::player::PlayerCommand;
+use common::types::Side;
+use service::Blocklist;
+
+/// Rejections per `"{side}.{unum}.{command}"`, shared by every proxy session.
+#[derive(Default, Debug)]
+pub struct BlockedActions {
+    counts: DashMap<String, u64>,
+}
+
+impl BlockedActions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn record(&self, side: Side, unum: u8, command: &PlayerCommand) {
+        let key = format!("{}.{}.{}", side.encode(), unum, command.encode());
+        *self.counts.entry(key).or_default() += 1;
+    }
+
+    pub fn snapshot(&self) -> BTreeMap<String, u64> {
+        self.counts.iter().map(|en| (en.key().clone(), *en.value())).collect()
+    }
+}
+
+/// Per-session filter enforcing the player blocklist.
+///
+/// The player is unknown until rcssserver answers its `init`/`reconnect`,
+/// so everything passes through until [`PlayerGuard::observe`] has seen the reply.
+#[derive(Debug)]
+pub struct PlayerGuard {
+    blocklist: Blocklist,
+    player: OnceLock<(Side, u8)>,
+    blocked: Arc<BlockedActions>,
+}
+
+pub struct Filtered<'a> {
+    /// Commands to forward upstream, empty when everything was blocked.
+    pub pass: Cow<'a, str>,
+    /// Replies to send back downstream, one per blocked command.
+    pub rejections: Vec<String>,
+}
+
+impl PlayerGuard {
+    pub fn new(blocklist: Blocklist, blocked: Arc<BlockedActions>) -> Self {
+        Self { blocklist, player: OnceLock::new(), blocked }
+    }
+
+    pub fn player(&self) -> Option<(Side, u8)> {
+        self.player.get().copied()
+    }
+
+    /// Learn the session's player from a server message, if it is the init reply.
+    pub fn observe(&self, msg: &str) {
+        if self.player.get().is_some() || self.blocklist.is_empty() {
+            return;
+        }
+
+        if let Some(player) = parse_init_reply(msg) {
+            info!("[PlayerGuard] Session identified as {}.{}", player.0.encode(), player.1);
+            self.player.set(player).ok();
+        }
+    }
+
+    /// Drop the blocked commands out of a client message.
+    pub fn filter<'a>(&self, msg: &'a str) -> Filtered<'a> {
+        let passthrough = Filtered { pass: Cow::Borrowed(msg), rejections: vec![] };
+
+        let Some((side, unum)) = self.player() else { return passthrough };
+        let Some(player) = self.blocklist.get(side, unum).filter(|p| !p.is_empty()) else {
+            return passthrough
+        };
+
+        let mut pass = String::with_capacity(msg.len());
+        let mut rejections = vec![];
+        for command in split_commands(msg) {
+            match command_head(command).and_then(PlayerCommand::decode) {
+                Some(kind) if player.blocks(&kind) => {
+                    self.blocked.record(side, unum, &kind);
+                    rejections.push(format!("(error blocked_action {})", kind.encode()));
+                },
+                _ => pass.push_str(command),
+            }
+        }
+
+        if rejections.is_empty() {
+            return passthrough;
+        }
+        Filtered { pass: Cow::Owned(pass), rejections }
+    }
+}
+
+/// Sides taken by online coaches, rcssserver lets one coach in per side.
+///
+/// A side is taken once rcssserver answers the coach's `init`, another coach of the same
+/// team is then turned away before reaching the server.
+#[derive(Default, Debug)]
+pub struct CoachSeats {
+    inner: Mutex<CoachSeatsInner>,
+}
+
+#[derive(Default, Debug)]
+struct CoachSeatsInner {
+    /// Left and right.
+    seats: [Option<(SocketAddr, String)>; 2],
+    /// Team names of the `init`s waiting for their reply.
+    pending: HashMap<SocketAddr, String>,
+}
+
+impl CoachSeats {
+    pub const REJECTION: &'static str = "(error no_such_team_or_already_have_coach)";
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, CoachSeatsInner> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Whether a message from `addr` may go upstream, `false` for an `init` of a team
+    /// which already has its coach elsewhere.
+    pub fn admit(&self, addr: SocketAddr, msg: &str) -> bool {
+        let Some(team) = parse_coach_init(msg) else { return true };
+        let mut inner = self.lock();
+        if inner.seats.iter().flatten().any(|(seat, seated)| *seat != addr && seated == team) {
+            return false;
+        }
+        inner.pending.insert(addr, team.to_string());
+        true
+    }
+
+    /// Seat the coach at `addr`, if the server message is the reply to its `init`.
+    pub fn observe(&self, addr: SocketAddr, msg: &str) {
+        let Some(side) = parse_coach_init_reply(msg) else { return };
+        let mut inner = self.lock();
+        let Some(team) = inner.pending.remove(&addr) else { return };
+        info!("[CoachSeats] {addr} seated as the coach of {team} ({})", side.encode());
+        let idx = if side == Side::LEFT { 0 } else { 1 };
+        inner.seats[idx] = Some((addr, team));
+    }
+
+    pub fn release(&self, addr: SocketAddr) {
+        let mut inner = self.lock();
+        inner.pending.remove(&addr);
+        for seat in &mut inner.seats {
+            if seat.as_ref().is_some_and(|(seated, _)| *seated == addr) {
+                info!("[CoachSeats] {addr} left its seat");
+                *seat = None;
+            }
+        }
+    }
+}
+
+/// `(init TEAM_NAME (version 15))`
+fn parse_coach_init(msg: &str) -> Option<&str> {
+    let inner = msg.trim().trim_end_matches('\0').strip_prefix("(init")?;
+    inner.strip_prefix(char::is_whitespace)?
+        .split(|c: char| c.is_whitespace() || c == '(' || c == ')')
+        .find(|team| !team.is_empty())
+}
+
+/// `(init l ok)`
+fn parse_coach_init_reply(msg: &str) -> Option<Side> {
+    let inner = msg.trim().trim_end_matches('\0').strip_prefix('(')?.strip_suffix(')')?;
+    match *inner.split_whitespace().collect::<Vec<_>>().as_slice() {
+        ["init", side, "ok"] => Side::decode(side).filter(|side| *side != Side::NEUTRAL),
+        _ => None,
+    }
+}
+
+/// `(init l 2 before_kick_off)` or `(reconnect l 2 play_on)`.
+fn parse_init_reply(msg: &str) -> Option<(Side, u8)> {
+    let inner = msg.trim().trim_end_matches('\0').strip_prefix('(')?.strip_suffix(')')?;
+    let mut tokens = inner.split_whitespace();
+    match tokens.next()? {
+        "init" | "reconnect" => {},
+        _ => return None,
+    }
+    let side = Side::decode(tokens.next()?)?;
+    let unum = tokens.next()?.parse().ok()?;
+    (side != Side::NEUTRAL).then_some((side, unum))
+}
+
+/// Top-level `(...)` commands of a message, quoted strings kept intact.
+fn split_commands(msg: &str) -> Vec<&str> {
+    let mut ret = vec![];
+    let mut depth = 0usize;
+    let mut quoted = false;
+    let mut start = 0;
+
+    for (i, c) in msg.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            '(' if !quoted => {
+                if depth == 0 { start = i; }
+                depth += 1;
+            },
+            ')' if !quoted && depth > 0 => {
+                depth -= 1;
+                if depth == 0 { ret.push(&msg[start..=i]); }
+            },
+            _ => {},
+        }
+    }
+    // unbalanced tail is left for rcssserver to complain about
+    if depth > 0 {
+        ret.push(&msg[start..]);
+    }
+    ret
+}
+
+fn command_head(command: &str) -> Option<&str> {
+    command.strip_prefix('(')?
+        .split(|c: char| c.is_whitespace() || c == '(' || c == ')')
+        .next()
+        .filter(|head| !head.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guard() -> PlayerGuard {
+        let blocklist = Blocklist::parse_arg(r#"{"left":{"1":{"catch":true}}}"#).unwrap();
+        PlayerGuard::new(blocklist, Arc::new(BlockedActions::new()))
+    }
+
+    #[test]
+    fn test_identify_from_init_reply() {
+        assert_eq!(parse_init_reply("(init l 1 before_kick_off)"), Some((Side::LEFT, 1)));
+        assert_eq!(parse_init_reply("(reconnect r 11 play_on)\0"), Some((Side::RIGHT, 11)));
+        assert_eq!(parse_init_reply("(init ok)"), None);
+        assert_eq!(parse_init_reply("(see 0 ((b) 10 0))"), None);
+    }
+
+    #[test]
+    fn test_filter_blocked_commands() {
+        let guard = guard();
+        // unidentified sessions pass through
+        assert_eq!(guard.filter("(catch 0)").pass, "(catch 0)");
+
+        guard.observe("(init l 1 before_kick_off)");
+        let filtered = guard.filter("(dash 100)(catch 0)(say \"(catch)\")");
+        assert_eq!(filtered.pass, "(dash 100)(say \"(catch)\")");
+        assert_eq!(filtered.rejections, vec!["(error blocked_action catch)"]);
+
+        let filtered = guard.filter("(catch -30)");
+        assert!(filtered.pass.is_empty());
+        assert_eq!(guard.blocked.snapshot().get("l.1.catch"), Some(&2));
+    }
+
+    #[test]
+    fn test_other_players_untouched() {
+        let guard = guard();
+        guard.observe("(init r 1 before_kick_off)");
+        let filtered = guard.filter("(catch 0)");
+        assert!(matches!(filtered.pass, Cow::Borrowed("(catch 0)")));
+        assert!(filtered.rejections.is_empty());
+    }
+
+    #[test]
+    fn test_one_coach_per_side() {
+        let seats = CoachSeats::new();
+        let (first, second): (SocketAddr, SocketAddr) = ("127.0.0.1:5000".parse().unwrap(), "127.0.0.1:5001".parse().unwrap());
+        assert!(seats.admit(first, "(init HELIOS (version 15))"));
+        // no seat is taken before the server agreed
+        assert!(seats.admit(second, "(init HELIOS (version 15))"));
+        seats.observe(first, "(init l ok)");
+        assert!(!seats.admit(second, "(init HELIOS (version 15))"));
+        assert!(seats.admit(second, "(say (freeform \"hi\"))"));
+        assert!(seats.admit(second, "(init Cyrus)"));
+
+        seats.release(first);
+        assert!(seats.admit(second, "(init HELIOS (version 15))"));
+    }
+}
@@ -6,7 +6,7 @@ use dashmap::DashMap;
 use log::{debug, info};
 use uuid::Uuid;
 
-use common::client::{Client, Info as ClientInfo, Config as ClientConfig};
+use common::client::{Client, Info as ClientInfo, Config as ClientConfig, Kind as ClientKind};
 
 #[derive(Clone, Default)]
 pub struct SessionManager {
@@ -25,6 +25,7 @@ impl SessionManager {
         &self,
         id: Uuid,
         name: Option<String>,
+        kind: ClientKind,
         server_addr: SocketAddr,
     ) -> Arc<Client> {
         // Try to find existing
@@ -38,7 +39,7 @@ impl SessionManager {
         let client_config = {
             let mut builder = ClientConfig::builder();
             builder.name = name;
-            builder.with_peer(server_addr);
+            builder.with_kind(kind).with_peer(server_addr);
             builder.build_into()
         };
 
@@ -1,3 +1,5 @@
 pub mod manager;
 pub mod ws;
 pub mod udp;
+pub mod trainer;
+pub mod guard;
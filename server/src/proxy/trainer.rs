@@ -0,0 +1,234 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use log::{debug, error, info, warn};
+use tokio::net::UdpSocket;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use common::command::CommandAny;
+use common::command::trainer::TrainerCommand;
+
+use crate::state::{AppState, AppStateStatus};
+
+// Timeout for an inactive external trainer
+const LEASE_TIMEOUT: Duration = Duration::from_secs(60);
+const CLEANUP_INTERVAL: Duration = Duration::from_secs(10);
+
+/// What to do with a message from the external trainer.
+#[derive(Debug, PartialEq, Eq)]
+enum TrainerAction {
+    /// Answer locally, the service's trainer is initialised already.
+    Reply(&'static str),
+    /// Give up the lease, the service's trainer stays connected.
+    Bye,
+    /// Steers the match, only relayed before kick-off.
+    Control(TrainerCommand),
+    Relay(TrainerCommand),
+}
+
+impl TrainerAction {
+    fn of(msg: &str) -> Self {
+        let msg = msg.trim().trim_end_matches('\0');
+        let Some(inner) = msg.strip_prefix('(').and_then(|m| m.strip_suffix(')')) else {
+            return TrainerAction::Reply("(error illegal_command_form)");
+        };
+        // the reply has to be told apart from those to the service's own calls
+        let kind = inner.split([' ', '(', ')']).next().and_then(TrainerCommand::decode);
+        match *inner.split_whitespace().collect::<Vec<_>>().as_slice() {
+            ["init", ..] => TrainerAction::Reply("(init ok)"),
+            ["bye"] => TrainerAction::Bye,
+            // the service follows the match through the referee and see_global
+            ["ear", "off"] => TrainerAction::Reply("(error ear_reserved_by_service)"),
+            ["eye", "off"] => TrainerAction::Reply("(error eye_reserved_by_service)"),
+            // in sync mode the service steps the simulation itself
+            ["done"] => TrainerAction::Reply("(error done_reserved_by_service)"),
+            _ => match kind {
+                Some(kind @ (TrainerCommand::Start | TrainerCommand::ChangeMode | TrainerCommand::Recover)) => {
+                    TrainerAction::Control(kind)
+                },
+                Some(kind) => TrainerAction::Relay(kind),
+                None => TrainerAction::Reply("(error unknown_command)"),
+            },
+        }
+    }
+}
+
+struct Lease {
+    addr: SocketAddr,
+    /// Match generation the forwarding is subscribed to.
+    generation: Option<u64>,
+    last_active: Instant,
+    forward_task: Option<JoinHandle<()>>,
+}
+
+impl Drop for Lease {
+    fn drop(&mut self) {
+        if let Some(task) = self.forward_task.take() {
+            task.abort();
+        }
+    }
+}
+
+/// Lets one external trainer share the service's own trainer connection.
+///
+/// rcssserver accepts a single trainer, which the service holds for the whole match.
+/// The first address to send something leases it, its messages are relayed over the
+/// service's connection and everything the server sends the trainer is copied back.
+/// Other addresses are turned away until the lease is given up with `(bye)` or times out.
+/// Once the ball is kicked off the match belongs to the referee, `start`, `change_mode`
+/// and `recover` are refused from then on.
+pub struct TrainerProxy {
+    socket: Arc<UdpSocket>,
+    lease: Option<Lease>,
+    state: AppState,
+}
+
+impl TrainerProxy {
+    pub const REJECTION: &'static str = "(error already_have_trainer)";
+    pub const KICKED_OFF: &'static str = "(error match_already_kicked_off)";
+
+    pub async fn new(state: AppState, listen_addr: SocketAddr) -> std::io::Result<Self> {
+        let socket = Arc::new(UdpSocket::bind(listen_addr).await?);
+        info!("[Trainer Proxy] Listening on {listen_addr}");
+        Ok(Self { socket, lease: None, state })
+    }
+
+    pub async fn run(mut self) {
+        let mut buf = [0u8; 4096];
+        let mut cleanup = tokio::time::interval(CLEANUP_INTERVAL);
+        loop {
+            tokio::select! {
+                _ = self.state.status_rx.changed() => {
+                    match *self.state.status_rx.borrow() {
+                        AppStateStatus::ShuttingDown|AppStateStatus::Stopped => {
+                            info!("[Trainer Proxy] Shutting down trainer proxy...");
+                            break;
+                        }
+                        _ => continue
+                    }
+                }
+
+                _ = cleanup.tick() => {
+                    if let Some(lease) = self.lease.take_if(|l| l.last_active.elapsed() > LEASE_TIMEOUT) {
+                        info!("[Trainer Proxy] Lease of {} timed out", lease.addr);
+                    }
+                }
+
+                res = self.socket.recv_from(&mut buf) => {
+                    let (len, addr) = match res {
+                        Ok(v) => v,
+                        Err(e) => {
+                            error!("[Trainer Proxy] Recv error: {}", e);
+                            continue;
+                        }
+                    };
+                    let Ok(data_str) = std::str::from_utf8(&buf[..len]) else {
+                        warn!("[Trainer Proxy] Received non-UTF8 data from {}, ignoring.", addr);
+                        continue;
+                    };
+                    self.handle(addr, data_str).await;
+                }
+            }
+        }
+    }
+
+    async fn handle(&mut self, addr: SocketAddr, msg: &str) {
+        match &self.lease {
+            Some(lease) if lease.addr != addr => {
+                info!("[Trainer Proxy] {} turned away, {} holds the trainer", addr, lease.addr);
+                self.reply(addr, Self::REJECTION).await;
+                return;
+            },
+            Some(_) => {},
+            None => {
+                info!("[Trainer Proxy] {} leased the trainer", addr);
+                self.lease = Some(Lease { addr, generation: None, last_active: Instant::now(), forward_task: None });
+            },
+        }
+        let lease = self.lease.as_mut().expect("leased above");
+        lease.last_active = Instant::now();
+
+        // a restart replaced the service's trainer, follow the new one
+        let generation = self.state.service.generation();
+        if lease.forward_task.is_none() || lease.generation != generation {
+            let (tx, mut rx) = mpsc::channel(32);
+            match self.state.service.subscribe_trainer(tx).await {
+                Ok(()) => {
+                    let socket = self.socket.clone();
+                    let task = tokio::spawn(async move {
+                        while let Some(msg) = rx.recv().await {
+                            if let Err(e) = socket.send_to(msg.as_bytes(), addr).await {
+                                info!("[Trainer Proxy] Failed to send data downstream to {}: {}, ignoring", addr, e);
+                            }
+                        }
+                    });
+                    if let Some(old) = lease.forward_task.replace(task) {
+                        old.abort();
+                    }
+                    lease.generation = generation;
+                },
+                Err(e) => warn!("[Trainer Proxy] Failed to follow the service's trainer: {}", e),
+            }
+        }
+
+        match TrainerAction::of(msg) {
+            TrainerAction::Reply(reply) => self.reply(addr, reply).await,
+            TrainerAction::Bye => {
+                info!("[Trainer Proxy] {} gave up the trainer", addr);
+                self.lease = None;
+            },
+            TrainerAction::Control(_) if self.kicked_off().await => {
+                info!("[Trainer Proxy] Refused {} from {} after kick-off", msg.trim(), addr);
+                self.reply(addr, Self::KICKED_OFF).await;
+            },
+            TrainerAction::Control(kind) | TrainerAction::Relay(kind) => {
+                // the reply reaches the lease through the forwarding, don't hold up the others meanwhile
+                let (state, socket, msg) = (self.state.clone(), self.socket.clone(), msg.to_string());
+                tokio::spawn(async move {
+                    match state.service.relay_trainer(kind, msg.into()).await {
+                        Ok(Ok(())) => {},
+                        Ok(Err(reason)) => debug!("[Trainer Proxy] Relayed {} failed: {}", kind.encode(), reason),
+                        Err(e) => {
+                            warn!("[Trainer Proxy] Failed to relay for {}: {}", addr, e);
+                            if let Err(e) = socket.send_to(b"(error server_not_running)", addr).await {
+                                info!("[Trainer Proxy] Failed to send reply downstream to {}: {}, ignoring", addr, e);
+                            }
+                        },
+                    }
+                });
+            },
+        }
+    }
+
+    async fn kicked_off(&self) -> bool {
+        self.state.service.time_now().await.is_some_and(|time| time > 0)
+    }
+
+    async fn reply(&self, addr: SocketAddr, msg: &str) {
+        if let Err(e) = self.socket.send_to(msg.as_bytes(), addr).await {
+            info!("[Trainer Proxy] Failed to send reply downstream to {}: {}, ignoring", addr, e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trainer_actions() {
+        assert_eq!(TrainerAction::of("(init (version 5))"), TrainerAction::Reply("(init ok)"));
+        assert_eq!(TrainerAction::of("(bye)\0"), TrainerAction::Bye);
+        assert_eq!(TrainerAction::of("(ear off)"), TrainerAction::Reply("(error ear_reserved_by_service)"));
+        assert_eq!(TrainerAction::of("(ear on)"), TrainerAction::Relay(TrainerCommand::Ear));
+        assert_eq!(TrainerAction::of("(move (player HELIOS 1) 0 0)"), TrainerAction::Relay(TrainerCommand::Move));
+        assert_eq!(TrainerAction::of("(team_names)"), TrainerAction::Relay(TrainerCommand::TeamNames));
+        assert_eq!(TrainerAction::of("(dance)"), TrainerAction::Reply("(error unknown_command)"));
+        assert_eq!(TrainerAction::of("(done)"), TrainerAction::Reply("(error done_reserved_by_service)"));
+        assert_eq!(TrainerAction::of("(start)"), TrainerAction::Control(TrainerCommand::Start));
+        assert_eq!(TrainerAction::of("(recover)"), TrainerAction::Control(TrainerCommand::Recover));
+        assert_eq!(TrainerAction::of("(change_mode play_on)"), TrainerAction::Control(TrainerCommand::ChangeMode));
+    }
+}
@@ -9,7 +9,8 @@ use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 use uuid::Uuid;
 
-use common::client::{Client, Error as ClientError};
+use common::client::{Client, Error as ClientError, Kind as ClientKind};
+use crate::proxy::guard::{CoachSeats, Filtered, PlayerGuard};
 use crate::state::{AppState, AppStateStatus};
 use crate::PEER_IP;
 
@@ -19,10 +20,14 @@ const SESSION_TIMEOUT: Duration = Duration::from_secs(60);
 const CLEANUP_INTERVAL: Duration = Duration::from_secs(10);
 // Default backend port (UDP server port)
 pub const DEFAULT_SERVER_UDP_PORT: u16 = 6000;
+// Default backend port for online coaches
+pub const DEFAULT_OLCOACH_UDP_PORT: u16 = 6002;
 
 struct SessionInfo {
     uuid: Uuid,
     client: Arc<Client>,
+    /// Players only.
+    guard: Option<Arc<PlayerGuard>>,
     last_active: Instant,
     forward_task: JoinHandle<()>,
 }
@@ -33,24 +38,31 @@ impl Drop for SessionInfo {
     }
 }
 
+/// Proxies players or online coaches, one upstream client per downstream address.
 pub struct UdpProxy {
+    kind: ClientKind,
     socket: Arc<UdpSocket>,
     sessions: Arc<DashMap<SocketAddr, SessionInfo>>,
+    /// Online coaches only.
+    seats: Arc<CoachSeats>,
     cleanup_task: JoinHandle<()>,
     state: AppState,
 }
 
 impl UdpProxy {
-    pub async fn new(state: AppState, listen_addr: SocketAddr) -> std::io::Result<Self> {
+    pub async fn new(state: AppState, listen_addr: SocketAddr, kind: ClientKind) -> std::io::Result<Self> {
+        assert_ne!(kind, ClientKind::Trainer, "the trainer is relayed by TrainerProxy");
         let socket = UdpSocket::bind(listen_addr).await?;
         let socket = Arc::new(socket);
         let sessions =
             Arc::new(DashMap::<SocketAddr, SessionInfo>::new());
+        let seats = Arc::new(CoachSeats::new());
 
-        info!("[UDP Proxy] Listening on {listen_addr}");
+        info!("[UDP Proxy] Listening on {listen_addr} for {kind:?}");
 
         // Start cleanup task
         let sessions_clone = sessions.clone();
+        let seats_clone = seats.clone();
         let state_clone = state.clone();
 
         let cleanup_task = tokio::spawn(async move {
@@ -70,19 +82,39 @@ impl UdpProxy {
                     if let Some((_, session)) = sessions_clone.remove(&key) {
                          info!("[UDP Proxy] Session timeout for {}, UUID: {}", key, session.uuid);
                          state_clone.session.remove(&session.uuid);
+                         seats_clone.release(key);
                     }
                 }
             }
         });
 
         Ok(Self {
+            kind,
             socket,
             sessions,
+            seats,
             cleanup_task,
             state,
         })
     }
 
+    fn server_addr(&self) -> SocketAddr {
+        let config = &self.state.service.config().server;
+        let port = match self.kind {
+            ClientKind::OlCoach => config.olcoach_port.unwrap_or(DEFAULT_OLCOACH_UDP_PORT),
+            _ => config.port.unwrap_or(DEFAULT_SERVER_UDP_PORT),
+        };
+        SocketAddr::new(PEER_IP, port)
+    }
+
+    fn remove_session(&self, addr: SocketAddr) {
+        if let Some((_, session)) = self.sessions.remove(&addr) {
+            info!("[UDP Proxy] Session closed for {}, UUID: {}", addr, session.uuid);
+            self.state.session.remove(&session.uuid);
+        }
+        self.seats.release(addr);
+    }
+
     pub async fn run(mut self) {
         let mut buf = [0u8; 4096];
         loop {
@@ -116,13 +148,20 @@ impl UdpProxy {
                         }
                     };
 
+                    if self.kind == ClientKind::OlCoach && !self.seats.admit(addr, data_str) {
+                        info!("[UDP Proxy] Coach at {} turned away, its team already has one", addr);
+                        if let Err(e) = self.socket.send_to(CoachSeats::REJECTION.as_bytes(), addr).await {
+                            info!("[UDP Proxy] Failed to send rejection downstream to {}: {}, ignoring", addr, e);
+                        }
+                        continue;
+                    }
+
                     if !self.sessions.contains_key(&addr) {
                         let uuid = Uuid::now_v7();
-                        let server_port = self.state.service.config().server.port.unwrap_or(DEFAULT_SERVER_UDP_PORT);
-                        let server_addr = SocketAddr::new(PEER_IP, server_port);
+                        let server_addr = self.server_addr();
 
                         let name = Some(format!("udp-{}", addr));
-                        let client = self.state.session.get_or_create(uuid, name, server_addr);
+                        let client = self.state.session.get_or_create(uuid, name, self.kind, server_addr);
 
                         let connect_result = client.connect().await;
                         match connect_result {
@@ -137,9 +176,16 @@ impl UdpProxy {
                         let (tx, mut rx) = mpsc::channel(32);
                         let _sub_id = client.subscribe(tx);
 
+                        let guard = (self.kind == ClientKind::Player).then(|| Arc::new(self.state.player_guard()));
+                        let guard_clone = guard.clone();
+                        let seats_clone = self.seats.clone();
                         let socket_clone = self.socket.clone();
                         let forward_task = tokio::spawn(async move {
                             while let Some(msg) = rx.recv().await {
+                                match &guard_clone {
+                                    Some(guard) => guard.observe(&msg),
+                                    None => seats_clone.observe(addr, &msg),
+                                }
                                 let bytes = msg.as_bytes();
                                 if let Err(_e) = socket_clone.send_to(bytes, addr).await {
                                      info!("[UDP Proxy] Failed to send data downstream to {}: {}, ignoring", addr, _e);
@@ -150,6 +196,7 @@ impl UdpProxy {
                         self.sessions.insert(addr, SessionInfo {
                             uuid,
                             client: client.clone(),
+                            guard,
                             last_active: Instant::now(),
                             forward_task,
                         });
@@ -158,10 +205,25 @@ impl UdpProxy {
 
                     if let Some(mut session) = self.sessions.get_mut(&addr) {
                         session.last_active = Instant::now();
-                        if let Err(e) = session.client.send_data(data_str.into()).await {
+                        let filtered = match &session.guard {
+                            Some(guard) => guard.filter(data_str),
+                            None => Filtered { pass: data_str.into(), rejections: vec![] },
+                        };
+                        for rejection in &filtered.rejections {
+                            if let Err(e) = self.socket.send_to(rejection.as_bytes(), addr).await {
+                                info!("[UDP Proxy] Failed to send rejection downstream to {}: {}, ignoring", addr, e);
+                            }
+                        }
+                        if filtered.pass.is_empty() { continue; }
+                        if let Err(e) = session.client.send_data(filtered.pass.into()).await {
                             error!("[UDP Proxy] Failed to send data upstream for {}: {}", addr, e);
                         }
                     }
+
+                    // the coach gave up its side, let the next one in
+                    if self.kind == ClientKind::OlCoach && data_str.trim().trim_end_matches('\0') == "(bye)" {
+                        self.remove_session(addr);
+                    }
                 }
             }
         }
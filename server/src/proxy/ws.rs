@@ -11,7 +11,7 @@ use futures::{SinkExt, StreamExt};
 use log::{error, info, trace, warn};
 use tokio::task::JoinHandle;
 
-use common::client::{Error as ClientError};
+use common::client::{Error as ClientError, Kind as ClientKind};
 use crate::state::{AppState, AppStateStatus};
 use crate::PEER_IP;
 
@@ -60,7 +60,7 @@ async fn handle_upgrade(
             .unwrap_or(DEFAULT_SERVER_UDP_PORT),
     );
 
-    let player_client = state.session.get_or_create(client_id, req.name, server_addr);
+    let player_client = state.session.get_or_create(client_id, req.name, ClientKind::Player, server_addr);
 
     let (client_tx, mut client_rx) = mpsc::channel(32);
     let subscription_id = player_client.subscribe(client_tx);
@@ -85,6 +85,7 @@ async fn handle_upgrade(
         }
     }
 
+    let guard = state.player_guard();
     let (socket_tx, mut socket_rx, mut socket_task) = ws_into_mpsc_tx::<32>(socket);
 
     let mut state_status = state.status_rx.clone();
@@ -122,7 +123,14 @@ async fn handle_upgrade(
                     Message::Text(text) => {
                         let text = text.trim();
                         if text.is_empty() { continue; }
-                        if let Err(e) = player_client.send_data(text.into()).await {
+                        let filtered = guard.filter(text);
+                        for rejection in filtered.rejections {
+                            if let Err(e) = socket_tx.send(Message::Text(rejection.into())).await {
+                                error!("[WS Proxy] Client[{client_id}] Failed to send message: {}", e);
+                            }
+                        }
+                        if filtered.pass.is_empty() { continue; }
+                        if let Err(e) = player_client.send_data(filtered.pass.into()).await {
                              error!("[WS Proxy] Client[{client_id}] Failed send msg to udp client: {}", e);
                         }
                     },
@@ -142,6 +150,7 @@ async fn handle_upgrade(
                 }
             },
             Some(msg) = client_rx.recv() => {
+                guard.observe(&msg);
                 let message = match ArcStr::as_static(&msg) {
                     Some(text) => Message::Text(text.into()),
                     None => Message::Binary(msg.to_string().into()),
@@ -157,7 +166,7 @@ async fn handle_upgrade(
     player_client.unsubscribe(subscription_id);
 }
 
-fn ws_into_mpsc_tx<const BUF_SIZE: usize>(
+pub(crate) fn ws_into_mpsc_tx<const BUF_SIZE: usize>(
     ws: WebSocket,
 ) -> (
     mpsc::Sender<Message>,
@@ -1,5 +1,6 @@
 use std::sync::Arc;
 use std::collections::HashMap;
+use std::net::SocketAddr;
 
 use log::{debug, error, info, warn};
 use uuid::Uuid;
@@ -8,6 +9,7 @@ use chrono::{Utc, Duration};
 use common::client::Info as ClientInfo;
 use service::Service;
 
+use crate::proxy::guard::{BlockedActions, PlayerGuard};
 use crate::proxy::manager::SessionManager;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -32,10 +34,23 @@ impl AppStateStatus {
 }
 
 
+/// Where the server and its proxies are bound.
+#[derive(Debug, Clone, Copy)]
+pub struct ListenAddrs {
+    pub http: SocketAddr,
+    pub player: SocketAddr,
+    pub trainer: SocketAddr,
+    pub coach: SocketAddr,
+}
+
 #[derive(Clone)]
 pub struct AppState {
     pub(crate) service: Arc<Service>,
     pub(crate) session: Arc<SessionManager>,
+    pub(crate) blocked: Arc<BlockedActions>,
+    pub(crate) listen: ListenAddrs,
+    /// The server's own command line args, the service reports its own.
+    pub(crate) args: Arc<serde_json::Value>,
 
     pub status_rx: watch::Receiver<AppStateStatus>,
 }
@@ -44,7 +59,12 @@ impl AppState {
     pub const CLEANER_POLL_INTERVAL: Duration = Duration::seconds(1);
     pub const CLEANER_TIMEOUT: Duration = Duration::seconds(30);
     
-    pub fn new(service: Service, shutdown_notifier: Option<oneshot::Receiver<()>>) -> Self {
+    pub fn new(
+        service: Service,
+        listen: ListenAddrs,
+        args: serde_json::Value,
+        shutdown_notifier: Option<oneshot::Receiver<()>>,
+    ) -> Self {
         let service = Arc::new(service);
 
         let (status_tx, status_rx) = watch::channel(AppStateStatus::Running);
@@ -57,6 +77,9 @@ impl AppState {
         Self {
             service,
             session: Arc::new(SessionManager::new()),
+            blocked: Arc::new(BlockedActions::new()),
+            listen,
+            args: Arc::new(args),
             status_rx,
         }
     }
@@ -113,4 +136,8 @@ impl AppState {
     pub async fn conn_info(&self) -> HashMap<Uuid, ClientInfo> {
         self.session.conn_info().await
     }
+
+    pub fn player_guard(&self) -> PlayerGuard {
+        PlayerGuard::new(self.service.base_config().blocklist.clone(), self.blocked.clone())
+    }
 }
\ No newline at end of file
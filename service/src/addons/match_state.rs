@@ -0,0 +1,268 @@
+use log::{debug, warn};
+use serde::Serialize;
+use tokio::sync::{mpsc, watch};
+use tokio::task::JoinHandle;
+
+use common::client::{RxData, TxData};
+use common::command;
+use common::command::trainer::TrainerCommand;
+use common::sensor::{Hear, HearSender};
+use common::types::{EarMode, EyeMode, Penalties, PlayMode, Side};
+use common::utils::sexp::Sexp;
+use process::addon::{Addon, ListenerAddon};
+use process::resolver::CallSender;
+
+pub use common::types::Score;
+
+#[derive(Serialize, Clone, Copy, Debug, PartialEq)]
+pub struct GoalEvent {
+    pub side: Side,
+    pub time: u16,
+    /// Goal count of the scoring side after this goal.
+    pub count: u8,
+}
+
+/// What the referee announced so far, `time` also follows every `see_global`.
+#[derive(Serialize, Clone, Debug, Default, PartialEq)]
+pub struct MatchState {
+    pub time: Option<u16>,
+    pub play_mode: Option<PlayMode>,
+    pub score: Score,
+    pub last_goal: Option<GoalEvent>,
+    /// Only once a penalty shoot-out has started.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub penalties: Option<Penalties>,
+}
+
+impl MatchState {
+    /// Fold a message received by the trainer in, returns whether anything changed.
+    pub fn ingest(&mut self, msg: &str) -> bool {
+        if !msg.starts_with("(hear ") && !msg.starts_with("(see_global ") {
+            return false;
+        }
+        let Ok(sexp) = Sexp::parse(msg) else {
+            return false;
+        };
+        let Some((head, tokens)) = sexp.list().and_then(|l| l.split_first()) else {
+            return false;
+        };
+
+        match head.atom() {
+            Some("see_global") => match tokens.first().and_then(Sexp::parse_atom) {
+                Some(time) => self.set_time(time),
+                None => false,
+            },
+            Some("hear") => match Hear::parse(tokens) {
+                Some(Hear { time, sender: HearSender::Referee, message: Some(message) }) => {
+                    self.referee(time, &message)
+                }
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    fn set_time(&mut self, time: u16) -> bool {
+        let changed = self.time != Some(time);
+        self.time = Some(time);
+        changed
+    }
+
+    fn referee(&mut self, time: u16, message: &str) -> bool {
+        let mut changed = self.set_time(time);
+
+        if let Some((side, count)) = PlayMode::decode_goal(message) {
+            match side {
+                Side::LEFT => self.score.left = count,
+                Side::RIGHT => self.score.right = count,
+                _ => {}
+            }
+            self.last_goal = Some(GoalEvent { side, time, count });
+            changed = true;
+        }
+
+        // fouls and cards are announced by the referee as well, but are no play modes
+        if let Some(play_mode) = PlayMode::decode(message) {
+            if self.play_mode != Some(play_mode) {
+                self.count_penalty(play_mode);
+                changed = true;
+            }
+            self.play_mode = Some(play_mode);
+        }
+
+        changed
+    }
+
+    /// Every kick of a shoot-out ends in `penalty_score_*` or `penalty_miss_*` once.
+    fn count_penalty(&mut self, play_mode: PlayMode) {
+        let penalties = match play_mode {
+            PlayMode::PM_PenaltySetup_Left | PlayMode::PM_PenaltySetup_Right
+            | PlayMode::PM_PenaltyScore_Left | PlayMode::PM_PenaltyMiss_Left
+            | PlayMode::PM_PenaltyScore_Right | PlayMode::PM_PenaltyMiss_Right => {
+                self.penalties.get_or_insert_default()
+            },
+            _ => return,
+        };
+        match play_mode {
+            PlayMode::PM_PenaltyScore_Left => penalties.left_score += 1,
+            PlayMode::PM_PenaltyMiss_Left => penalties.left_miss += 1,
+            PlayMode::PM_PenaltyScore_Right => penalties.right_score += 1,
+            PlayMode::PM_PenaltyMiss_Right => penalties.right_miss += 1,
+            _ => {},
+        }
+    }
+}
+
+/// Turns the trainer's `ear` and `eye` on and tracks [`MatchState`] from what the server pushes.
+#[derive(Debug)]
+pub struct MatchStateAddon {
+    state: watch::Receiver<MatchState>,
+    task: JoinHandle<()>,
+}
+
+impl MatchStateAddon {
+    fn start(
+        caller: CallSender<TrainerCommand, TxData, RxData>,
+        mut data_rx: mpsc::Receiver<RxData>,
+    ) -> Self {
+        let (state_tx, state_rx) = watch::channel(MatchState::default());
+        let task = tokio::spawn(async move {
+            if !matches!(caller.call(command::trainer::Ear { mode: EarMode::On }).await, Ok(Ok(_))) {
+                warn!("[MatchStateAddon] Failed to turn the ear on, no referee messages will be heard.");
+            }
+            if !matches!(caller.call(command::trainer::Eye { mode: EyeMode::On }).await, Ok(Ok(_))) {
+                warn!("[MatchStateAddon] Failed to turn the eye on, time only follows the referee.");
+            }
+            if let Ok(Ok(res)) = caller.call(command::trainer::CheckBall).await {
+                state_tx.send_if_modified(|s| s.set_time(res.time));
+            }
+
+            while let Some(msg) = data_rx.recv().await {
+                state_tx.send_if_modified(|s| s.ingest(&msg));
+                if state_tx.is_closed() {
+                    debug!("[MatchStateAddon] State channel closed, stopping.");
+                    break;
+                }
+            }
+            debug!("[MatchStateAddon] Client closed, stopping.");
+        });
+
+        Self {
+            state: state_rx,
+            task,
+        }
+    }
+
+    fn watcher(&self) -> watch::Receiver<MatchState> {
+        self.state.clone()
+    }
+}
+
+impl Addon for MatchStateAddon {
+    fn close(&self) {
+        self.task.abort()
+    }
+}
+
+impl ListenerAddon<TrainerCommand> for MatchStateAddon {
+    type Handle = watch::Receiver<MatchState>;
+
+    fn handle(&self) -> Self::Handle {
+        self.watcher()
+    }
+
+    fn from_listener(
+        caller: CallSender<TrainerCommand, TxData, RxData>,
+        data_rx: mpsc::Receiver<RxData>,
+    ) -> Self {
+        Self::start(caller, data_rx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use process::CoachedProcess;
+
+    #[test]
+    fn test_ingest_referee() {
+        let mut state = MatchState::default();
+        assert!(!state.ingest("(ok check_ball 0 in_field)"));
+        assert!(state.ingest("(hear 0 referee kick_off_l)"));
+        assert_eq!(state.play_mode, Some(PlayMode::PM_KickOff_Left));
+
+        assert!(state.ingest("(see_global 12 ((g l) -52.5 0))"));
+        assert!(!state.ingest("(see_global 12 ((g l) -52.5 0))"));
+        assert!(!state.ingest("(hear 12 (p \"HELIOS\" 3) \"hello\")"));
+
+        assert!(state.ingest("(hear 40 referee goal_r_1)"));
+        assert_eq!(state.score, Score { left: 0, right: 1 });
+        assert_eq!(state.last_goal, Some(GoalEvent { side: Side::RIGHT, time: 40, count: 1 }));
+        assert_eq!(state.play_mode, Some(PlayMode::PM_AfterGoal_Right));
+
+        assert!(state.ingest("(hear 41 referee yellow_card_l_5)"));
+        assert_eq!(state.play_mode, Some(PlayMode::PM_AfterGoal_Right));
+        assert_eq!(state.time, Some(41));
+        assert_eq!(state.penalties, None);
+    }
+
+    #[test]
+    fn test_ingest_penalties() {
+        let mut state = MatchState::default();
+        assert!(state.ingest("(hear 6000 referee penalty_setup_l)"));
+        assert_eq!(state.penalties, Some(Penalties::default()));
+        for msg in [
+            "(hear 6010 referee penalty_ready_l)", "(hear 6012 referee penalty_taken_l)",
+            "(hear 6020 referee penalty_score_l)", "(hear 6020 referee penalty_score_l)",
+            "(hear 6030 referee penalty_setup_r)", "(hear 6040 referee penalty_miss_r)",
+        ] {
+            state.ingest(msg);
+        }
+        assert_eq!(state.penalties, Some(Penalties { left_score: 1, left_miss: 0, right_score: 0, right_miss: 1 }));
+    }
+
+    #[tokio::test]
+    async fn test_tracking_match_state_auto_start_half_time_break_end() -> Result<(), ()> {
+        let spawner = CoachedProcess::spawner().await.expect("Spawner failed");
+        let server = spawner.spawn().await.expect("Spawn failed");
+
+        let rx = server.coach().add_listener_addon::<MatchStateAddon>("match_state");
+        let caller = server.coach().caller();
+        caller
+            .call(command::trainer::Start)
+            .await
+            .expect("Start failed")
+            .expect("Failed to start");
+
+        let state_task = tokio::spawn(async move {
+            let mut rx = rx;
+            while rx.changed().await.is_ok() {
+                let state = rx.borrow().clone();
+                println!("MatchState:\t{state:?}.");
+                if state.time == Some(3000) && state.play_mode == Some(PlayMode::PM_BeforeKickOff) {
+                    caller
+                        .call(command::trainer::Start)
+                        .await
+                        .expect("Start failed")
+                        .expect("Failed to start at half-time");
+                    continue;
+                }
+                if state.play_mode == Some(PlayMode::PM_TimeOver) {
+                    println!("Reached end of test at timestep {:?}.", state.time);
+                    break;
+                }
+            }
+        });
+
+        tokio::time::timeout(Duration::from_secs(10), state_task)
+            .await
+            .expect("Timeout waiting for time_over")
+            .unwrap();
+
+        let mut server = server;
+        server.shutdown().await.expect("Shutdown failed");
+
+        Ok(())
+    }
+}
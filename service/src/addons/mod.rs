@@ -1,4 +1,3 @@
-mod time;
-mod playmode;
+mod match_state;
 
-pub use time::TimeStatusAddon;
+pub use match_state::{GoalEvent, MatchState, MatchStateAddon, Score};
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::fmt::Display;
 use std::path::PathBuf;
 use std::pin::Pin;
@@ -8,6 +9,7 @@ use log::{debug, info, warn};
 use tokio::sync::{mpsc, watch, RwLock};
 use chrono::{DateTime, Utc};
 use agones::Sdk as AgonesSdk;
+use allocator::metadata::{Annotations, Labels, MetaData};
 use tokio_util::sync::CancellationToken;
 use crate::{Error, Result, ServerStatus};
 use crate::agones::config::AgonesAutoShutdownConfig;
@@ -29,6 +31,14 @@ pub struct McLastPoll {
     pub polled_at: DateTime<Utc>,
 }
 
+/// Where clients reach this GameServer from outside the cluster.
+#[derive(Debug, Clone)]
+pub struct GameServerAddress {
+    pub address: String,
+    /// Allocated host ports, by the port names of the fleet template.
+    pub ports: HashMap<String, u16>,
+}
+
 pub struct AgonesService {
     sdk:    Arc<RwLock<AgonesSdk>>,
     cfg:    AgonesConfig,
@@ -66,12 +76,59 @@ impl Display for AgonesService {
 
 impl AgonesService {
     pub async fn from_args(args: AgonesArgs, log_root: PathBuf) -> Result<Self> {
-        let sdk = agones::Sdk::new(
+        let mut sdk = agones::Sdk::new(
             args.agones_port,
             args.agones_keep_alive.map(Duration::from_secs),
         ).await.map_err(Error::AgonesSdkFailToConnect)?;
 
-        let base = BaseService::from_args(args.base_args, log_root).await;
+        let mut base_args = args.base_args;
+        let mut teams = (None, None);
+        let mut metadata = None;
+        match sdk.get_gameserver().await {
+            Ok(gs) => {
+                let meta = gs.object_meta.unwrap_or_default();
+                let labels = Labels::from_map(meta.labels).unwrap_or_else(|e| {
+                    warn!("[AgonesService] Failed to parse GameServer labels: {e}");
+                    Labels::new(HashMap::new(), HashMap::new())
+                });
+                let annotations = Annotations::from_map(meta.annotations);
+                let (team_l, team_r) = annotations.declared_teams();
+                teams = (team_l.map(str::to_string), team_r.map(str::to_string));
+                info!("[AgonesService] Teams declared by GameServer: {teams:?}");
+                let Annotations { referee, stopping, init, blocklist, server_params, .. } = annotations.clone();
+                metadata = Some(MetaData { labels, annotations });
+                info!("[AgonesService] Referee declared by GameServer: {referee:?}");
+                base_args.rcss_referee = referee.enabled;
+                if !referee.enabled && (stopping.goal_l.is_some() || stopping.goal_r.is_some()) {
+                    warn!("[AgonesService] Goals are not awarded with the referee disabled, goal stopping will never fire");
+                }
+
+                info!("[AgonesService] Stopping declared by GameServer: {stopping:?}");
+                if let Some(time_up) = stopping.timeup {
+                    base_args.rcss_max_timesteps = time_up;
+                }
+                base_args.stop_goal_l = stopping.goal_l.or(base_args.stop_goal_l);
+                base_args.stop_goal_r = stopping.goal_r.or(base_args.stop_goal_r);
+
+                if !init.is_empty() {
+                    info!("[AgonesService] InitState declared by GameServer: {init:?}");
+                    base_args.init_state = Some(init);
+                }
+
+                if !server_params.is_empty() {
+                    info!("[AgonesService] Server params declared by GameServer: {server_params:?}");
+                    base_args.server_params = Some(server_params);
+                }
+
+                if !blocklist.is_empty() {
+                    info!("[AgonesService] Blocklist declared by GameServer: {blocklist:?}");
+                    base_args.blocklist = Some(blocklist);
+                }
+            },
+            Err(e) => warn!("[AgonesService] Failed to get GameServer, referee, stopping, init state, blocklist and server params fall back to args: {e}"),
+        }
+
+        let base = BaseService::from_args(base_args, log_root).await?;
 
         let mc_config = args.mc_args.into_config();
         let mc_client = mc_config.as_ref()
@@ -85,6 +142,8 @@ impl AgonesService {
             cfg.sdk.port = args.agones_port;
             cfg.sdk.keep_alive = args.agones_keep_alive.map(Duration::from_secs);
             cfg.match_composer = mc_config;
+            (cfg.team_l, cfg.team_r) = teams;
+            cfg.metadata = metadata;
 
             cfg
         };
@@ -310,6 +369,29 @@ impl AgonesService {
         &self.cfg
     }
 
+    pub fn declared_teams(&self) -> (Option<&str>, Option<&str>) {
+        (self.cfg.team_l.as_deref(), self.cfg.team_r.as_deref())
+    }
+
+    /// Public address and ports from the GameServer status, `None` until Agones has allocated them.
+    pub async fn gameserver_address(&self) -> Option<GameServerAddress> {
+        let gs = match self.sdk.write().await.get_gameserver().await {
+            Ok(gs) => gs,
+            Err(e) => {
+                warn!("[AgonesService] Failed to get GameServer for its address: {e}");
+                return None;
+            },
+        };
+        let status = gs.status?;
+        if status.address.is_empty() {
+            return None;
+        }
+        let ports = status.ports.into_iter()
+            .filter_map(|p| Some((p.name, u16::try_from(p.port).ok()?)))
+            .collect();
+        Some(GameServerAddress { address: status.address, ports })
+    }
+
     /// Whether the cancellation token has been triggered (entering shutdown path).
     pub fn is_cancelled(&self) -> bool {
         self.cancel_token.is_cancelled()
@@ -319,4 +401,4 @@ impl AgonesService {
     pub fn is_shutdown_signalled(&self) -> bool {
         self.shutdown_rx.borrow().is_some()
     }
-}
\ No newline at end of file
+}
@@ -1,4 +1,5 @@
 use std::time::Duration;
+use allocator::metadata::MetaData;
 use super::match_composer::MatchComposerConfig;
 
 #[derive(Clone, Debug)]
@@ -7,6 +8,11 @@ pub struct AgonesConfig {
     pub sdk: AgonesSdkConfig,
     pub shutdown: AgonesAutoShutdownConfig,
     pub match_composer: Option<MatchComposerConfig>,
+    /// Team names declared by the GameServer annotations.
+    pub team_l: Option<String>,
+    pub team_r: Option<String>,
+    /// Labels and annotations of the GameServer, as the service applied them.
+    pub metadata: Option<MetaData>,
 }
 
 impl AgonesConfig { pub fn new() -> Self { Self::default() } }
@@ -17,6 +23,9 @@ impl Default for AgonesConfig {
             sdk: AgonesSdkConfig::default(),
             shutdown: AgonesAutoShutdownConfig::default(),
             match_composer: None,
+            team_l: None,
+            team_r: None,
+            metadata: None,
         }
     }
 }
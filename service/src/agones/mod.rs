@@ -7,4 +7,4 @@ use crate::base::{BaseService, BaseArgs};
 
 pub use args::AgonesArgs;
 pub use config::AgonesConfig;
-pub use agones::AgonesService;
+pub use agones::{AgonesService, GameServerAddress};
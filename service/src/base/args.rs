@@ -1,8 +1,15 @@
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 use clap::Parser;
+use serde::Serialize;
+use super::{Blocklist, InitState};
 
-#[derive(Parser, Debug)]
+#[derive(Parser, Serialize, Clone, Debug)]
 pub struct BaseArgs {
+    #[clap(long, env = "RCSSSERVER_BIN", default_value = "rcssserver", help = "RCSS executable, a name in PATH or a path")]
+    pub rcss_binary: PathBuf,
+    #[clap(long, env = "RCSSSERVER_WRAPPER", default_value = "stdbuf -oL -eL", help = "Command RCSS is launched through, empty to launch it directly")]
+    pub rcss_wrapper: String,
     #[clap(long, env = "RCSSSERVER_PLAYER_UDP", default_value_t = 6000, help = "RCSS player udp port")]
     pub player_port: u16,
     #[clap(long, env = "RCSSSERVER_TRAINER_UDP", default_value_t = 6001, help = "RCSS trainer udp port")]
@@ -11,10 +18,24 @@ pub struct BaseArgs {
     pub coach_port: u16,
     #[clap(long, env = "RCSSSERVER_SYNC_MODE_EN", default_value_t = true, help = "RCSS sync mode")]
     pub rcss_sync: bool,
+    #[clap(long, env = "RCSSSERVER_REFEREE_EN", default_value_t = true, help = "RCSS automatic referee")]
+    pub rcss_referee: bool,
     #[clap(long, env = "RCSSSERVER_LOG_DIR", default_value = "./games", help = "RCSS log directory")]
     pub rcss_game_log_dir: PathBuf,
     #[clap(long, env = "RCSSSERVER_MAX_TIMESTEP", default_value_t = 6000, help = "Total timesteps")]
     pub rcss_max_timesteps: u16,
+    #[clap(long, env = "RCSSSERVER_STOP_GOAL_L", help = "End the match once the left team scored this many goals")]
+    pub stop_goal_l: Option<u8>,
+    #[clap(long, env = "RCSSSERVER_STOP_GOAL_R", help = "End the match once the right team scored this many goals")]
+    pub stop_goal_r: Option<u8>,
+    #[clap(long, env = "RCSSSERVER_INIT_STATE", value_parser = InitState::parse_arg, help = "Ball and player placement before kick-off, as JSON")]
+    pub init_state: Option<InitState>,
+    #[clap(long, env = "RCSSSERVER_BLOCKLIST", value_parser = Blocklist::parse_arg, help = "Player commands dropped by the proxies, as JSON")]
+    pub blocklist: Option<Blocklist>,
+    #[clap(long, env = "RCSSSERVER_SERVER_PARAMS", value_parser = parse_server_params, help = "server:: parameter overrides, as a JSON object")]
+    pub server_params: Option<BTreeMap<String, String>>,
+    #[clap(long, env = "RCSSSERVER_PROFILE", help = "rcssserver .conf file or TOML/YAML profile, layered under --server-params")]
+    pub rcss_profile: Option<PathBuf>,
     
     #[clap(long, env = "TRAINER_HALF_TIME_AUTO_START_EN", default_value_t = false, help = "Auto start when half-time(3000) is reached")]
     pub half_time_auto_start: bool,
@@ -23,4 +44,16 @@ pub struct BaseArgs {
     pub always_log_stdout: bool,
     #[clap(long, env = "RCSSSERVER_STDIO_LOG_PATH", default_value = "./rcss.log", help = "RCSSServer wrapped process stdout/stderr log file")]
     pub rcss_stdio_log_path: PathBuf,
-}
\ No newline at end of file
+    #[clap(long, env = "SERVICE_HISTORY_PATH", default_value = "./history.jsonl", help = "Match history file, relative to the log root")]
+    pub history_path: PathBuf,
+}
+
+fn parse_server_params(s: &str) -> Result<BTreeMap<String, String>, String> {
+    let params: BTreeMap<String, serde_json::Value> = serde_json::from_str(s).map_err(|e| e.to_string())?;
+    Ok(params.into_iter()
+        .map(|(name, value)| match value {
+            serde_json::Value::String(value) => (name, value),
+            value => (name, value.to_string()),
+        })
+        .collect())
+}
@@ -1,16 +1,33 @@
+use std::collections::BTreeMap;
 use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
 use log::{debug, info, warn};
-use tokio::sync::{watch, RwLock};
+use tokio::sync::{mpsc, watch, RwLock};
 use tokio::task::JoinHandle;
 use chrono::{DateTime, Utc};
 
+use common::client::{RxData, TxData};
 use common::command::{trainer, Command, CommandResult};
 use common::command::trainer::TrainerCommand;
-use process::{CoachedProcessSpawner, CommandCaller, ProcessConfig, ProcessStatus};
+use process::{CoachedProcessSpawner, CommandCaller, ProcessConfig, ProcessStatus, RcssBinary, RcssVersion, StepOutcome};
+use process::config::{ParamValue, ServerConfig};
 
+use common::rcg;
+use common::types::{MatchLogs, MatchResult, PlayMode, ResultSource, Side};
+
+use crate::addons::MatchState;
 use crate::GAME_END_TIMESTEP;
 use crate::{Error, Result};
-use super::{AddonProcess, BaseArgs, BaseConfig, ServerStatus};
+use super::{AddonProcess, BaseArgs, BaseConfig, EndReason, InitState, MatchHistory, ServerStatus, StoppingConfig};
+
+const INIT_STATE_POLL_INTERVAL: Duration = Duration::from_millis(200);
+/// How long declared players get to connect before the init state is applied without them.
+const INIT_STATE_CONNECT_TIMEOUT: Duration = Duration::from_secs(60);
+/// rcssserver's `server::stamina_max` when the config leaves it out.
+const DEFAULT_STAMINA_MAX: f64 = 8000.0;
+/// The rcssserver config actually spawned with, written under the log root.
+const EFFECTIVE_CONFIG_FILE: &str = "rcssserver.conf";
 
 
 #[derive(Debug)]
@@ -63,13 +80,31 @@ impl OptionedProcess {
 }
 
 
+/// Where the status tracing task reports how the match ended.
+struct MatchEnd {
+    end_reason_tx: watch::Sender<Option<EndReason>>,
+    result_tx: watch::Sender<Option<MatchResult>>,
+    history: Arc<MatchHistory>,
+    generation: u64,
+    started_at: DateTime<Utc>,
+}
+
 #[derive(Debug)]
 pub struct BaseService {
+    /// The args the service was built from, after any GameServer overrides.
+    args: BaseArgs,
     config: BaseConfig,
     spawner: CoachedProcessSpawner,
     process: RwLock<OptionedProcess>,
     status_tx: watch::Sender<ServerStatus>,
     status_rx: watch::Receiver<ServerStatus>,
+    end_reason_tx: watch::Sender<Option<EndReason>>,
+    result_tx: watch::Sender<Option<MatchResult>>,
+    /// Result read back from a game log, with the log it was read from.
+    log_result: Mutex<Option<MatchResult>>,
+    history: Arc<MatchHistory>,
+    /// Generation of the current match in the history.
+    generation_tx: watch::Sender<Option<u64>>,
 
     cancel_tx: watch::Sender<bool>,
 }
@@ -84,28 +119,56 @@ pub(crate) fn get_status(watch: &watch::Receiver<ServerStatus>) -> ServerStatus
 }
 
 impl BaseService {
-    pub async fn from_args(args: BaseArgs, log_root: PathBuf) -> Self {
-        let config: BaseConfig = (&args).into();
+    pub async fn from_args(args: BaseArgs, log_root: PathBuf) -> Result<Self> {
+        let mut config: BaseConfig = (&args).into();
         config.set_log_root(log_root);
         
-        let mut spawner = CoachedProcessSpawner::new().await;
-        let rcss_game_log_dir = {
-            let dir = config.log_root().join(args.rcss_game_log_dir);
-            dir.to_string_lossy().into_owned().leak() // STRING LEAK
-        };
+        let binary = RcssBinary::new(&args.rcss_binary)
+            .with_wrapper(args.rcss_wrapper.split_whitespace().map(String::from).collect());
+        let mut spawner = CoachedProcessSpawner::from_binary(binary).await
+            .map_err(Error::ProcessSpawnFailed)?;
+        info!("[BaseService] Using rcssserver {}", spawner.process.version());
+        // defaults < profile < server params, the settings the service relies on below always win
+        let process_config = spawner.process_config_mut();
+        if let Some(path) = &args.rcss_profile {
+            let profile = ProcessConfig::load(path).map_err(Error::ProcessConfigInvalid)?;
+            process_config.merge(&profile);
+            info!("[BaseService] Loaded rcssserver profile {}", path.display());
+        }
+        for (name, value) in args.server_params.iter().flatten() {
+            process_config.set_param(ServerConfig::NAMESPACE, name, value)
+                .map_err(Error::ProcessConfigInvalid)?;
+        }
+        let rcss_game_log_dir = config.log_root().join(&args.rcss_game_log_dir);
         spawner
             .with_ports(args.player_port, args.trainer_port, args.coach_port)
             .with_sync_mode(args.rcss_sync)
-            .with_log_dir(rcss_game_log_dir);
+            .with_referee(args.rcss_referee)
+            .with_log_dir(rcss_game_log_dir.to_string_lossy());
+        spawner.process.config.validate().map_err(Error::ProcessConfigInvalid)?;
+        config.stopping = config.stopping.with_referee(args.rcss_referee, &spawner.process.config.server);
+
+        let effective_config = config.log_root().join(EFFECTIVE_CONFIG_FILE);
+        if let Err(e) = spawner.process.config.save(&effective_config) {
+            warn!("[BaseService] Failed to write the effective rcssserver config: {e}");
+        }
 
-        BaseService::new(config, spawner).await
+        Ok(BaseService::new(args, config, spawner).await)
     }
 
-    pub(super) async fn new(config: BaseConfig, spawner: CoachedProcessSpawner) -> Self {
+    pub(super) async fn new(args: BaseArgs, config: BaseConfig, spawner: CoachedProcessSpawner) -> Self {
         let process = RwLock::new(OptionedProcess::Uninitialized);
         let (status_tx, status_rx) = watch::channel(ServerStatus::Uninitialized);
+        let (end_reason_tx, _) = watch::channel(None);
+        let (result_tx, _) = watch::channel(None);
+        let log_result = Mutex::new(None);
+        let history = Arc::new(MatchHistory::open(config.log_root().join(&config.history_rel_path)));
+        let (generation_tx, _) = watch::channel(None);
         let (cancel_tx, _) = watch::channel(false);
-        Self { config, spawner, process, status_tx, status_rx, cancel_tx }
+        Self {
+            args, config, spawner, process, status_tx, status_rx, end_reason_tx, result_tx, log_result,
+            history, generation_tx, cancel_tx,
+        }
     }
 
     pub(crate) async fn spawn(&self, force: bool) -> Result<JoinHandle<()>> {
@@ -123,28 +186,51 @@ impl BaseService {
                 warn!("[BaseService] Failed to shutdown existing process: {:?}. dropping", e);
             }
         }
+        self.close_record().await;
         self.set_status(ServerStatus::Uninitialized)
             .ok_or(Error::StatusChannelClosed)?;
+        self.end_reason_tx.send_replace(None);
+        self.result_tx.send_replace(None);
+        self.log_result.lock().unwrap_or_else(|e| e.into_inner()).take();
 
         let process = self.spawner.spawn().await
             .map_err(|e| Error::ProcessSpawnFailed(e))?;
         let process = AddonProcess::from_coached_process(process);
         info!("[BaseService] AddonProcess spawned");
 
+        let generation = self.history.start(
+            process.started_at(),
+            self.rcss_version().to_string(),
+            self.server_params(),
+            self.config.stopping,
+        );
+        self.generation_tx.send_replace(Some(generation));
+        info!("[BaseService] Match generation {generation} started");
+
         let cancel_tx = self.cancel_tx.clone();
         let mut tasks: Vec<JoinHandle<()>> = vec![];
 
-        let time_rx = process.time_watch();
-        let status_tracing = tokio::spawn(
-            Self::status_tracing_task(self.status_tx.clone(), time_rx, cancel_tx.clone())
-        );
+        let status_tracing = tokio::spawn(Self::status_tracing_task(
+            self.status_tx.clone(),
+            MatchEnd {
+                end_reason_tx: self.end_reason_tx.clone(),
+                result_tx: self.result_tx.clone(),
+                history: self.history.clone(),
+                generation,
+                started_at: process.started_at(),
+            },
+            process.match_watch(),
+            process.trainer_command_sender(),
+            self.config.stopping,
+            cancel_tx.clone(),
+        ));
         tasks.push(status_tracing);
         info!("[BaseService] Status tracing task spawned");
 
         if let Some(half_time) = self.config.half_time_auto_start {
             let caller = process.trainer_command_sender();
             let kick_off_half_time = tokio::spawn(Self::kick_off_half_time_task(
-                process.time_watch(),
+                process.match_watch(),
                 caller,
                 half_time,
                 cancel_tx.clone()
@@ -153,6 +239,17 @@ impl BaseService {
             info!("[BaseService] KickOff Half-Time task spawned (half_time = {}ts)", half_time);
         }
 
+        if let Some(init_state) = self.config.init_state.clone() {
+            let apply_init_state = tokio::spawn(Self::apply_init_state_task(
+                process.trainer_command_sender(),
+                init_state,
+                self.config().server.stamina_max.unwrap_or(DEFAULT_STAMINA_MAX),
+                cancel_tx.clone(),
+            ));
+            tasks.push(apply_init_state);
+            info!("[BaseService] Apply InitState task spawned");
+        }
+
         if self.config.always_log_stdout {
             let watcher = process.process_status_watch();
             let stdout_err_logging_task = tokio::spawn(Self::stdout_err_logging_task(
@@ -182,6 +279,35 @@ impl BaseService {
             .map_err(|_| Error::Timeout { op: "send_trainer_command" })
     }
 
+    /// Advance a synch mode server by `cycles`, waiting at most `timeout` for the clients.
+    pub async fn step(&self, cycles: u16, timeout: Duration) -> Result<StepOutcome> {
+        if self.config().server.synch_mode != Some(true) {
+            return Err(Error::NotSyncMode);
+        }
+
+        self.process.read().await.process()
+            .ok_or(Error::ServerNotRunning { status: self.status_now() })?
+            .step(cycles, timeout).await
+    }
+
+    /// Relay a `kind` message from an external trainer over the service's own trainer
+    /// connection and wait for its reply, an error reply comes back as its reason.
+    ///
+    /// The message waits in line with the service's own calls, so its reply never resolves one.
+    pub async fn relay_trainer(&self, kind: TrainerCommand, data: TxData) -> Result<std::result::Result<(), String>> {
+        self.process.read().await.process()
+            .ok_or(Error::ServerNotRunning { status: self.status_now() })?
+            .relay_trainer(kind, data).await
+    }
+
+    /// Everything rcssserver sends to the trainer of the current process, until `tx` is dropped.
+    pub async fn subscribe_trainer(&self, tx: mpsc::Sender<RxData>) -> Result<()> {
+        self.process.read().await.process()
+            .ok_or(Error::ServerNotRunning { status: self.status_now() })?
+            .subscribe_trainer(tx);
+        Ok(())
+    }
+
     pub async fn trainer_command_sender(&self) -> Result<CommandCaller<TrainerCommand>> {
         let ret = self.process.read().await.process()
             .ok_or(Error::ServerNotRunning { status: ServerStatus::Uninitialized })?
@@ -207,6 +333,7 @@ impl BaseService {
             if self.set_status(ServerStatus::Shutdown).is_none() {
                 warn!("[BaseService] Status channel closed during shutdown");
             }
+            self.close_record().await;
         } else {
             debug!("[BaseService] Shutdown called but no process to shutdown.");
         }
@@ -219,31 +346,56 @@ impl BaseService {
 
     async fn status_tracing_task(
         status_tx: watch::Sender<ServerStatus>,
-        mut time_rx: watch::Receiver<Option<u16>>,
+        end: MatchEnd,
+        mut match_rx: watch::Receiver<MatchState>,
+        caller: CommandCaller<TrainerCommand>,
+        stopping: StoppingConfig,
         cancel_tx: watch::Sender<bool>,
     ) {
         let status_rx = status_tx.subscribe();
         let mut cancel_rx = cancel_tx.subscribe();
         loop {
             tokio::select! {
-                res = time_rx.changed() => {
-                    let timestep = match res {
-                        Ok(_) => *time_rx.borrow(),
+                res = match_rx.changed() => {
+                    let (state, end_reason, time_over) = match res {
+                        Ok(_) => {
+                            let state = match_rx.borrow().clone();
+                            let time_over = state.play_mode == Some(PlayMode::PM_TimeOver);
+                            let end_reason = stopping.check(&state);
+                            (state, end_reason, time_over)
+                        },
                         Err(_) => {
                             let _ = set_status(&status_tx, ServerStatus::Finished);
-                            info!("[BaseService] Status Tracking ended: time_rx channel closed.");
+                            end.history.update(end.generation, |r| { r.ended_at.get_or_insert_with(Utc::now); });
+                            info!("[BaseService] Status Tracking ended: match_rx channel closed.");
                             break;
                         }
                     };
 
+                    let timestep = state.time;
+                    if let Some(reason) = end_reason {
+                        info!("[BaseService] Status Tracking: match ended at {timestep:?}ts by {reason:?}.");
+                        end.end_reason_tx.send_replace(Some(reason));
+                        let result = Self::referee_result(&caller, &state, reason, end.started_at).await;
+                        info!("[BaseService] Status Tracking: result {:?} {}:{} {:?}, winner {:?}.",
+                            result.team_l, result.score.left, result.score.right, result.team_r, result.winner);
+                        end.history.update(end.generation, |r| {
+                            r.ended_at.get_or_insert_with(Utc::now);
+                            r.result = Some(result.clone());
+                        });
+                        end.result_tx.send_replace(Some(result));
+
+                        // let rcssserver wrap the match up as well, it only ends on its own at time_over
+                        if !time_over && let Err(e) = caller.call(trainer::ChangeMode { play_mode: PlayMode::PM_TimeOver }).await {
+                            warn!("[BaseService] Status Tracking: Failed to change mode to time_over: {:?}", e);
+                        }
+                    }
+
                     let next_status = match (get_status(&status_rx), timestep) {
+                        (status, _) if end_reason.is_some() && !status.is_finished() => ServerStatus::Finished,
                         (ServerStatus::Uninitialized, Some(0)) => ServerStatus::Idle,
                         (ServerStatus::Uninitialized, Some(_)) => ServerStatus::Simulating,
-                        (ServerStatus::Idle, Some(t)) if t > 0 && t < GAME_END_TIMESTEP => {
-                            ServerStatus::Simulating
-                        }
-                        (ServerStatus::Idle, Some(t)) if t >= GAME_END_TIMESTEP => ServerStatus::Finished,
-                        (ServerStatus::Simulating, Some(t)) if t >= GAME_END_TIMESTEP => ServerStatus::Finished,
+                        (ServerStatus::Idle, Some(t)) if t > 0 => ServerStatus::Simulating,
                         _ => continue,
                     };
 
@@ -272,9 +424,30 @@ impl BaseService {
         info!("[BaseService] Status Tracking finished.");
     }
 
+    /// the result as the referee announced it, team names are asked from the trainer
+    async fn referee_result(
+        caller: &CommandCaller<TrainerCommand>,
+        state: &MatchState,
+        reason: EndReason,
+        started_at: DateTime<Utc>,
+    ) -> MatchResult {
+        let mut ret = MatchResult::new(state.score, state.penalties, ResultSource::Referee);
+        ret.end_reason = Some(reason);
+        ret.cycles = state.time.unwrap_or_default();
+        ret.duration_ms = Some((Utc::now() - started_at).num_milliseconds());
+        match caller.call(trainer::TeamNames).await {
+            Ok(Ok(teams)) => {
+                ret.team_l = teams.left;
+                ret.team_r = teams.right;
+            },
+            res => warn!("[BaseService] Status Tracking: Failed to get team names for the result: {res:?}"),
+        }
+        ret
+    }
+
     /// trying to send start when half-time reached
     async fn kick_off_half_time_task(
-        mut time_rx: watch::Receiver<Option<u16>>,
+        mut match_rx: watch::Receiver<MatchState>,
         caller: CommandCaller<TrainerCommand>,
         half_time: u16,
         cancel_tx: watch::Sender<bool>,
@@ -284,26 +457,29 @@ impl BaseService {
         assert!(half_time > 0 && half_time < GAME_END_TIMESTEP,
             "[BaseService] kick_off_half_time_task: half_time must be between 1 and 5999");
 
+        let mut kicked_off = false;
         loop {
             tokio::select! {
                 _ = cancel_rx.changed() => {
                     info!("[BaseService] KickOff Halftime ended: cancel recved.");
                 },
-                res = time_rx.changed() => {
+                res = match_rx.changed() => {
                     if let Err(e) = res {
-                        info!("[BaseService] KickOff Halftime ended: time_rx channel closed.");
+                        info!("[BaseService] KickOff Halftime ended: match_rx channel closed.");
                         let _ = cancel_tx.send(true);
                         break;
                     }
 
-                    let time = match *time_rx.borrow() {
+                    let time = match match_rx.borrow().time {
                         Some(t) => t,
                         None => continue,
                     };
 
                     // rcss server always stop at the half-time point
-                    // thus the equality check would be safe
-                    if time != half_time { continue };
+                    // thus the equality check would be safe,
+                    // the play mode changes at that point as well, so only kick off once
+                    if time != half_time || kicked_off { continue };
+                    kicked_off = true;
                     match caller.call(trainer::Start).await {
                         Ok(_) =>
                             debug!("[BaseService] KickOff Halftime: Sent Start command at half-time {}", half_time),
@@ -318,6 +494,108 @@ impl BaseService {
         info!("[BaseService] KickOff Halftime finished.");
     }
 
+    /// wait for every declared player to connect, then place them before kick-off
+    async fn apply_init_state_task(
+        caller: CommandCaller<TrainerCommand>,
+        init_state: InitState,
+        stamina_max: f64,
+        cancel_tx: watch::Sender<bool>,
+    ) {
+        let mut cancel_rx = cancel_tx.subscribe();
+
+        tokio::select! {
+            res = Self::apply_init_state(&caller, &init_state, stamina_max) => match res {
+                Ok(_) => info!("[BaseService] Apply InitState: applied."),
+                Err(e) => warn!("[BaseService] Apply InitState: failed: {e}"),
+            },
+            _ = cancel_rx.changed() => {
+                info!("[BaseService] Apply InitState ended: cancel recved.");
+            },
+        }
+    }
+
+    async fn apply_init_state(
+        caller: &CommandCaller<TrainerCommand>,
+        init_state: &InitState,
+        stamina_max: f64,
+    ) -> Result<()> {
+        async fn call<C: Command<Kind = TrainerCommand>>(
+            caller: &CommandCaller<TrainerCommand>,
+            command: C,
+        ) -> Result<C::Ok> {
+            match caller.call(command).await {
+                Ok(Ok(ok)) => Ok(ok),
+                Ok(Err(e)) => Err(Error::TrainerCommandFailed(e.to_string())),
+                Err(e) => Err(Error::TrainerCommandFailed(e.to_string())),
+            }
+        }
+
+        let deadline = tokio::time::Instant::now() + INIT_STATE_CONNECT_TIMEOUT;
+        let (left, right, missing) = loop {
+            let polled = async {
+                let teams = call(caller, trainer::TeamNames).await?;
+                let world = call(caller, trainer::Look).await?;
+                Ok::<_, Error>((teams, world))
+            }.await;
+            let timed_out = tokio::time::Instant::now() >= deadline;
+
+            let (teams, mut world) = match polled {
+                Ok(polled) => polled,
+                Err(e) if timed_out => return Err(e),
+                Err(e) => {
+                    warn!("[BaseService] Apply InitState: failed to poll players, retrying: {e}");
+                    tokio::time::sleep(INIT_STATE_POLL_INTERVAL).await;
+                    continue;
+                },
+            };
+            if world.time > 0 {
+                return Err(Error::TrainerCommandFailed(
+                    format!("match already kicked off at {}ts", world.time)
+                ));
+            }
+
+            world.resolve_sides(teams.left.as_deref(), teams.right.as_deref());
+            let missing = init_state.players()
+                .filter(|(side, unum, _)| world.player(*side, *unum).is_none())
+                .map(|(side, unum, _)| (side, unum))
+                .collect::<Vec<_>>();
+            if missing.is_empty() || timed_out {
+                break (teams.left.unwrap_or_default(), teams.right.unwrap_or_default(), missing);
+            }
+            tokio::time::sleep(INIT_STATE_POLL_INTERVAL).await;
+        };
+        if !missing.is_empty() {
+            warn!("[BaseService] Apply InitState: never connected within {INIT_STATE_CONNECT_TIMEOUT:?}, not placed: {missing:?}");
+        }
+
+        for (side, unum, player) in init_state.players() {
+            let Some(pos) = player.pos else { continue };
+            if missing.contains(&(side, unum)) {
+                continue;
+            }
+            let team = if side == Side::LEFT { &left } else { &right };
+            call(caller, trainer::Move::player(team.as_str(), unum, pos.x as f32, pos.y as f32)).await?;
+        }
+        if let Some(ball) = init_state.ball {
+            call(caller, trainer::Move::ball(ball.x as f32, ball.y as f32)).await?;
+        }
+
+        // `recover` is all the trainer has, it refills everyone to stamina_max
+        let stamina = init_state.players()
+            .filter_map(|(side, unum, p)| Some((side, unum, p.stamina?)))
+            .collect::<Vec<_>>();
+        for (side, unum, value) in &stamina {
+            if f64::from(*value) != stamina_max {
+                warn!("[BaseService] Apply InitState: stamina {value} of {side:?} {unum} is not supported, recovering to stamina_max {stamina_max} instead");
+            }
+        }
+        if !stamina.is_empty() {
+            call(caller, trainer::Recover).await?;
+        }
+
+        Ok(())
+    }
+
     async fn stdout_err_logging_task(
         mut status: watch::Receiver<ProcessStatus>,
         cancel_tx: watch::Sender<bool>,
@@ -362,12 +640,123 @@ impl BaseService {
         self.status_rx.clone()
     }
 
+    /// Which stopping condition ended the match, `None` while it is still going.
+    pub fn end_reason(&self) -> Option<EndReason> {
+        *self.end_reason_tx.borrow()
+    }
+
+    /// How the last match ended, `None` while it is still going.
+    ///
+    /// Followed live from the referee, or read back from the newest game log when the
+    /// trainer lost track of the match, e.g. because rcssserver went away early.
+    pub async fn match_result(&self) -> Option<MatchResult> {
+        // nothing to report mid-match, don't scan the log dirs on every poll
+        let reported = self.result_tx.borrow().clone();
+        if reported.is_none() && !matches!(self.status_now(), ServerStatus::Finished | ServerStatus::Shutdown) {
+            return None;
+        }
+
+        let dirs = self.log_dirs();
+        let logs = tokio::task::spawn_blocking(move || rcg::find_logs(&dirs)).await.ok()?;
+
+        if let Some(mut result) = reported {
+            result.logs = logs;
+            return Some(result);
+        }
+
+        let game_log = logs.game_log.clone()?;
+        {
+            let cached = self.log_result.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(result) = cached.as_ref().filter(|r| r.logs.game_log.as_ref() == Some(&game_log)) {
+                return Some(result.clone());
+            }
+        }
+
+        // game logs run into tens of megabytes, keep the parsing off the runtime
+        let path = game_log.clone();
+        let mut result = match tokio::task::spawn_blocking(move || rcg::read_result(path)).await.ok()? {
+            Ok(result) => result,
+            Err(e) => {
+                warn!("[BaseService] Failed to read the result from {}: {e}", game_log.display());
+                return None;
+            },
+        };
+        result.logs = logs;
+        *self.log_result.lock().unwrap_or_else(|e| e.into_inner()) = Some(result.clone());
+        Some(result)
+    }
+
+    /// File the result and the logs of the current generation, once its process is gone.
+    ///
+    /// Logs older than the match belong to an earlier one, e.g. when this one was killed
+    /// before rcssserver wrote any.
+    async fn close_record(&self) {
+        let Some(generation) = *self.generation_tx.borrow() else { return };
+        let Some(started_at) = self.history.get(generation).map(|r| r.started_at) else { return };
+
+        let dirs = self.log_dirs();
+        let logs = tokio::task::spawn_blocking(move || {
+            let logs = rcg::find_logs(&dirs);
+            let since = SystemTime::from(started_at);
+            let written_since = |path: Option<PathBuf>| path.filter(|path| {
+                path.metadata().and_then(|m| m.modified()).is_ok_and(|modified| modified >= since)
+            });
+            MatchLogs { game_log: written_since(logs.game_log), text_log: written_since(logs.text_log) }
+        }).await.unwrap_or_default();
+        let result = self.match_result().await
+            .filter(|r| r.source == ResultSource::Referee || logs.game_log.is_some())
+            .map(|r| MatchResult { logs: logs.clone(), ..r });
+
+        self.history.update(generation, |r| {
+            r.ended_at.get_or_insert_with(Utc::now);
+            if result.is_some() {
+                r.result = result;
+            }
+            r.logs = logs;
+        });
+        info!("[BaseService] Match generation {generation} filed");
+    }
+
+    /// The `server::` parameters set for the next spawn.
+    fn server_params(&self) -> BTreeMap<String, ParamValue> {
+        self.config().server.params().into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect()
+    }
+
+    /// Where rcssserver writes its game and text logs.
+    fn log_dirs(&self) -> Vec<PathBuf> {
+        let mut ret = vec![self.config.log_root().join(&self.config.rcss_game_log_rel_dir)];
+        if let Some(text_log_dir) = &self.config().server.text_log_dir {
+            ret.push(PathBuf::from(text_log_dir));
+        }
+        ret
+    }
+
+    /// Generation of the current match, `None` before the first spawn.
+    pub fn generation(&self) -> Option<u64> {
+        *self.generation_tx.borrow()
+    }
+
+    /// Changes once a spawned process is up, e.g. to follow its trainer.
+    pub fn generation_watch(&self) -> watch::Receiver<Option<u64>> {
+        self.generation_tx.subscribe()
+    }
+
+    pub fn history(&self) -> &MatchHistory {
+        &self.history
+    }
+
     pub async fn time_now(&self) -> Option<u16> {
         self.process.read().await.process().and_then(|p| p.time())
     }
 
-    pub async fn time(&self) -> Option<watch::Receiver<Option<u16>>> {
-        self.process.read().await.process().map(|p| p.time_watch())
+    pub async fn match_state_now(&self) -> Option<MatchState> {
+        self.process.read().await.process().map(|p| p.match_state())
+    }
+
+    pub async fn match_state(&self) -> Option<watch::Receiver<MatchState>> {
+        self.process.read().await.process().map(|p| p.match_watch())
     }
 
     pub async fn started_at(&self) -> Option<DateTime<Utc>> {
@@ -378,10 +767,23 @@ impl BaseService {
         &self.spawner.process.config
     }
 
+    pub fn rcss_version(&self) -> RcssVersion {
+        self.spawner.process.version()
+    }
+
     pub fn base_config(&self) -> &BaseConfig {
         &self.config
     }
 
+    pub fn base_args(&self) -> &BaseArgs {
+        &self.args
+    }
+
+    /// The command line every spawn of rcssserver runs.
+    pub fn rcss_command_line(&self) -> Vec<String> {
+        self.spawner.process.command_line()
+    }
+
     pub async fn process_pid(&self) -> Option<u32> {
         self.process.read().await.pid()
     }
@@ -389,4 +791,69 @@ impl BaseService {
     pub async fn process_status_name(&self) -> &'static str {
         self.process.read().await.process_status_name()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use process::resolver::CallResolver;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_status_tracing_waits_for_shoot_out() {
+        let dir = std::env::temp_dir().join(format!("shoot-out-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let history = Arc::new(MatchHistory::open(dir.join("history.jsonl")));
+        let generation = history.start(Utc::now(), "19.0.0".to_string(), BTreeMap::new(), StoppingConfig::default());
+
+        let resolver = CallResolver::<TrainerCommand, RxData>::new(8);
+        let trainer_rx = resolver.ingest_tx().unwrap();
+        let (trainer_tx, mut sent) = mpsc::channel::<TxData>(8);
+        let (status_tx, _) = watch::channel(ServerStatus::Simulating);
+        let (end_reason_tx, end_reason_rx) = watch::channel(None);
+        let (result_tx, result_rx) = watch::channel(None);
+        let (match_tx, match_rx) = watch::channel(MatchState::default());
+        let (cancel_tx, _) = watch::channel(false);
+        let stopping = StoppingConfig { referee_time_over: true, ..Default::default() };
+
+        let task = tokio::spawn(BaseService::status_tracing_task(
+            status_tx,
+            MatchEnd { end_reason_tx, result_tx, history, generation, started_at: Utc::now() },
+            match_rx,
+            resolver.sender(trainer_tx),
+            stopping,
+            cancel_tx,
+        ));
+
+        let mut state = MatchState::default();
+        for msg in [
+            "(hear 3000 referee goal_l_1)",
+            "(hear 5000 referee goal_r_1)",
+            "(hear 6000 referee penalty_setup_l)",
+            "(hear 6010 referee penalty_score_l)",
+            "(hear 6020 referee penalty_setup_r)",
+            "(hear 6030 referee penalty_miss_r)",
+        ] {
+            assert!(state.ingest(msg));
+            match_tx.send_replace(state.clone());
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            assert_eq!(*end_reason_rx.borrow(), None, "ended at {msg}");
+        }
+
+        assert!(state.ingest("(hear 6040 referee time_over)"));
+        match_tx.send_replace(state);
+        assert_eq!(sent.recv().await.as_deref(), Some("(team_names)"));
+        trainer_rx.send("(ok team_names (team l Alpha) (team r Beta))".into()).await.unwrap();
+        task.await.unwrap();
+
+        let result = result_rx.borrow().clone().unwrap();
+        assert_eq!(result.end_reason, Some(EndReason::TimeUp));
+        assert_eq!((result.score.left, result.score.right), (1, 1));
+        assert_eq!(result.penalties.map(|p| (p.left_score, p.right_miss)), Some((1, 1)));
+        assert_eq!(result.winner, Side::LEFT);
+        assert!(sent.try_recv().is_err(), "rcssserver already wrapped up, no change_mode");
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
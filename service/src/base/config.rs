@@ -1,6 +1,6 @@
 use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
-use crate::base::BaseArgs;
+use crate::base::{BaseArgs, Blocklist, InitState, StoppingConfig};
 
 #[derive(Clone, Debug)]
 pub struct BaseConfig {
@@ -9,6 +9,10 @@ pub struct BaseConfig {
     pub log_root: OnceLock<PathBuf>,
     pub rcss_game_log_rel_dir: PathBuf,
     pub rcss_stdio_log_rel_path: Option<PathBuf>,
+    pub history_rel_path: PathBuf,
+    pub stopping: StoppingConfig,
+    pub init_state: Option<InitState>,
+    pub blocklist: Blocklist,
 }
 
 impl BaseConfig {
@@ -29,6 +33,15 @@ impl From<&BaseArgs> for BaseConfig {
         ret.half_time_auto_start = args.half_time_auto_start.then_some(timesteps / 2);
         ret.always_log_stdout = args.always_log_stdout;
         ret.rcss_game_log_rel_dir = args.rcss_game_log_dir.clone();
+        ret.history_rel_path = args.history_path.clone();
+        ret.stopping = StoppingConfig {
+            time_up: timesteps,
+            goal_l: args.stop_goal_l,
+            goal_r: args.stop_goal_r,
+            referee_time_over: false,
+        };
+        ret.init_state = args.init_state.clone().filter(|s| !s.is_empty());
+        ret.blocklist = args.blocklist.clone().unwrap_or_default();
 
         ret
     }
@@ -48,6 +61,10 @@ impl Default for BaseConfig {
             log_root: OnceLock::new(),
             rcss_game_log_rel_dir: PathBuf::from("./games"),
             rcss_stdio_log_rel_path: None,
+            history_rel_path: PathBuf::from("./history.jsonl"),
+            stopping: StoppingConfig::default(),
+            init_state: None,
+            blocklist: Blocklist::default(),
         }
     }
 }
\ No newline at end of file
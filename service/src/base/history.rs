@@ -0,0 +1,213 @@
+//! Every match the service has run, kept in an append-only JSONL file.
+//!
+//! A match is appended when it is spawned and again whenever it changes, the last line of a
+//! generation wins when the file is loaded. Superseded lines are compacted away on load.
+
+use std::collections::BTreeMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+
+use common::types::{MatchLogs, MatchResult};
+use process::config::ParamValue;
+
+use super::StoppingConfig;
+
+/// One spawn of rcssserver and how it went.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct MatchRecord {
+    /// Increases with every spawn, across restarts of the service.
+    pub generation: u64,
+    pub started_at: DateTime<Utc>,
+    /// `None` while the match is running, or if the service went away during it.
+    pub ended_at: Option<DateTime<Utc>>,
+    pub rcss_version: String,
+    /// The `server::` parameters the process was spawned with.
+    pub server_params: BTreeMap<String, ParamValue>,
+    pub stopping: StoppingConfig,
+    pub result: Option<MatchResult>,
+    /// Filled once rcssserver let go of its logs, on the next spawn or shutdown.
+    pub logs: MatchLogs,
+}
+
+#[derive(Debug)]
+pub struct MatchHistory {
+    path: PathBuf,
+    records: Mutex<BTreeMap<u64, MatchRecord>>,
+}
+
+impl MatchHistory {
+    /// Load the history at `path`, a missing file is an empty history.
+    pub fn open(path: PathBuf) -> Self {
+        let (records, lines) = match File::open(&path) {
+            Ok(file) => Self::load(&path, BufReader::new(file)),
+            Err(e) => {
+                if e.kind() != std::io::ErrorKind::NotFound {
+                    warn!("[MatchHistory] Failed to open {}: {e}", path.display());
+                }
+                (BTreeMap::new(), 0)
+            },
+        };
+        info!("[MatchHistory] Loaded {} matches from {}", records.len(), path.display());
+
+        let ret = Self { path, records: Mutex::new(records) };
+        if lines > ret.len() {
+            ret.compact();
+        }
+        ret
+    }
+
+    fn load(path: &Path, reader: impl BufRead) -> (BTreeMap<u64, MatchRecord>, usize) {
+        let mut records = BTreeMap::new();
+        let mut lines = 0;
+        for (idx, line) in reader.lines().enumerate() {
+            let line = match line {
+                Ok(line) if line.trim().is_empty() => continue,
+                Ok(line) => line,
+                Err(e) => {
+                    warn!("[MatchHistory] Failed to read {}: {e}", path.display());
+                    break;
+                },
+            };
+            lines += 1;
+            match serde_json::from_str::<MatchRecord>(&line) {
+                Ok(record) => { records.insert(record.generation, record); },
+                Err(e) => warn!("[MatchHistory] Skipping malformed line {}:{}: {e}", path.display(), idx + 1),
+            }
+        }
+        (records, lines)
+    }
+
+    /// Record a freshly spawned match under the next generation.
+    pub fn start(
+        &self,
+        started_at: DateTime<Utc>,
+        rcss_version: String,
+        server_params: BTreeMap<String, ParamValue>,
+        stopping: StoppingConfig,
+    ) -> u64 {
+        let mut records = self.lock();
+        let generation = records.last_key_value().map_or(1, |(generation, _)| generation + 1);
+        let record = MatchRecord {
+            generation,
+            started_at,
+            ended_at: None,
+            rcss_version,
+            server_params,
+            stopping,
+            result: None,
+            logs: MatchLogs::default(),
+        };
+        self.append(&record);
+        records.insert(generation, record);
+        generation
+    }
+
+    /// Change the record of `generation` and append it, if there is one.
+    pub fn update(&self, generation: u64, f: impl FnOnce(&mut MatchRecord)) {
+        let mut records = self.lock();
+        let Some(record) = records.get_mut(&generation) else {
+            warn!("[MatchHistory] No match of generation {generation} to update");
+            return;
+        };
+        let before = record.clone();
+        f(record);
+        if *record != before {
+            self.append(record);
+        }
+    }
+
+    pub fn get(&self, generation: u64) -> Option<MatchRecord> {
+        self.lock().get(&generation).cloned()
+    }
+
+    /// Up to `limit` records, newest first, skipping the `offset` newest.
+    pub fn list(&self, offset: usize, limit: usize) -> Vec<MatchRecord> {
+        self.lock().values().rev().skip(offset).take(limit).cloned().collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BTreeMap<u64, MatchRecord>> {
+        self.records.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// A failed write only loses history, it never fails the match.
+    fn append(&self, record: &MatchRecord) {
+        let res = (|| {
+            if let Some(parent) = self.path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            let mut file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+            let mut line = serde_json::to_vec(record)?;
+            line.push(b'\n');
+            file.write_all(&line)
+        })();
+        if let Err(e) = res {
+            warn!("[MatchHistory] Failed to append generation {} to {}: {e}", record.generation, self.path.display());
+        }
+    }
+
+    /// Rewrite the file with the last line of every generation.
+    fn compact(&self) {
+        let tmp = self.path.with_extension("jsonl.tmp");
+        let res = (|| {
+            let mut file = File::create(&tmp)?;
+            for record in self.lock().values() {
+                serde_json::to_writer(&mut file, record)?;
+                file.write_all(b"\n")?;
+            }
+            file.sync_all()?;
+            fs::rename(&tmp, &self.path)
+        })();
+        if let Err(e) = res {
+            warn!("[MatchHistory] Failed to compact {}: {e}", self.path.display());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::types::{EndReason, ResultSource, Score};
+
+    use super::*;
+
+    #[test]
+    fn test_history_persists() {
+        let dir = std::env::temp_dir().join(format!("match-history-{}", std::process::id()));
+        let path = dir.join("history.jsonl");
+        let _ = fs::remove_dir_all(&dir);
+
+        let history = MatchHistory::open(path.clone());
+        let params = BTreeMap::from([("synch_mode".to_string(), ParamValue::Bool(true))]);
+        let first = history.start(Utc::now(), "19.0.0".to_string(), params, StoppingConfig::default());
+        let second = history.start(Utc::now(), "19.0.0".to_string(), BTreeMap::new(), StoppingConfig::default());
+        assert_eq!((first, second), (1, 2));
+
+        let mut result = MatchResult::new(Score { left: 2, right: 0 }, None, ResultSource::Referee);
+        result.end_reason = Some(EndReason::TimeUp);
+        history.update(first, |r| {
+            r.ended_at = Some(Utc::now());
+            r.result = Some(result.clone());
+        });
+        fs::OpenOptions::new().append(true).open(&path).unwrap().write_all(b"not json\n").unwrap();
+
+        let reloaded = MatchHistory::open(path.clone());
+        assert_eq!(reloaded.get(first), history.get(first));
+        assert_eq!(reloaded.get(first).unwrap().result, Some(result));
+        assert_eq!(reloaded.list(0, 10).iter().map(|r| r.generation).collect::<Vec<_>>(), vec![2, 1]);
+        assert_eq!(reloaded.list(1, 10).len(), 1);
+        // compacted to one line per generation
+        assert_eq!(fs::read_to_string(&path).unwrap().lines().count(), 2);
+        assert_eq!(reloaded.start(Utc::now(), "19.0.0".to_string(), BTreeMap::new(), StoppingConfig::default()), 3);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -3,10 +3,15 @@ mod status;
 mod process;
 mod args;
 mod config;
+mod stopping;
+mod history;
 
 use process::AddonProcess;
 
 pub use status::ServerStatus;
 pub use base::{BaseService};
 pub use args::BaseArgs;
-pub use config::BaseConfig;
\ No newline at end of file
+pub use config::BaseConfig;
+pub use stopping::{EndReason, StoppingConfig};
+pub use common::types::{Blocklist, InitState, PlayerBlocklist, PlayerInitState};
+pub use history::{MatchHistory, MatchRecord};
\ No newline at end of file
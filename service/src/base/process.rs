@@ -1,10 +1,13 @@
+use std::time::Duration;
+
 use log::info;
-use tokio::sync::{broadcast, watch};
+use tokio::sync::{broadcast, mpsc, watch};
 use chrono::{DateTime, Utc};
 
+use common::client::{RxData, TxData};
 use common::command::trainer::TrainerCommand;
 use common::command::{Command, CommandResult};
-use process::{CoachedProcess, CoachedProcessSpawner, CommandCaller, ProcessStatus};
+use process::{CoachedProcess, CoachedProcessSpawner, CommandCaller, ProcessStatus, StepOutcome};
 
 use crate::addons;
 use crate::{Error, Result};
@@ -13,13 +16,14 @@ use crate::{Error, Result};
 #[derive(Debug)]
 pub struct AddonProcess {
     process: CoachedProcess,
-    time_rx: watch::Receiver<Option<u16>>,
+    match_rx: watch::Receiver<addons::MatchState>,
     started_at: DateTime<Utc>,
 }
 
 impl AddonProcess {
     pub async fn new() -> Result<Self> {
-        let spawner = CoachedProcess::spawner().await;
+        let spawner = CoachedProcess::spawner().await
+            .map_err(|e| Error::ProcessSpawnFailed(e))?;
         Self::spawn(&spawner).await
     }
 
@@ -34,12 +38,12 @@ impl AddonProcess {
     pub fn from_coached_process(process: CoachedProcess) -> Self {
         let started_at = Utc::now();
 
-        let time_rx = process
+        let match_rx = process
             .coach()
-            .add_caller_addon::<addons::TimeStatusAddon>("time");
-        info!("[AddonProcess] Time status addon registered");
+            .add_listener_addon::<addons::MatchStateAddon>("match_state");
+        info!("[AddonProcess] Match state addon registered");
 
-        Self { process, time_rx, started_at }
+        Self { process, match_rx, started_at }
     }
 
     pub async fn send_trainer_command<C: Command<Kind = TrainerCommand>>(
@@ -50,16 +54,37 @@ impl AddonProcess {
             .map_err(|_| Error::Timeout { op: "send_trainer_command" })
     }
     
+    pub async fn step(&self, cycles: u16, timeout: Duration) -> Result<StepOutcome> {
+        self.process.coach().step(cycles, timeout).await
+            .map_err(|e| Error::TrainerCommandFailed(e.to_string()))
+    }
+
+    /// Send a message over the trainer connection without waiting for a reply.
+    pub async fn relay_trainer(&self, kind: TrainerCommand, data: TxData) -> Result<std::result::Result<(), String>> {
+        let reply = self.process.coach().call_raw(kind, data).await
+            .map_err(|e| Error::TrainerCommandFailed(e.to_string()))?;
+        Ok(reply.map_err(|reason| reason.to_string()))
+    }
+
+    /// Everything rcssserver sends to the trainer, until `tx` is dropped.
+    pub fn subscribe_trainer(&self, tx: mpsc::Sender<RxData>) {
+        self.process.coach().subscribe(tx);
+    }
+
     pub fn trainer_command_sender(&self) -> CommandCaller<TrainerCommand> {
         self.process.coach().caller()
     }
 
-    pub fn time_watch(&self) -> watch::Receiver<Option<u16>> {
-        self.time_rx.clone()
+    pub fn match_watch(&self) -> watch::Receiver<addons::MatchState> {
+        self.match_rx.clone()
+    }
+
+    pub fn match_state(&self) -> addons::MatchState {
+        self.match_rx.borrow().clone()
     }
 
     pub fn time(&self) -> Option<u16> {
-        *self.time_rx.borrow()
+        self.match_rx.borrow().time
     }
 
     pub async fn shutdown(&mut self) -> Result<()> {
@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+
+use common::types::PlayMode;
+use process::config::ServerConfig;
+
+use crate::addons::MatchState;
+use crate::GAME_END_TIMESTEP;
+
+pub use common::types::EndReason;
+
+/// When the match ends, whichever condition fires first.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StoppingConfig {
+    pub time_up: u16,
+    pub goal_l: Option<u8>,
+    pub goal_r: Option<u8>,
+    /// rcssserver's referee ends the match itself by `time_up`, so wait for its `time_over`:
+    /// on a draw it may play extra time and a penalty shoot-out first.
+    #[serde(default)]
+    pub referee_time_over: bool,
+}
+
+impl Default for StoppingConfig {
+    fn default() -> Self {
+        Self {
+            time_up: GAME_END_TIMESTEP,
+            goal_l: None,
+            goal_r: None,
+            referee_time_over: false,
+        }
+    }
+}
+
+impl StoppingConfig {
+    pub fn check(&self, state: &MatchState) -> Option<EndReason> {
+        if self.goal_l.is_some_and(|n| state.score.left >= n) {
+            return Some(EndReason::GoalL);
+        }
+        if self.goal_r.is_some_and(|n| state.score.right >= n) {
+            return Some(EndReason::GoalR);
+        }
+
+        // a shoot-out only ever ends by the referee
+        let time_up = !self.referee_time_over && state.penalties.is_none()
+            && state.time.is_some_and(|t| t >= self.time_up);
+        if time_up || state.play_mode == Some(PlayMode::PM_TimeOver) {
+            return Some(EndReason::TimeUp);
+        }
+
+        None
+    }
+
+    /// Leave the end to the referee when it is on and `time_up` is past its regular time.
+    pub fn with_referee(mut self, referee: bool, server: &ServerConfig) -> Self {
+        self.referee_time_over = referee && i64::from(self.time_up) >= regular_time(server);
+        self
+    }
+}
+
+/// Cycle rcssserver's regular time ends at, from its `half_time` in seconds.
+fn regular_time(server: &ServerConfig) -> i64 {
+    let half_time = i64::from(server.half_time.unwrap_or(300));
+    let simulator_step = i64::from(server.simulator_step.unwrap_or(100)).max(1);
+    half_time * 1000 / simulator_step * i64::from(server.nr_normal_halfs.unwrap_or(2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_condition_fires() {
+        let stopping = StoppingConfig { time_up: 100, goal_l: None, goal_r: Some(2), referee_time_over: false };
+        let mut state = MatchState::default();
+        assert_eq!(stopping.check(&state), None);
+
+        assert!(state.ingest("(hear 50 referee goal_r_1)"));
+        assert_eq!(stopping.check(&state), None);
+        assert!(state.ingest("(hear 60 referee goal_l_1)"));
+        assert_eq!(stopping.check(&state), None);
+
+        assert!(state.ingest("(hear 70 referee goal_r_2)"));
+        assert_eq!(stopping.check(&state), Some(EndReason::GoalR));
+
+        let mut state = MatchState::default();
+        assert!(state.ingest("(see_global 100 ((b) 0 0 0 0))"));
+        assert_eq!(stopping.check(&state), Some(EndReason::TimeUp));
+
+        let mut state = MatchState::default();
+        assert!(state.ingest("(hear 20 referee time_over)"));
+        assert_eq!(StoppingConfig::default().check(&state), Some(EndReason::TimeUp));
+    }
+
+    #[test]
+    fn test_referee_time_over() {
+        let mut server = ServerConfig::default();
+        let stopping = StoppingConfig::default().with_referee(true, &server);
+        assert!(stopping.referee_time_over);
+        assert!(!StoppingConfig::default().with_referee(false, &server).referee_time_over);
+        server.half_time(600);
+        assert!(!StoppingConfig::default().with_referee(true, &server).referee_time_over);
+
+        let mut state = MatchState::default();
+        assert!(state.ingest("(hear 6000 referee penalty_setup_l)"));
+        assert_eq!(stopping.check(&state), None);
+        assert_eq!(StoppingConfig::default().check(&state), None);
+        assert!(state.ingest("(hear 6100 referee time_over)"));
+        assert_eq!(stopping.check(&state), Some(EndReason::TimeUp));
+    }
+}
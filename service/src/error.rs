@@ -15,9 +15,15 @@ pub enum Error {
     #[error("Failed to spawn process: {0}")]
     ProcessSpawnFailed(#[source] process::Error),
 
+    #[error("Invalid rcssserver config: {0}")]
+    ProcessConfigInvalid(#[source] process::config::ConfigError),
+
     #[error("Failed to send trainer command: {0}")]
     TrainerCommandFailed(String),
 
+    #[error("Server is not running in synch mode, can not step.")]
+    NotSyncMode,
+
     #[error("Status channel closed unexpectedly")]
     StatusChannelClosed,
 
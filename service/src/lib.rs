@@ -26,9 +26,12 @@ pub use agones::{
     AgonesArgs as Args,
     AgonesConfig as Config,
     AgonesService as Service,
+    GameServerAddress,
 };
 
 pub use error::{Error, Result};
-pub use base::ServerStatus;
+pub use base::{Blocklist, EndReason, MatchRecord, PlayerBlocklist, ServerStatus, StoppingConfig};
+pub use addons::{GoalEvent, MatchState, Score};
+pub use process::TRAINER_PROTOCOL_VERSION;
 
 pub const GAME_END_TIMESTEP: u16 = 6000;
@@ -1,4 +1,5 @@
 use serde::Serialize;
+use allocator::metadata::MetaData;
 
 #[derive(Serialize, Debug, Clone)]
 pub struct AgonesMcConfigInfo {
@@ -23,6 +24,10 @@ pub struct AgonesConfigInfo {
 	pub match_composer_enabled: bool,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub match_composer: Option<AgonesMcConfigInfo>,
+
+	/// Teams, referee, stopping and init state as read from the GameServer.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub metadata: Option<MetaData>,
 }
 
 #[inline]
@@ -50,6 +55,7 @@ impl crate::Service {
 			sdk_keep_alive_ms: cfg.sdk.keep_alive.map(duration_ms),
 			match_composer_enabled: match_composer.is_some(),
 			match_composer,
+			metadata: cfg.metadata.clone(),
 		}
 	}
 }
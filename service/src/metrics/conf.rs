@@ -1,12 +1,78 @@
 use serde::Serialize;
+use crate::base::BaseArgs;
 use crate::metrics::RcssConfigInfo;
 #[cfg(feature = "agones")]
 use crate::metrics::AgonesConfigInfo;
 
+pub const REDACTED: &str = "<redacted>";
+/// Values of names containing any of these are redacted.
+const SECRET_MARKERS: &[&str] = &["secret", "token", "password", "passwd", "credential", "api_key", "apikey", "access_key"];
+
 #[derive(Serialize, Debug)]
 pub struct ConfigInfo {
     pub base: RcssConfigInfo,
-    
+    /// The args after the GameServer overrides.
+    pub args: BaseArgs,
+    /// What every spawn of rcssserver runs, wrapper first.
+    pub rcss_command_line: Vec<String>,
+
     #[cfg(feature = "agones")]
     pub agones: AgonesConfigInfo,
 }
+
+fn is_secret(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    SECRET_MARKERS.iter().any(|marker| name.contains(marker))
+}
+
+/// Redact the values of `NAME=value` and `--name value` words whose name looks like a secret.
+pub fn redact_words<'a>(words: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let mut ret = Vec::new();
+    let mut hide_next = false;
+    for word in words {
+        let redacted = match word.split_once('=') {
+            _ if hide_next => REDACTED.to_string(),
+            Some((name, _)) if is_secret(name) => format!("{name}={REDACTED}"),
+            _ => word.to_string(),
+        };
+        hide_next = word.starts_with('-') && !word.contains('=') && is_secret(word);
+        ret.push(redacted);
+    }
+    ret
+}
+
+impl crate::Service {
+    /// The effective configuration, with secrets redacted.
+    pub async fn config_info(&self) -> ConfigInfo {
+        let mut args = self.base_args().clone();
+        args.rcss_wrapper = redact_words(args.rcss_wrapper.split_whitespace()).join(" ");
+        for (name, value) in args.server_params.iter_mut().flatten() {
+            if is_secret(name) {
+                *value = REDACTED.to_string();
+            }
+        }
+
+        let command_line = self.rcss_command_line();
+        ConfigInfo {
+            base: self.rcss_config_info().await,
+            args,
+            rcss_command_line: redact_words(command_line.iter().map(String::as_str)),
+            #[cfg(feature = "agones")]
+            agones: self.agones_info().await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_words() {
+        let words = "env OSS_ACCESS_KEY_SECRET=abc stdbuf -oL --auth-token xyz rcssserver server::port=6000";
+        assert_eq!(
+            redact_words(words.split_whitespace()).join(" "),
+            "env OSS_ACCESS_KEY_SECRET=<redacted> stdbuf -oL --auth-token <redacted> rcssserver server::port=6000",
+        );
+    }
+}
@@ -1,8 +1,8 @@
 use serde::Serialize;
-use crate::GAME_END_TIMESTEP;
 
 #[derive(Serialize, Debug, Clone)]
 pub struct RcssConfigInfo {
+    pub rcss_version: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub player_port: Option<u16>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -12,15 +12,21 @@ pub struct RcssConfigInfo {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sync_mode: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub game_log_dir: Option<&'static str>,
+    pub referee: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub text_log_dir: Option<&'static str>,
+    pub game_log_dir: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub keepaway_log_dir: Option<&'static str>,
+    pub text_log_dir: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keepaway_log_dir: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub half_time_auto_start_timestep: Option<u16>,
     pub always_log_stdout: bool,
     pub service_finish_timestep: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_finish_goal_l: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_finish_goal_r: Option<u8>,
 }
 
 impl crate::Service {
@@ -29,16 +35,20 @@ impl crate::Service {
         let base_cfg = self.base_config();
 
         RcssConfigInfo {
+            rcss_version: self.rcss_version().to_string(),
             player_port: cfg.server.port,
             trainer_port: cfg.server.coach_port,
             coach_port: cfg.server.olcoach_port,
             sync_mode: cfg.server.synch_mode,
-            game_log_dir: cfg.server.game_log_dir,
-            text_log_dir: cfg.server.text_log_dir,
-            keepaway_log_dir: cfg.server.keepaway_log_dir,
+            referee: cfg.server.coach_w_referee,
+            game_log_dir: cfg.server.game_log_dir.clone(),
+            text_log_dir: cfg.server.text_log_dir.clone(),
+            keepaway_log_dir: cfg.server.keepaway_log_dir.clone(),
             half_time_auto_start_timestep: base_cfg.half_time_auto_start,
             always_log_stdout: base_cfg.always_log_stdout,
-            service_finish_timestep: GAME_END_TIMESTEP,
+            service_finish_timestep: base_cfg.stopping.time_up,
+            service_finish_goal_l: base_cfg.stopping.goal_l,
+            service_finish_goal_r: base_cfg.stopping.goal_r,
         }
     }
 }
@@ -1,12 +1,26 @@
 use serde::Serialize;
 use chrono::{DateTime, Utc};
-use crate::ServerStatus;
+use common::types::MatchResult;
+
+use crate::{EndReason, MatchState, ServerStatus};
 
 #[derive(Serialize, Debug, Clone)]
 pub struct ServiceStatusInfo {
     pub status: ServerStatus,
+    /// Generation of the current match in the history.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generation: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub timestep: Option<u16>,
+    /// Play mode, score and last goal as announced by the referee.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub match_state: Option<MatchState>,
+    /// The stopping condition which ended the match.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_reason: Option<EndReason>,
+    /// Final score, winner and logs, once the match is over.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub match_result: Option<MatchResult>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub started_at: Option<DateTime<Utc>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -21,14 +35,19 @@ pub struct ServiceStatusInfo {
 impl crate::Service {
     pub async fn status_info(&self) -> ServiceStatusInfo {
         let status = self.status_now();
-        let timestep = self.time_now().await;
+        let match_state = self.match_state_now().await;
+        let timestep = match_state.as_ref().and_then(|s| s.time);
         let started_at = self.started_at().await;
         let uptime_ms = started_at
             .map(|started_at| (Utc::now() - started_at).num_milliseconds());
 
         ServiceStatusInfo {
             status,
+            generation: self.generation(),
             timestep,
+            match_state,
+            end_reason: self.end_reason(),
+            match_result: self.match_result().await,
             started_at,
             uptime_ms,
             process_pid: self.process_pid().await,
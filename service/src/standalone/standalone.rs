@@ -9,7 +9,7 @@ pub struct StandaloneService {
 
 impl StandaloneService {
     pub async fn from_args(args: StandaloneArgs, log_root: PathBuf) -> crate::Result<Self> {
-        let base = BaseService::from_args(args.base_args, log_root).await;
+        let base = BaseService::from_args(args.base_args, log_root).await?;
         Ok(Self::new(base))
     }
 
@@ -28,6 +28,11 @@ impl StandaloneService {
         self.service.spawn(true).await
     }
 
+    /// Standalone servers take whichever teams connect first.
+    pub fn declared_teams(&self) -> (Option<&str>, Option<&str>) {
+        (None, None)
+    }
+
     pub async fn restart(&self, force: bool) -> crate::Result<JoinHandle<()>> {
         self.service.spawn(force).await
     }